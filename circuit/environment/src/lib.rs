@@ -73,6 +73,7 @@ pub mod prelude {
             Inverse as _,
             Square as _,
             SquareRoot as _,
+            SquareRootCanonical as _,
             ToBits as _,
         },
         Parser,
@@ -34,6 +34,13 @@ pub trait SquareRoot {
     fn square_root(&self) -> Self::Output;
 }
 
+/// Unary operator for retrieving the canonical (lexicographically smaller) square root of the value.
+pub trait SquareRootCanonical {
+    type Output;
+
+    fn square_root_canonical(&self) -> Self::Output;
+}
+
 ///
 /// A single-bit binary adder with a carry bit.
 ///
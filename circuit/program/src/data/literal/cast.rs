@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Converts the integer circuit `$integer` into an integer literal of type `$to_type`, using the method `$cast`.
+#[cfg(console)]
+macro_rules! cast_into {
+    ($integer:expr, $to_type:expr, $cast:ident) => {
+        match $to_type {
+            console::LiteralType::I8 => Literal::I8($integer.$cast::<i8>()),
+            console::LiteralType::I16 => Literal::I16($integer.$cast::<i16>()),
+            console::LiteralType::I32 => Literal::I32($integer.$cast::<i32>()),
+            console::LiteralType::I64 => Literal::I64($integer.$cast::<i64>()),
+            console::LiteralType::I128 => Literal::I128($integer.$cast::<i128>()),
+            console::LiteralType::U8 => Literal::U8($integer.$cast::<u8>()),
+            console::LiteralType::U16 => Literal::U16($integer.$cast::<u16>()),
+            console::LiteralType::U32 => Literal::U32($integer.$cast::<u32>()),
+            console::LiteralType::U64 => Literal::U64($integer.$cast::<u64>()),
+            console::LiteralType::U128 => Literal::U128($integer.$cast::<u128>()),
+            _ => bail!("Cannot cast an integer into a '{}'", $to_type),
+        }
+    };
+}
+
+/// Casts the integer literal `$literal` into an integer literal of type `$to_type`, using the method `$cast`.
+#[cfg(console)]
+macro_rules! cast_integer {
+    ($literal:expr, $to_type:expr, $cast:ident) => {
+        match $literal {
+            Literal::I8(integer) => cast_into!(integer, $to_type, $cast),
+            Literal::I16(integer) => cast_into!(integer, $to_type, $cast),
+            Literal::I32(integer) => cast_into!(integer, $to_type, $cast),
+            Literal::I64(integer) => cast_into!(integer, $to_type, $cast),
+            Literal::I128(integer) => cast_into!(integer, $to_type, $cast),
+            Literal::U8(integer) => cast_into!(integer, $to_type, $cast),
+            Literal::U16(integer) => cast_into!(integer, $to_type, $cast),
+            Literal::U32(integer) => cast_into!(integer, $to_type, $cast),
+            Literal::U64(integer) => cast_into!(integer, $to_type, $cast),
+            Literal::U128(integer) => cast_into!(integer, $to_type, $cast),
+            literal => bail!("Cannot cast a '{}' into a '{}'", literal.to_type(), $to_type),
+        }
+    };
+}
+
+#[cfg(console)]
+impl<A: Aleo> Literal<A> {
+    /// Casts the integer literal into the given integer type, halting if the value does not fit.
+    pub fn cast(&self, to_type: console::LiteralType) -> Result<Self> {
+        Ok(cast_integer!(self, to_type, cast_checked))
+    }
+
+    /// Casts the integer literal into the given integer type, truncating the value if it does not fit.
+    pub fn cast_lossy(&self, to_type: console::LiteralType) -> Result<Self> {
+        Ok(cast_integer!(self, to_type, cast_lossy))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    fn check_cast(literal: &str, to_type: console::LiteralType) -> Result<()> {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let expected = console::Literal::<<Circuit as Environment>::Network>::from_str(literal)?;
+            let candidate = Literal::<Circuit>::new(mode, expected.clone());
+
+            // Ensure the circuit casts agree with the console casts.
+            assert_eq!(expected.cast(to_type)?, candidate.cast(to_type)?.eject_value());
+            assert_eq!(expected.cast_lossy(to_type)?, candidate.cast_lossy(to_type)?.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast() -> Result<()> {
+        check_cast("200u8", console::LiteralType::U64)?;
+        check_cast("-5i8", console::LiteralType::I128)?;
+        check_cast("255u64", console::LiteralType::U8)?;
+        check_cast("-128i32", console::LiteralType::I8)?;
+        check_cast("127u8", console::LiteralType::I8)
+    }
+
+    #[test]
+    fn test_cast_lossy_truncates() -> Result<()> {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let literal = Literal::<Circuit>::from_str(&format!("-1i16.{mode}"))?;
+            let candidate = literal.cast_lossy(console::LiteralType::U8)?;
+            assert_eq!(console::Literal::from_str("255u8")?, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_out_of_range_fails() -> Result<()> {
+        // Constant casts halt.
+        let result = std::panic::catch_unwind(|| {
+            Literal::<Circuit>::from_str("256u16.constant").unwrap().cast(console::LiteralType::U8)
+        });
+        assert!(result.is_err());
+        Circuit::reset();
+
+        // Non-constant casts are unsatisfiable.
+        for mode in [Mode::Public, Mode::Private] {
+            let literal = Literal::<Circuit>::from_str(&format!("256u16.{mode}"))?;
+            let _candidate = literal.cast(console::LiteralType::U8)?;
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -32,3 +32,39 @@ impl<A: Aleo> Record<A, Ciphertext<A>> {
         A::halt("Illegal operation: Record::to_commitment() cannot be invoked on the `Ciphertext` variant.")
     }
 }
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    #[test]
+    fn test_to_commitment_matches_console() -> Result<()> {
+        let program_id = console::ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        let record_name = console::Identifier::<CurrentNetwork>::from_str("token")?;
+
+        for record in [
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u64.private, _nonce: 0group.public }",
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, amount: 7u64.public, flag: true.constant, _nonce: 0group.public }",
+        ] {
+            // Compute the record commitment from the console value.
+            let value = console::Value::<CurrentNetwork>::from_str(record)?;
+            let expected = value.to_record_commitment(&program_id, &record_name)?;
+
+            // Compute the record commitment in the circuit.
+            let record = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, console::Record::from_str(record)?);
+            let candidate = record.to_commitment(
+                &ProgramID::new(Mode::Constant, program_id),
+                &Identifier::new(Mode::Constant, record_name),
+            );
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
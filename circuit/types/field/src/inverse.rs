@@ -34,6 +34,11 @@ impl<E: Environment> Inverse for Field<E> {
     type Output = Field<E>;
 
     fn inverse(&self) -> Self::Output {
+        // Halt early if `self` is a constant zero, as it has no inverse.
+        if self.is_constant() && self.eject_value().is_zero() {
+            return E::halt("Cannot invert zero in the field.");
+        }
+
         let inverse = witness!(|self| match self.inverse() {
             Ok(inverse) => inverse,
             _ => console::Field::zero(),
@@ -103,6 +108,28 @@ mod tests {
         check_inverse("Private", Mode::Private, &mut rng);
     }
 
+    #[test]
+    fn test_inverse_of_inverse() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                let given: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                if given.is_zero() {
+                    continue;
+                }
+                let candidate = Field::<Circuit>::new(mode, given);
+
+                // Ensure `x^(-1)^(-1)` == `x` and `x` * `x^(-1)` == 1.
+                let inverse = candidate.inverse();
+                assert_eq!(given, inverse.inverse().eject_value());
+                assert!((&candidate * &inverse).is_one().eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
     #[test]
     fn test_zero_inverse_fails() {
         let zero = console::Field::<<Circuit as Environment>::Network>::zero();
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> IsZero for Field<E> {
+    type Output = Boolean<E>;
+
+    ///
+    /// Returns `true` if `self` is zero.
+    ///
+    /// This method costs 3 constraints, regardless of the value of `self`.
+    ///
+    fn is_equal_to_zero(&self) -> Self::Output {
+        self.is_zero()
+    }
+}
+
+impl<E: Environment> Metrics<dyn IsZero<Output = Boolean<E>>> for Field<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(1, 0, 0, 0),
+            _ => Count::is(0, 0, 2, 3),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn IsZero<Output = Boolean<E>>> for Field<E> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_is_equal_to_zero(name: &str, mode: Mode, value: console::Field<<Circuit as Environment>::Network>) {
+        let a = Field::<Circuit>::new(mode, value);
+        Circuit::scope(name, || {
+            let candidate = a.is_equal_to_zero();
+            assert_eq!(*value.is_equal_to_zero(), candidate.eject_value());
+            assert_count!(IsZero(Field) => Boolean, &mode);
+            assert_output_mode!(IsZero(Field) => Boolean, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        check_is_equal_to_zero(&format!("IsZero: {mode} zero"), mode, console::Field::zero());
+        check_is_equal_to_zero(&format!("IsZero: {mode} one"), mode, console::Field::one());
+
+        for i in 0..ITERATIONS {
+            check_is_equal_to_zero(&format!("IsZero: {mode} {i}"), mode, Uniform::rand(&mut rng));
+        }
+    }
+
+    #[test]
+    fn test_constant_is_zero() {
+        run_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_public_is_zero() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_private_is_zero() {
+        run_test(Mode::Private);
+    }
+}
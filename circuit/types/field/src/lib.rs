@@ -25,6 +25,8 @@ pub mod div_unchecked;
 pub mod double;
 pub mod equal;
 pub mod inverse;
+pub mod is_zero;
+pub mod mod_pow;
 pub mod mul;
 pub mod neg;
 pub mod pow;
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_circuit_environment::CircuitType;
+
+impl<E: Environment> ModPow<Field<E>> for Field<E> {
+    type Output = Field<E>;
+
+    ///
+    /// Returns `self` to the power of `exponent`, where the exponent is a field element.
+    ///
+    /// The exponent is bounded by the bit length of the base field, and the output is computed
+    /// with a square-and-multiply over every bit of the exponent, so the number of steps is fixed.
+    ///
+    fn mod_pow(&self, exponent: &Field<E>) -> Self::Output {
+        self.pow(exponent)
+    }
+}
+
+impl<E: Environment> Metrics<dyn ModPow<Field<E>, Output = Field<E>>> for Field<E> {
+    type Case = (CircuitType<Field<E>>, CircuitType<Field<E>>);
+
+    fn count(case: &Self::Case) -> Count {
+        <Self as Metrics<dyn Pow<Field<E>, Output = Field<E>>>>::count(case)
+    }
+}
+
+impl<E: Environment> OutputMode<dyn ModPow<Field<E>, Output = Field<E>>> for Field<E> {
+    type Case = (CircuitType<Field<E>>, CircuitType<Field<E>>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        <Self as OutputMode<dyn Pow<Field<E>, Output = Field<E>>>>::output_mode(case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_mod_pow(
+        name: &str,
+        expected: &console::Field<<Circuit as Environment>::Network>,
+        a: &Field<Circuit>,
+        b: &Field<Circuit>,
+    ) {
+        Circuit::scope(name, || {
+            let candidate = a.mod_pow(b);
+            assert_eq!(*expected, candidate.eject_value(), "({}^{})", a.eject_value(), b.eject_value());
+            assert_count!(ModPow(Field, Field) => Field, &(CircuitType::from(a), CircuitType::from(b)));
+            assert_output_mode!(ModPow(Field, Field) => Field, &(CircuitType::from(a), CircuitType::from(b)), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let a = Field::<Circuit>::new(mode_a, first);
+            let b = Field::<Circuit>::new(mode_b, second);
+            check_mod_pow(&format!("ModPow: a ^ b {i}"), &first.mod_pow(&second), &a, &b);
+
+            // Test small exponents, starting with zero.
+            let mut expected = console::Field::<<Circuit as Environment>::Network>::one();
+            for exponent in 0..4u8 {
+                let a = Field::<Circuit>::new(mode_a, first);
+                let b = Field::<Circuit>::new(mode_b, console::Field::from_u8(exponent));
+                check_mod_pow(&format!("ModPow: a ^ {exponent} {i}"), &expected, &a, &b);
+                expected *= first;
+            }
+        }
+
+        let zero = console::Field::<<Circuit as Environment>::Network>::zero();
+        let one = console::Field::<<Circuit as Environment>::Network>::one();
+
+        // Test 0 ^ 0.
+        check_mod_pow(
+            "ModPow: 0 ^ 0",
+            &one,
+            &Field::<Circuit>::new(mode_a, zero),
+            &Field::<Circuit>::new(mode_b, zero),
+        );
+    }
+
+    #[test]
+    fn test_constant_mod_pow_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_mod_pow_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_constant_mod_pow_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_public_mod_pow_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_public_mod_pow_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_public_mod_pow_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_mod_pow_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_private_mod_pow_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_private_mod_pow_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
@@ -30,6 +30,39 @@ impl<E: Environment> SquareRoot for Field<E> {
     }
 }
 
+impl<E: Environment> SquareRootCanonical for Field<E> {
+    type Output = Self;
+
+    /// Returns the lexicographically smaller of the two square roots of `self`.
+    fn square_root_canonical(&self) -> Self::Output {
+        // If `self` is a constant, compute the square root natively.
+        if self.is_constant() {
+            match self.eject_value().square_root_canonical() {
+                Ok(square_root) => Field::constant(square_root),
+                Err(_) => E::halt(format!("Failed to square root a field element: {}", self.eject_value())),
+            }
+        } else {
+            let square_root = witness!(|self| match self.square_root_canonical() {
+                Ok(square_root) => square_root,
+                _ => console::Field::zero(),
+            });
+            self.enforce_square_root_canonical(&square_root);
+            square_root
+        }
+    }
+}
+
+impl<E: Environment> Field<E> {
+    /// Enforces that `square_root` is the lexicographically smaller of the two square roots of `self`.
+    fn enforce_square_root_canonical(&self, square_root: &Self) {
+        // Ensure `square_root` * `square_root` == `self`.
+        E::enforce(|| (square_root, square_root, self));
+        // Ensure `square_root` is the smaller of the two roots, i.e. `square_root` <= (p - 1) / 2.
+        let max_root = Field::constant(console::Field::half() - console::Field::one());
+        E::assert(square_root.is_less_than_or_equal(&max_root));
+    }
+}
+
 impl<E: Environment> Metrics<dyn SquareRoot<Output = Field<E>>> for Field<E> {
     type Case = Mode;
 
@@ -52,6 +85,28 @@ impl<E: Environment> OutputMode<dyn SquareRoot<Output = Field<E>>> for Field<E>
     }
 }
 
+impl<E: Environment> Metrics<dyn SquareRootCanonical<Output = Field<E>>> for Field<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(1, 0, 0, 0),
+            false => Count::less_than(2, 0, 758, 761),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn SquareRootCanonical<Output = Field<E>>> for Field<E> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,4 +141,80 @@ mod tests {
         check_square_root("Public", Mode::Public, &mut rng);
         check_square_root("Private", Mode::Private, &mut rng);
     }
+
+    fn check_square_root_canonical(name: &str, mode: Mode, rng: &mut TestRng) {
+        for _ in 0..ITERATIONS {
+            // Sample a random element.
+            let given: console::Field<<Circuit as Environment>::Network> = Uniform::rand(rng);
+            // Compute its canonical square root, or skip this iteration if it does not natively exist.
+            if let Ok(expected) = given.square_root_canonical() {
+                let input = Field::<Circuit>::new(mode, given);
+
+                Circuit::scope(name, || {
+                    let candidate = input.square_root_canonical();
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_count!(SquareRootCanonical(Field) => Field, &mode);
+                    assert_output_mode!(SquareRootCanonical(Field) => Field, &mode, candidate);
+                });
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_square_root_canonical() {
+        let mut rng = TestRng::default();
+
+        check_square_root_canonical("Constant", Mode::Constant, &mut rng);
+        check_square_root_canonical("Public", Mode::Public, &mut rng);
+        check_square_root_canonical("Private", Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_square_root_canonical_zero() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let candidate = Field::<Circuit>::new(mode, console::Field::zero()).square_root_canonical();
+            assert_eq!(console::Field::zero(), candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_square_root_canonical_rejects_larger_root() {
+        let mut rng = TestRng::default();
+
+        // Sample a quadratic residue.
+        let given = loop {
+            let candidate: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            if candidate.square_root().is_ok() && !candidate.is_zero() {
+                break candidate;
+            }
+        };
+        let input = Field::<Circuit>::new(Mode::Private, given);
+
+        // Witness the larger root in place of the canonical root, and ensure the gadget rejects it.
+        let larger = Field::<Circuit>::new(Mode::Private, -given.square_root_canonical().unwrap());
+        assert_eq!(given, (&larger * &larger).eject_value());
+        input.enforce_square_root_canonical(&larger);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_square_root_canonical_constant_nonresidue_halts() {
+        let mut rng = TestRng::default();
+
+        // Sample a quadratic nonresidue.
+        let given = loop {
+            let candidate: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            if candidate.square_root().is_err() {
+                break candidate;
+            }
+        };
+        let result = std::panic::catch_unwind(|| Field::<Circuit>::constant(given).square_root_canonical());
+        assert!(result.is_err());
+        Circuit::reset();
+    }
 }
@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Casts `self` into an integer of type `J`, halting if the value does not fit in `J`.
+    pub fn cast_checked<J: IntegerType>(&self) -> Integer<E, J> {
+        let (bits_le, upper_bits_le) = self.extend_and_split::<J>();
+
+        // The discarded bits must equal the sign bit of the output (if signed), or zero (if unsigned).
+        let expected = match J::is_signed() {
+            true => bits_le[J::BITS as usize - 1].clone(),
+            false => Boolean::constant(false),
+        };
+
+        if self.is_constant() {
+            if upper_bits_le.iter().any(|bit| bit.eject_value() != expected.eject_value()) {
+                E::halt(format!(
+                    "Failed to cast {} into {}",
+                    self.eject_value(),
+                    console::Integer::<E::Network, J>::type_name()
+                ))
+            }
+        } else {
+            for bit in &upper_bits_le {
+                E::assert_eq(bit, &expected);
+            }
+        }
+
+        Integer { bits_le, phantom: Default::default() }
+    }
+
+    /// Casts `self` into an integer of type `J`, truncating the value if it does not fit in `J`.
+    pub fn cast_lossy<J: IntegerType>(&self) -> Integer<E, J> {
+        let (bits_le, _) = self.extend_and_split::<J>();
+        Integer { bits_le, phantom: Default::default() }
+    }
+
+    /// Returns the bits of `self`, sign- or zero-extended and split into the lower `J::BITS` bits and the rest.
+    fn extend_and_split<J: IntegerType>(&self) -> (Vec<Boolean<E>>, Vec<Boolean<E>>) {
+        // Extend one bit past the wider of the two types, so the sign of `self` is always retained.
+        let num_bits = core::cmp::max(I::BITS, J::BITS) as usize + 1;
+        let extension = match I::is_signed() {
+            true => self.msb().clone(),
+            false => Boolean::constant(false),
+        };
+
+        let mut bits_le = self.bits_le.clone();
+        bits_le.resize(num_bits, extension);
+        let upper_bits_le = bits_le.split_off(J::BITS as usize);
+        (bits_le, upper_bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_cast<I: IntegerType + UnwindSafe, J: IntegerType>(
+        value: I,
+        expected_checked: Option<J>,
+        expected_lossy: J,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::new(value));
+
+        // Check the lossy cast.
+        Circuit::scope("CastLossy", || {
+            let candidate = a.cast_lossy::<J>();
+            assert_eq!(expected_lossy, *candidate.eject_value());
+            assert_eq!(mode, candidate.eject_mode());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+
+        // Check the checked cast.
+        match expected_checked {
+            Some(expected) => Circuit::scope("CastChecked", || {
+                let candidate = a.cast_checked::<J>();
+                assert_eq!(expected, *candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            }),
+            None => match mode {
+                Mode::Constant => check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.cast_checked::<J>()),
+                _ => Circuit::scope("CastChecked", || {
+                    let _candidate = a.cast_checked::<J>();
+                    assert!(!Circuit::is_satisfied_in_scope());
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    macro_rules! test_cast {
+        ($name:ident, $from:ty, $to:ty) => {
+            #[test]
+            fn $name() {
+                let mut rng = TestRng::default();
+
+                for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                    for _ in 0..ITERATIONS {
+                        let value: console::Integer<_, $from> = Uniform::rand(&mut rng);
+                        let value = *value;
+                        check_cast::<$from, $to>(value, <$to>::try_from(value).ok(), value as $to, mode);
+                    }
+                    // Check the boundary values.
+                    for value in [<$from>::MIN, <$from>::MAX, 0, 1] {
+                        check_cast::<$from, $to>(value, <$to>::try_from(value).ok(), value as $to, mode);
+                    }
+                }
+            }
+        };
+    }
+
+    // Widening.
+    test_cast!(test_cast_u8_to_u64, u8, u64);
+    test_cast!(test_cast_i8_to_i64, i8, i64);
+    test_cast!(test_cast_u32_to_i128, u32, i128);
+    test_cast!(test_cast_i16_to_u32, i16, u32);
+
+    // Narrowing.
+    test_cast!(test_cast_u64_to_u8, u64, u8);
+    test_cast!(test_cast_i64_to_i8, i64, i8);
+    test_cast!(test_cast_u128_to_i16, u128, i16);
+    test_cast!(test_cast_i32_to_u16, i32, u16);
+
+    // Same width.
+    test_cast!(test_cast_u8_to_i8, u8, i8);
+    test_cast!(test_cast_i8_to_u8, i8, u8);
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> CountLeadingSignBits for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the number of leading bits equal to the sign bit, excluding the sign bit itself.
+    fn count_leading_sign_bits(&self) -> Self::Output {
+        if !I::is_signed() {
+            E::halt("Counting the leading sign bits of unsigned integers is not supported.")
+        }
+
+        // Compare each bit below the sign bit, from most to least significant, with the sign bit.
+        let sign = self.msb();
+        let mut is_sign_bit = self.bits_le.iter().rev().skip(1).map(|bit| bit.is_equal(sign));
+
+        // Track whether every bit seen so far equals the sign bit, and count the number of such bits.
+        let mut is_leading = match is_sign_bit.next() {
+            Some(is_leading) => is_leading,
+            // Note: `E::halt` should never be invoked as `I::BITS` is greater than one.
+            None => E::halt("Malformed integer detected while counting the leading sign bits"),
+        };
+        let mut count = Field::from_boolean(&is_leading);
+        for is_sign_bit in is_sign_bit {
+            is_leading = is_leading & is_sign_bit;
+            count += Field::from_boolean(&is_leading);
+        }
+
+        U32::from_field(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::{ops::RangeInclusive, panic::UnwindSafe};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_count_leading_sign_bits<I: IntegerType + UnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = value.count_leading_sign_bits();
+        Circuit::scope(name, || {
+            let candidate = a.count_leading_sign_bits();
+            assert_eq!(expected, candidate.eject_value());
+            match mode {
+                Mode::Constant => assert_scope!(32, 0, 0, 0),
+                _ => assert_scope!(0, 0, 2 * I::BITS + 29, 2 * I::BITS + 30),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("CountLeadingSignBits: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_count_leading_sign_bits::<I>(&name, value, mode);
+        }
+
+        // Check the edge cases.
+        for (i, value) in [
+            console::Integer::zero(),
+            console::Integer::one(),
+            -console::Integer::one(),
+            console::Integer::MIN,
+            console::Integer::MAX,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let name = format!("CountLeadingSignBits: {mode} edge {i}");
+            check_count_leading_sign_bits::<I>(&name, value, mode);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + UnwindSafe>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("CountLeadingSignBits: {mode}");
+            check_count_leading_sign_bits::<I>(&name, value, mode);
+        }
+    }
+
+    #[test]
+    fn test_count_leading_sign_bits_edge_cases() {
+        type I = i8;
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for (value, expected) in
+                [(0i8, 7u32), (-1, 7), (1, 6), (-2, 6), (63, 1), (-64, 1), (i8::MAX, 0), (i8::MIN, 0)]
+            {
+                let a = Integer::<Circuit, I>::new(mode, console::Integer::new(value));
+                Circuit::scope(format!("{mode} {value}"), || {
+                    let candidate = a.count_leading_sign_bits();
+                    assert_eq!(expected, *candidate.eject_value());
+                    match mode {
+                        Mode::Constant => assert_scope!(32, 0, 0, 0),
+                        _ => assert_scope!(0, 0, 45, 46),
+                    }
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    test_integer_unary!(run_test, i8, count_leading_sign_bits);
+    test_integer_unary!(run_test, i16, count_leading_sign_bits);
+    test_integer_unary!(run_test, i32, count_leading_sign_bits);
+    test_integer_unary!(run_test, i64, count_leading_sign_bits);
+    test_integer_unary!(run_test, i128, count_leading_sign_bits);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, count_leading_sign_bits, exhaustive);
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> CountZeros for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the number of zeros in the binary representation of `self`.
+    fn count_zeros(&self) -> Self::Output {
+        // Sum the negated bits as field elements.
+        // Note: The sum is a linear combination, so the only cost is the conversion into a `u32`.
+        let count = self.bits_le.iter().fold(Field::zero(), |count, bit| count + Field::from_boolean(&!bit));
+        U32::from_field(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::{ops::RangeInclusive, panic::UnwindSafe};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_count_zeros<I: IntegerType + UnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = value.count_zeros();
+        Circuit::scope(name, || {
+            let candidate = a.count_zeros();
+            assert_eq!(expected, candidate.eject_value());
+            match mode {
+                Mode::Constant => assert_scope!(32, 0, 0, 0),
+                _ => assert_scope!(0, 0, 32, 33),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("CountZeros: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_count_zeros::<I>(&name, value, mode);
+        }
+
+        // Check the edge cases, including all zeros and all ones.
+        for (i, value) in [
+            console::Integer::zero(),
+            console::Integer::one(),
+            !console::Integer::zero(),
+            console::Integer::MIN,
+            console::Integer::MAX,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let name = format!("CountZeros: {mode} edge {i}");
+            check_count_zeros::<I>(&name, value, mode);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + UnwindSafe>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("CountZeros: {mode}");
+            check_count_zeros::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, count_zeros);
+    test_integer_unary!(run_test, i16, count_zeros);
+    test_integer_unary!(run_test, i32, count_zeros);
+    test_integer_unary!(run_test, i64, count_zeros);
+    test_integer_unary!(run_test, i128, count_zeros);
+
+    test_integer_unary!(run_test, u8, count_zeros);
+    test_integer_unary!(run_test, u16, count_zeros);
+    test_integer_unary!(run_test, u32, count_zeros);
+    test_integer_unary!(run_test, u64, count_zeros);
+    test_integer_unary!(run_test, u128, count_zeros);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, count_zeros, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, count_zeros, exhaustive);
+}
@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Gcd<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the greatest common divisor of `self` and `other`, using the binary GCD algorithm.
+    fn gcd(&self, other: &Integer<E, I>) -> Self::Output {
+        match I::is_signed() {
+            true => E::halt("Taking the greatest common divisor of signed integers is not supported"),
+            false => {
+                // Initialize the operands, and the power of two dividing both operands.
+                let (mut a, mut b) = (self.clone(), other.clone());
+                let mut factor = Integer::one();
+
+                // Note: Each step that is not done removes at least one bit from `a` or `b`,
+                // so `2 * I::BITS` steps suffice for one of the operands to reach zero.
+                for _ in 0..2 * I::BITS {
+                    // The algorithm is done once either operand is zero.
+                    let is_done = a.is_zero() | b.is_zero();
+                    let is_a_even = !&a.bits_le[0];
+                    let is_b_even = !&b.bits_le[0];
+                    let is_a_less_than_b = a.is_less_than(&b);
+
+                    // If both operands are odd, halve the difference of the larger and the smaller operand.
+                    let half_a_minus_b = a.sub_wrapped(&b).halve();
+                    let half_b_minus_a = b.sub_wrapped(&a).halve();
+                    let odd_a = Integer::ternary(&is_a_less_than_b, &a, &half_a_minus_b);
+                    let odd_b = Integer::ternary(&is_a_less_than_b, &half_b_minus_a, &b);
+
+                    // If an operand is even, halve it, and otherwise keep it unless both operands are odd.
+                    let next_a = Integer::ternary(&is_a_even, &a.halve(), &Integer::ternary(&is_b_even, &a, &odd_a));
+                    let next_b = Integer::ternary(&is_b_even, &b.halve(), &Integer::ternary(&is_a_even, &b, &odd_b));
+                    // If both operands are even, double the common factor.
+                    let next_factor = Integer::ternary(&(is_a_even & is_b_even), &factor.double(), &factor);
+
+                    a = Integer::ternary(&is_done, &a, &next_a);
+                    b = Integer::ternary(&is_done, &b, &next_b);
+                    factor = Integer::ternary(&is_done, &factor, &next_factor);
+                }
+
+                // Note: One of the operands is now zero, so the bitwise OR selects the other operand.
+                (a | b).mul_wrapped(&factor)
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `self` shifted right by one bit, which incurs no constraints.
+    fn halve(&self) -> Self {
+        let mut bits_le = self.bits_le[1..].to_vec();
+        bits_le.push(Boolean::constant(false));
+        Integer { bits_le, phantom: Default::default() }
+    }
+
+    /// Returns `self` shifted left by one bit, which incurs no constraints.
+    fn double(&self) -> Self {
+        let mut bits_le = vec![Boolean::constant(false)];
+        bits_le.extend_from_slice(&self.bits_le[..self.bits_le.len() - 1]);
+        Integer { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 4;
+
+    fn check_gcd<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+
+        match I::is_signed() {
+            true => check_operation_halts(&a, &b, Integer::gcd),
+            false => {
+                let expected = first.gcd(&second);
+                Circuit::scope(name, || {
+                    let candidate = a.gcd(&b);
+                    assert_eq!(expected, candidate.eject_value());
+                    if mode_a.is_constant() && mode_b.is_constant() {
+                        assert_scope!(0, 0, 0, 0);
+                    }
+                });
+                assert!(Circuit::is_satisfied());
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Gcd: {mode_a} {mode_b} {i}");
+            check_gcd::<I>(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Gcd by Self: {mode_a} {mode_b} {i}");
+            check_gcd::<I>(&name, first, first, mode_a, mode_b);
+
+            let name = format!("Gcd by Zero: {mode_a} {mode_b} {i}");
+            check_gcd::<I>(&name, first, console::Integer::zero(), mode_a, mode_b);
+            check_gcd::<I>(&name, console::Integer::zero(), second, mode_a, mode_b);
+        }
+
+        // Check corner cases.
+        check_gcd::<I>("0 GCD 0", console::Integer::zero(), console::Integer::zero(), mode_a, mode_b);
+        check_gcd::<I>("1 GCD MAX", console::Integer::one(), console::Integer::MAX, mode_a, mode_b);
+        check_gcd::<I>("MAX GCD MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_gcd_known_values() {
+        for (first, second, expected) in
+            [(12u8, 18u8, 6u8), (17, 5, 1), (0, 9, 9), (9, 0, 9), (0, 0, 0), (128, 96, 32), (255, 85, 85)]
+        {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let a = Integer::<Circuit, u8>::new(mode, console::Integer::new(first));
+                let b = Integer::<Circuit, u8>::new(mode, console::Integer::new(second));
+                assert_eq!(expected, *a.gcd(&b).eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, gcd);
+    test_integer_binary!(run_test, i16, gcd);
+    test_integer_binary!(run_test, i32, gcd);
+    test_integer_binary!(run_test, i64, gcd);
+    test_integer_binary!(run_test, i128, gcd);
+
+    test_integer_binary!(run_test, u8, gcd);
+    test_integer_binary!(run_test, u16, gcd);
+    test_integer_binary!(run_test, u32, gcd);
+    test_integer_binary!(run_test, u64, gcd);
+    test_integer_binary!(run_test, u128, gcd);
+}
@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> GetBit<U32<E>> for Integer<E, I> {
+    type Output = Boolean<E>;
+
+    /// Returns the bit of `self` at the given index, in little-endian order.
+    fn get_bit(&self, index: &U32<E>) -> Self::Output {
+        // If the index is a constant, return the bit directly, which incurs no constraints.
+        if index.is_constant() {
+            match self.bits_le.get(*index.eject_value() as usize) {
+                Some(bit) => bit.clone(),
+                None => E::halt(format!(
+                    "Bit index {} is out of range for {}",
+                    index.eject_value(),
+                    console::Integer::<E::Network, I>::type_name()
+                )),
+            }
+        } else {
+            // Note: `I::BITS` is a power of two, so the lower `log2(I::BITS)` bits of the index select the bit.
+            let (selector_bits, upper_bits) = index.bits_le.split_at(I::BITS.trailing_zeros() as usize);
+
+            // Ensure the index is less than `I::BITS`, by checking the upper bits of the index are zero.
+            for bit in upper_bits {
+                E::assert_eq(bit, E::zero());
+            }
+
+            // Select the bit by halving the candidates for each selector bit, from least to most significant.
+            let mut candidates = self.bits_le.clone();
+            for selector in selector_bits {
+                candidates = candidates.chunks(2).map(|pair| Boolean::ternary(selector, &pair[1], &pair[0])).collect();
+            }
+            match candidates.pop() {
+                Some(bit) if candidates.is_empty() => bit,
+                _ => E::halt("Malformed integer detected while retrieving a bit"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 8;
+
+    fn check_get_bit<I: IntegerType + UnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        index: u32,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, value);
+        let b = U32::<Circuit>::new(mode_b, console::U32::new(index));
+
+        if index as u64 >= I::BITS {
+            match mode_b {
+                // A constant out-of-range index halts.
+                Mode::Constant => {
+                    check_operation_halts(&a, &b, |a: &Integer<Circuit, I>, b: &U32<Circuit>| a.get_bit(b))
+                }
+                // A variable out-of-range index is unsatisfiable.
+                _ => {
+                    let _candidate = a.get_bit(&b);
+                    assert!(!Circuit::is_satisfied(), "Out-of-range index {index} should not be satisfied");
+                }
+            }
+        } else {
+            let expected = value.get_bit(&console::U32::new(index));
+            Circuit::scope(name, || {
+                let candidate = a.get_bit(&b);
+                assert_eq!(expected, candidate.eject_value());
+                match mode_b {
+                    Mode::Constant => assert_scope!(0, 0, 0, 0),
+                    _ => assert_scope!(<=I::BITS, <=0, <=I::BITS, <=I::BITS + 32),
+                }
+            });
+            assert!(Circuit::is_satisfied());
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            // Check every index in range, and a few indices past the boundary.
+            for index in 0..(I::BITS as u32 + 2) {
+                let name = format!("GetBit: {mode_a} {mode_b} {i} {index}");
+                check_get_bit::<I>(&name, value, index, mode_a, mode_b);
+            }
+            check_get_bit::<I>("GetBit: u32::MAX", value, u32::MAX, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_get_bit_known_values() {
+        // 0b1010_0101
+        let value = console::Integer::<<Circuit as Environment>::Network, u8>::new(0xa5);
+        for (index, expected) in [true, false, true, false, false, true, false, true].into_iter().enumerate() {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let a = Integer::<Circuit, u8>::new(mode, value);
+                let b = U32::<Circuit>::new(mode, console::U32::new(index as u32));
+                assert_eq!(expected, a.get_bit(&b).eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+
+        // The sign bit of a negative integer is set.
+        let value = console::Integer::<<Circuit as Environment>::Network, i64>::new(-2);
+        let a = Integer::<Circuit, i64>::new(Mode::Private, value);
+        assert!(!a.get_bit(&U32::new(Mode::Private, console::U32::new(0))).eject_value());
+        assert!(a.get_bit(&U32::new(Mode::Private, console::U32::new(63))).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    test_integer_binary!(run_test, i8, get_bit);
+    test_integer_binary!(run_test, i16, get_bit);
+    test_integer_binary!(run_test, i32, get_bit);
+    test_integer_binary!(run_test, i64, get_bit);
+    test_integer_binary!(run_test, i128, get_bit);
+
+    test_integer_binary!(run_test, u8, get_bit);
+    test_integer_binary!(run_test, u16, get_bit);
+    test_integer_binary!(run_test, u32, get_bit);
+    test_integer_binary!(run_test, u64, get_bit);
+    test_integer_binary!(run_test, u128, get_bit);
+}
@@ -14,17 +14,26 @@
 
 use super::*;
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Ensures at compile time that the number of bits in the integer is less than the base field modulus.
+    const FITS_IN_BASE_FIELD: () = assert!(I::BITS < E::BaseField::SIZE_IN_BITS as u64);
+}
+
 impl<E: Environment, I: IntegerType> FromField for Integer<E, I> {
     type Field = Field<E>;
 
     /// Casts an integer from a base field.
+    /// This method enforces that the field element is within the integer range,
+    /// so a field element with any upper bits set will not satisfy the circuit.
     fn from_field(field: Self::Field) -> Self {
         // Note: We are reconstituting the integer from the base field.
         // This is safe as the number of bits in the integer is less than the base field modulus,
         // and thus will always fit within a single base field element.
-        debug_assert!(I::BITS < E::BaseField::size_in_bits() as u64);
+        let () = Self::FITS_IN_BASE_FIELD;
 
         // Extract the integer bits from the field element, **without** a carry bit.
+        // For signed integers, the bits are in two's complement form, so the most significant bit is the sign bit.
+        // Note: `to_lower_bits_le` enforces that the upper bits of the field element are zero.
         let bits_le = field.to_lower_bits_le(I::BITS as usize);
 
         // Return the integer.
@@ -58,6 +67,47 @@ mod tests {
         }
     }
 
+    fn check_from_field_negative<I: IntegerType>(mode: Mode, rng: &mut TestRng) {
+        // Sample random negative integers, starting with `-1`.
+        let negatives = (0..ITERATIONS).map(|i| match i {
+            0 => I::zero().wrapping_sub(&I::one()),
+            _ => I::MIN | I::rand(rng),
+        });
+
+        for (i, expected) in negatives.enumerate() {
+            let candidate = Integer::<Circuit, I>::new(mode, expected).to_field();
+
+            Circuit::scope(format!("{mode} {expected} {i}"), || {
+                // Perform the operation, which must recover the original two's complement value.
+                let candidate = Integer::<Circuit, I>::from_field(candidate);
+                assert_eq!(expected, candidate.eject_value());
+                match mode {
+                    Mode::Constant => assert_scope!(I::BITS, 0, 0, 0),
+                    _ => assert_scope!(0, 0, I::BITS, I::BITS + 1),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_from_field_out_of_range<I: IntegerType>(mode: Mode, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            // Sample a random integer, and shift a field element beyond the integer range.
+            let expected = Uniform::rand(rng);
+            let shift = (0..I::BITS).fold(Field::<Circuit>::one(), |shift, _| shift.double());
+            let candidate = Integer::<Circuit, I>::new(mode, expected).to_field() + shift;
+
+            Circuit::scope(format!("{mode} {expected} {i}"), || {
+                // Perform the operation.
+                let _candidate = Integer::<Circuit, I>::from_field(candidate);
+                assert_scope!(0, 0, I::BITS, I::BITS + 1);
+                // Ensure the high-bit-zero constraint is not satisfied.
+                assert!(!Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_u8_from_field() {
         let mut rng = TestRng::default();
@@ -66,6 +116,8 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Public, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -76,6 +128,11 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_negative::<I>(Mode::Constant, &mut rng);
+        check_from_field_negative::<I>(Mode::Public, &mut rng);
+        check_from_field_negative::<I>(Mode::Private, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Public, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -86,6 +143,8 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Public, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -96,6 +155,11 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_negative::<I>(Mode::Constant, &mut rng);
+        check_from_field_negative::<I>(Mode::Public, &mut rng);
+        check_from_field_negative::<I>(Mode::Private, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Public, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -106,6 +170,8 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Public, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -116,6 +182,11 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_negative::<I>(Mode::Constant, &mut rng);
+        check_from_field_negative::<I>(Mode::Public, &mut rng);
+        check_from_field_negative::<I>(Mode::Private, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Public, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -126,6 +197,8 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Public, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -136,6 +209,11 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_negative::<I>(Mode::Constant, &mut rng);
+        check_from_field_negative::<I>(Mode::Public, &mut rng);
+        check_from_field_negative::<I>(Mode::Private, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Public, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -146,6 +224,8 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Public, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -156,5 +236,10 @@ mod tests {
         check_from_field::<I>(Mode::Constant, &mut rng);
         check_from_field::<I>(Mode::Public, &mut rng);
         check_from_field::<I>(Mode::Private, &mut rng);
+        check_from_field_negative::<I>(Mode::Constant, &mut rng);
+        check_from_field_negative::<I>(Mode::Public, &mut rng);
+        check_from_field_negative::<I>(Mode::Private, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Public, &mut rng);
+        check_from_field_out_of_range::<I>(Mode::Private, &mut rng);
     }
 }
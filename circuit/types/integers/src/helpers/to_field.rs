@@ -18,6 +18,11 @@ impl<E: Environment, I: IntegerType> ToField for Integer<E, I> {
     type Field = Field<E>;
 
     /// Casts an integer into a base field.
+    ///
+    /// The field element is formed from the integer bits, zero-extended to the size of the base field.
+    /// For signed integers, the bits are in two's complement form, so the upper bits of the field element
+    /// are always zero, and a negative integer `x` maps to `2^BITS + x` (e.g. `-1i8` maps to `255field`).
+    /// This is the encoding expected by `from_field`, so `from_field(to_field(x)) == x` for every integer.
     fn to_field(&self) -> Self::Field {
         // Note: We are reconstituting the integer as a base field.
         // This is safe as the number of bits in the integer is less than the base field modulus,
@@ -68,6 +73,27 @@ mod tests {
         }
     }
 
+    fn check_to_field_round_trip<I: IntegerType>(mode: Mode, rng: &mut TestRng) {
+        // Check the boundary values, followed by random integers.
+        let boundaries = [I::zero(), I::one(), I::zero().wrapping_sub(&I::one()), I::MIN, I::MAX];
+        let values = boundaries.into_iter().chain((0..ITERATIONS).map(|_| Uniform::rand(rng)));
+
+        for (i, expected) in values.enumerate() {
+            let candidate = Integer::<Circuit, I>::new(mode, expected);
+
+            Circuit::scope(format!("{mode} {expected} {i}"), || {
+                // Perform the round trip.
+                let candidate = Integer::<Circuit, I>::from_field(candidate.to_field());
+                assert_eq!(expected, candidate.eject_value());
+                match mode {
+                    Mode::Constant => assert_scope!(I::BITS, 0, 0, 0),
+                    _ => assert_scope!(0, 0, I::BITS, I::BITS + 1),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_u8_to_field() {
         let mut rng = TestRng::default();
@@ -76,6 +102,9 @@ mod tests {
         check_to_field::<I>(Mode::Constant, &mut rng);
         check_to_field::<I>(Mode::Public, &mut rng);
         check_to_field::<I>(Mode::Private, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Constant, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Public, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -86,6 +115,9 @@ mod tests {
         check_to_field::<I>(Mode::Constant, &mut rng);
         check_to_field::<I>(Mode::Public, &mut rng);
         check_to_field::<I>(Mode::Private, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Constant, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Public, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -96,6 +128,9 @@ mod tests {
         check_to_field::<I>(Mode::Constant, &mut rng);
         check_to_field::<I>(Mode::Public, &mut rng);
         check_to_field::<I>(Mode::Private, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Constant, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Public, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -106,6 +141,9 @@ mod tests {
         check_to_field::<I>(Mode::Constant, &mut rng);
         check_to_field::<I>(Mode::Public, &mut rng);
         check_to_field::<I>(Mode::Private, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Constant, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Public, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -116,6 +154,9 @@ mod tests {
         check_to_field::<I>(Mode::Constant, &mut rng);
         check_to_field::<I>(Mode::Public, &mut rng);
         check_to_field::<I>(Mode::Private, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Constant, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Public, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -126,6 +167,9 @@ mod tests {
         check_to_field::<I>(Mode::Constant, &mut rng);
         check_to_field::<I>(Mode::Public, &mut rng);
         check_to_field::<I>(Mode::Private, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Constant, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Public, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -136,6 +180,9 @@ mod tests {
         check_to_field::<I>(Mode::Constant, &mut rng);
         check_to_field::<I>(Mode::Public, &mut rng);
         check_to_field::<I>(Mode::Private, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Constant, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Public, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -146,6 +193,9 @@ mod tests {
         check_to_field::<I>(Mode::Constant, &mut rng);
         check_to_field::<I>(Mode::Public, &mut rng);
         check_to_field::<I>(Mode::Private, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Constant, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Public, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -156,6 +206,9 @@ mod tests {
         check_to_field::<I>(Mode::Constant, &mut rng);
         check_to_field::<I>(Mode::Public, &mut rng);
         check_to_field::<I>(Mode::Private, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Constant, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Public, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Private, &mut rng);
     }
 
     #[test]
@@ -166,5 +219,8 @@ mod tests {
         check_to_field::<I>(Mode::Constant, &mut rng);
         check_to_field::<I>(Mode::Public, &mut rng);
         check_to_field::<I>(Mode::Private, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Constant, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Public, &mut rng);
+        check_to_field_round_trip::<I>(Mode::Private, &mut rng);
     }
 }
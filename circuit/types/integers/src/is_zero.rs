@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> IsZero for Integer<E, I> {
+    type Output = Boolean<E>;
+
+    ///
+    /// Returns `true` if `self` is zero.
+    ///
+    /// This method costs 3 constraints, regardless of the value of `self`.
+    ///
+    fn is_equal_to_zero(&self) -> Self::Output {
+        // Note: The integer is checked as a field element, to avoid allocating a constant zero integer.
+        self.to_field().is_zero()
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn IsZero<Output = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(1, 0, 0, 0),
+            false => Count::is(0, 0, 2, 3),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn IsZero<Output = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_is_equal_to_zero<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, first);
+        Circuit::scope(name, || {
+            let candidate = a.is_equal_to_zero();
+            assert_eq!(*first.is_equal_to_zero(), candidate.eject_value());
+            assert_count!(IsZero(Integer<I>) => Boolean, &mode);
+            assert_output_mode!(IsZero(Integer<I>) => Boolean, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("IsZero: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_is_equal_to_zero::<I>(&name, value, mode);
+        }
+
+        // Check the 0, 1, min, and max cases.
+        check_is_equal_to_zero::<I>(&format!("IsZero: {mode} zero"), console::Integer::zero(), mode);
+        check_is_equal_to_zero::<I>(&format!("IsZero: {mode} one"), console::Integer::one(), mode);
+        check_is_equal_to_zero::<I>(&format!("IsZero: {mode} min"), console::Integer::new(I::MIN), mode);
+        check_is_equal_to_zero::<I>(&format!("IsZero: {mode} max"), console::Integer::new(I::MAX), mode);
+    }
+
+    test_integer_unary!(run_test, i8, is_zero);
+    test_integer_unary!(run_test, i16, is_zero);
+    test_integer_unary!(run_test, i32, is_zero);
+    test_integer_unary!(run_test, i64, is_zero);
+    test_integer_unary!(run_test, i128, is_zero);
+
+    test_integer_unary!(run_test, u8, is_zero);
+    test_integer_unary!(run_test, u16, is_zero);
+    test_integer_unary!(run_test, u32, is_zero);
+    test_integer_unary!(run_test, u64, is_zero);
+    test_integer_unary!(run_test, u128, is_zero);
+}
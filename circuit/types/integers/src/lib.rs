@@ -23,14 +23,25 @@ pub mod abs_wrapped;
 pub mod add_checked;
 pub mod add_wrapped;
 pub mod and;
+pub mod cast;
 pub mod compare;
+pub mod count_leading_sign_bits;
+pub mod count_ones;
+pub mod count_zeros;
 pub mod div_checked;
 pub mod div_wrapped;
 pub mod equal;
+pub mod gcd;
+pub mod get_bit;
+pub mod is_zero;
+pub mod leading_zeros;
 pub mod modulo;
 pub mod mul_checked;
 pub mod mul_wrapped;
+pub mod nand;
 pub mod neg;
+pub mod neg_wrapped;
+pub mod nor;
 pub mod not;
 pub mod or;
 pub mod pow_checked;
@@ -43,7 +54,9 @@ pub mod shr_checked;
 pub mod shr_wrapped;
 pub mod sub_checked;
 pub mod sub_wrapped;
+pub mod swap_bytes;
 pub mod ternary;
+pub mod trailing_zeros;
 pub mod xor;
 
 pub type I8<E> = Integer<E, i8>;
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Nand<Self> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns `NOT (a AND b)`, for each bit of `self` and `other`.
+    fn nand(&self, other: &Self) -> Self::Output {
+        Self {
+            bits_le: self.bits_le.iter().zip_eq(other.bits_le.iter()).map(|(a, b)| a.nand(b)).collect(),
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Nand<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, _) | (_, Mode::Constant) => Count::is(0, 0, 0, 0),
+            (_, _) => Count::is(0, 0, I::BITS, I::BITS),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Nand<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0.mode(), case.1.mode()) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (Mode::Constant, mode_b) => match &case.0 {
+                // Determine if the constant is all zeros.
+                CircuitType::Constant(constant) => match constant.eject_value().is_zero() {
+                    true => Mode::Constant,
+                    false => Mode::Private,
+                },
+                _ => E::halt(format!("The constant is required to determine the output mode of Constant AND {mode_b}")),
+            },
+            (mode_a, Mode::Constant) => match &case.1 {
+                // Determine if the constant is all zeros.
+                CircuitType::Constant(constant) => match constant.eject_value().is_zero() {
+                    true => Mode::Constant,
+                    false => Mode::Private,
+                },
+                _ => E::halt(format!("The constant is required to determine the output mode of {mode_a} AND Constant")),
+            },
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 64;
+
+    fn check_nand<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::new(mode_b, second);
+        let expected = first.nand(&second);
+        Circuit::scope(name, || {
+            let candidate = a.nand(&b);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(Nand(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(Nand(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Nand: ({mode_a} AND {mode_b}) {i}");
+            check_nand::<I>(&name, first, second, mode_a, mode_b);
+            check_nand::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+        }
+
+        // Check cases common to signed and unsigned integers.
+        check_nand::<I>("0 AND MAX", console::Integer::zero(), console::Integer::MAX, mode_a, mode_b);
+        check_nand::<I>("MAX AND 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+        check_nand::<I>("0 AND MIN", console::Integer::zero(), console::Integer::MIN, mode_a, mode_b);
+        check_nand::<I>("MIN AND 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
+        check_nand::<I>("0 AND 0", console::Integer::zero(), console::Integer::zero(), mode_a, mode_b);
+        check_nand::<I>("!0 AND !0", !console::Integer::zero(), !console::Integer::zero(), mode_a, mode_b);
+    }
+
+    test_integer_binary!(run_test, i8, nand);
+    test_integer_binary!(run_test, i16, nand);
+    test_integer_binary!(run_test, i32, nand);
+    test_integer_binary!(run_test, i64, nand);
+    test_integer_binary!(run_test, i128, nand);
+
+    test_integer_binary!(run_test, u8, nand);
+    test_integer_binary!(run_test, u16, nand);
+    test_integer_binary!(run_test, u32, nand);
+    test_integer_binary!(run_test, u64, nand);
+    test_integer_binary!(run_test, u128, nand);
+}
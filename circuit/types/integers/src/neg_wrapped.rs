@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> NegWrapped for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Performs the unary `-` operation, wrapping around at the boundary of the type.
+    fn neg_wrapped(self) -> Self::Output {
+        (&self).neg_wrapped()
+    }
+}
+
+impl<E: Environment, I: IntegerType> NegWrapped for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Performs the unary `-` operation, wrapping around at the boundary of the type.
+    fn neg_wrapped(self) -> Self::Output {
+        match I::is_signed() {
+            // Note: This addition wraps, so that `-Integer::MIN` returns `Integer::MIN`.
+            true => Integer::one().add_wrapped(&!self),
+            // Note: `halt` is necessary since negation is not defined for unsigned integers.
+            false => E::halt("Attempted to negate an unsigned integer"),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn NegWrapped<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match I::is_signed() {
+            false => E::halt("Unsigned integers cannot be negated"),
+            true => match case {
+                Mode::Constant => Count::is(2 * I::BITS, 0, 0, 0),
+                _ => Count::is(I::BITS, 0, I::BITS + 1, I::BITS + 2),
+            },
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn NegWrapped<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::{ops::RangeInclusive, panic::UnwindSafe};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_neg_wrapped<I: IntegerType + UnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = value.wrapping_neg();
+        Circuit::scope(name, || {
+            let candidate = a.neg_wrapped();
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_count!(NegWrapped(Integer<I>) => Integer<I>, &mode);
+            assert_output_mode!(NegWrapped(Integer<I>) => Integer<I>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        // Check the 0 case.
+        check_neg_wrapped::<I>(&format!("NegWrapped: {mode} zero"), console::Integer::zero(), mode);
+        // Check the 1 case.
+        check_neg_wrapped::<I>(&format!("NegWrapped: {mode} one"), console::Integer::one(), mode);
+        // Check the console::Integer::MIN (wrapped) case.
+        check_neg_wrapped::<I>(&format!("NegWrapped: {mode} min"), console::Integer::MIN, mode);
+        // Check random values.
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            check_neg_wrapped::<I>(&format!("NegWrapped: {mode} {i}"), value, mode);
+        }
+    }
+
+    fn assert_unsigned_neg_wrapped_halts<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let candidate = Integer::<Circuit, I>::new(mode, Uniform::rand(&mut TestRng::default()));
+        let operation = std::panic::catch_unwind(|| candidate.neg_wrapped());
+        assert!(operation.is_err());
+    }
+
+    fn run_exhaustive_test<I: IntegerType + UnwindSafe>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("NegWrapped: {mode}");
+            check_neg_wrapped::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, neg_wrapped);
+    test_integer_unary!(run_test, i16, neg_wrapped);
+    test_integer_unary!(run_test, i32, neg_wrapped);
+    test_integer_unary!(run_test, i64, neg_wrapped);
+    test_integer_unary!(run_test, i128, neg_wrapped);
+
+    test_integer_unary!(assert_unsigned_neg_wrapped_halts, u8, neg_wrapped);
+    test_integer_unary!(assert_unsigned_neg_wrapped_halts, u16, neg_wrapped);
+    test_integer_unary!(assert_unsigned_neg_wrapped_halts, u32, neg_wrapped);
+    test_integer_unary!(assert_unsigned_neg_wrapped_halts, u64, neg_wrapped);
+    test_integer_unary!(assert_unsigned_neg_wrapped_halts, u128, neg_wrapped);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, neg_wrapped, exhaustive);
+}
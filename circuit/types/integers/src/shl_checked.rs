@@ -234,6 +234,18 @@ mod tests {
             let name = format!("Zero: {mode_a} << {mode_b} {i}");
             check_shl::<I, M>(&name, console::Integer::zero(), second, mode_a, mode_b);
         }
+
+        // Check that shifting by the amounts around the bit width is computed correctly.
+        for shift in [I::BITS - 1, I::BITS, I::BITS + 1] {
+            let first = Uniform::rand(&mut rng);
+            let second = console::Integer::new(M::try_from(shift).unwrap_or_default());
+
+            let name = format!("Boundary: {mode_a} << {mode_b} {shift}");
+            check_shl::<I, M>(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Boundary One: {mode_a} << {mode_b} {shift}");
+            check_shl::<I, M>(&name, console::Integer::one(), second, mode_a, mode_b);
+        }
     }
 
     fn run_exhaustive_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe + TryFrom<u64>>(
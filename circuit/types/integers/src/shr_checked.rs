@@ -183,7 +183,10 @@ mod tests {
         Circuit::reset();
     }
 
-    fn run_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+    fn run_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe + TryFrom<u64>>(
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
         let mut rng = TestRng::default();
 
         for i in 0..ITERATIONS {
@@ -197,6 +200,18 @@ mod tests {
             let name = format!("Half: {mode_a} >> {mode_b} {i}");
             check_shr::<I, M>(&name, first, console::Integer::one(), mode_a, mode_b);
         }
+
+        // Check that shifting by the amounts around the bit width is computed correctly.
+        for shift in [I::BITS - 1, I::BITS, I::BITS + 1] {
+            let first = Uniform::rand(&mut rng);
+            let second = console::Integer::new(M::try_from(shift).unwrap_or_default());
+
+            let name = format!("Boundary: {mode_a} >> {mode_b} {shift}");
+            check_shr::<I, M>(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Boundary Min: {mode_a} >> {mode_b} {shift}");
+            check_shr::<I, M>(&name, console::Integer::new(I::MIN), second, mode_a, mode_b);
+        }
     }
 
     fn run_exhaustive_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
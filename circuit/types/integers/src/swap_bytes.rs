@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> SwapBytes for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `self` with the order of its bytes reversed.
+    fn swap_bytes(&self) -> Self::Output {
+        if I::BITS <= 8 {
+            E::halt(format!("Swapping the bytes of {} is not supported", Self::type_name()))
+        }
+
+        // Reverse the order of the bytes, while preserving the order of the bits within each byte.
+        let bits_le = self.bits_le.chunks(8).rev().flatten().cloned().collect();
+        Integer { bits_le, phantom: Default::default() }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn SwapBytes<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn SwapBytes<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        *case
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_swap_bytes<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, first);
+        let expected = first.swap_bytes();
+
+        Circuit::scope(name, || {
+            let candidate = a.swap_bytes();
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(SwapBytes(Integer<I>) => Integer<I>, &mode);
+            assert_output_mode!(SwapBytes(Integer<I>) => Integer<I>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("SwapBytes: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_swap_bytes::<I>(&name, value, mode);
+        }
+
+        // Check the zero, one, and max cases.
+        check_swap_bytes::<I>(&format!("SwapBytes: {mode} zero"), console::Integer::zero(), mode);
+        check_swap_bytes::<I>(&format!("SwapBytes: {mode} one"), console::Integer::one(), mode);
+        check_swap_bytes::<I>(&format!("SwapBytes: {mode} max"), console::Integer::new(I::MAX), mode);
+    }
+
+    test_integer_unary!(run_test, i16, swap_bytes);
+    test_integer_unary!(run_test, i32, swap_bytes);
+    test_integer_unary!(run_test, i64, swap_bytes);
+    test_integer_unary!(run_test, i128, swap_bytes);
+
+    test_integer_unary!(run_test, u16, swap_bytes);
+    test_integer_unary!(run_test, u32, swap_bytes);
+    test_integer_unary!(run_test, u64, swap_bytes);
+    test_integer_unary!(run_test, u128, swap_bytes);
+}
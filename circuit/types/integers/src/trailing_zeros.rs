@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> TrailingZeros for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    /// If `self` is zero, this returns the number of bits in the integer.
+    fn trailing_zeros(&self) -> Self::Output {
+        // Compute the prefix-OR of the bits, from least to most significant, tracking whether
+        // every bit seen so far is zero, and count the number of such bits.
+        let mut bits = self.bits_le.iter();
+        let mut is_trailing = match bits.next() {
+            Some(bit) => !bit,
+            // Note: \`E::halt\` should never be invoked as \`I::BITS\` is greater than zero.
+            None => E::halt("Malformed integer detected while counting the trailing zeros"),
+        };
+        let mut count = Field::from_boolean(&is_trailing);
+        for bit in bits {
+            is_trailing = is_trailing & !bit;
+            count += Field::from_boolean(&is_trailing);
+        }
+
+        U32::from_field(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::{ops::RangeInclusive, panic::UnwindSafe};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_trailing_zeros<I: IntegerType + UnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = value.trailing_zeros();
+        Circuit::scope(name, || {
+            let candidate = a.trailing_zeros();
+            assert_eq!(expected, candidate.eject_value());
+            match mode {
+                Mode::Constant => assert_scope!(32, 0, 0, 0),
+                _ => assert_scope!(0, 0, I::BITS + 31, I::BITS + 32),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("TrailingZeros: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_trailing_zeros::<I>(&name, value, mode);
+        }
+
+        // Check the edge cases.
+        for (i, value) in [
+            console::Integer::zero(),
+            console::Integer::one(),
+            !console::Integer::zero(),
+            console::Integer::MIN,
+            console::Integer::MAX,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let name = format!("TrailingZeros: {mode} edge {i}");
+            check_trailing_zeros::<I>(&name, value, mode);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + UnwindSafe>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("TrailingZeros: {mode}");
+            check_trailing_zeros::<I>(&name, value, mode);
+        }
+    }
+
+    #[test]
+    fn test_trailing_zeros_single_bit() {
+        type I = u16;
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Check zero, which returns the number of bits.
+            let a = Integer::<Circuit, I>::new(mode, console::Integer::zero());
+            assert_eq!(16, *a.trailing_zeros().eject_value());
+            Circuit::reset();
+
+            // Check each single bit set.
+            for index in 0..16u32 {
+                let a = Integer::<Circuit, I>::new(mode, console::Integer::new(1 << index));
+                assert_eq!(1u16.wrapping_shl(index).trailing_zeros(), *a.trailing_zeros().eject_value());
+                Circuit::reset();
+            }
+        }
+    }
+
+    test_integer_unary!(run_test, i8, trailing_zeros);
+    test_integer_unary!(run_test, i16, trailing_zeros);
+    test_integer_unary!(run_test, i32, trailing_zeros);
+    test_integer_unary!(run_test, i64, trailing_zeros);
+    test_integer_unary!(run_test, i128, trailing_zeros);
+
+    test_integer_unary!(run_test, u8, trailing_zeros);
+    test_integer_unary!(run_test, u16, trailing_zeros);
+    test_integer_unary!(run_test, u32, trailing_zeros);
+    test_integer_unary!(run_test, u64, trailing_zeros);
+    test_integer_unary!(run_test, u128, trailing_zeros);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, trailing_zeros, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, trailing_zeros, exhaustive);
+}
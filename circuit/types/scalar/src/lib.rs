@@ -21,6 +21,7 @@ mod helpers;
 pub mod add;
 pub mod compare;
 pub mod equal;
+pub mod is_zero;
 pub mod ternary;
 
 #[cfg(test)]
@@ -63,6 +63,20 @@ pub trait DivWrapped<Rhs: ?Sized = Self> {
     fn div_wrapped(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for computing the greatest common divisor of two values.
+pub trait Gcd<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn gcd(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for raising a value to the power of an exponent, modulo the field modulus.
+pub trait ModPow<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn mod_pow(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for modding two values.
 pub trait Modulo<Rhs: ?Sized = Self> {
     type Output;
@@ -212,6 +226,13 @@ pub trait Inverse {
     fn inverse(&self) -> Result<Self::Output>;
 }
 
+/// Unary operator for retrieving the negated value, wrapping the result if an overflow occurs.
+pub trait NegWrapped {
+    type Output;
+
+    fn neg_wrapped(self) -> Self::Output;
+}
+
 /// Unary operator for retrieving the squared value.
 pub trait Square {
     type Output;
@@ -225,3 +246,10 @@ pub trait SquareRoot {
 
     fn square_root(&self) -> Result<Self::Output>;
 }
+
+/// Unary operator for retrieving the canonical (lexicographically smaller) square root of the value.
+pub trait SquareRootCanonical {
+    type Output;
+
+    fn square_root_canonical(&self) -> Result<Self::Output>;
+}
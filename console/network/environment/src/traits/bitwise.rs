@@ -40,6 +40,54 @@ pub trait Compare<Rhs: ?Sized = Self> {
     fn is_greater_than_or_equal(&self, other: &Rhs) -> Self::Output;
 }
 
+/// Unary operator for counting the leading bits equal to the sign bit, excluding the sign bit itself.
+pub trait CountLeadingSignBits {
+    type Output;
+
+    /// Returns the number of leading bits equal to the sign bit, excluding the sign bit itself.
+    fn count_leading_sign_bits(&self) -> Self::Output;
+}
+
+/// Unary operator for counting the number of bits set to one.
+pub trait CountOnes {
+    type Output;
+
+    /// Returns the number of ones in the binary representation of `self`.
+    fn count_ones(&self) -> Self::Output;
+}
+
+/// Unary operator for counting the number of bits set to zero.
+pub trait CountZeros {
+    type Output;
+
+    /// Returns the number of zeros in the binary representation of `self`.
+    fn count_zeros(&self) -> Self::Output;
+}
+
+/// Binary operator for retrieving the bit at a given index.
+pub trait GetBit<Rhs: ?Sized> {
+    type Output;
+
+    /// Returns the bit of `self` at the given index, in little-endian order.
+    fn get_bit(&self, index: &Rhs) -> Self::Output;
+}
+
+/// Unary operator for checking if a value is the additive identity.
+pub trait IsZero {
+    type Output;
+
+    /// Returns `true` if `self` is zero.
+    fn is_equal_to_zero(&self) -> Self::Output;
+}
+
+/// Unary operator for counting the number of leading zero bits.
+pub trait LeadingZeros {
+    type Output;
+
+    /// Returns the number of leading zeros in the binary representation of `self`.
+    fn leading_zeros(&self) -> Self::Output;
+}
+
 /// Binary operator for performing `NOT (a AND b)`.
 pub trait Nand<Rhs: ?Sized = Self> {
     type Output;
@@ -56,6 +104,14 @@ pub trait Nor<Rhs: ?Sized = Self> {
     fn nor(&self, other: &Rhs) -> Self::Output;
 }
 
+/// Unary operator for reversing the byte order of a value.
+pub trait SwapBytes {
+    type Output;
+
+    /// Returns `self` with the order of its bytes reversed.
+    fn swap_bytes(&self) -> Self::Output;
+}
+
 /// Trait for ternary operations.
 pub trait Ternary {
     type Boolean;
@@ -66,3 +122,11 @@ pub trait Ternary {
     where
         Self: Sized;
 }
+
+/// Unary operator for counting the number of trailing zero bits.
+pub trait TrailingZeros {
+    type Output;
+
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    fn trailing_zeros(&self) -> Self::Output;
+}
@@ -32,6 +32,87 @@ pub fn convert_result<'a, O>(result: ParserResult<'a, O>, input: &'a str) -> Str
     }
 }
 
+/// A parse error, annotated with the position of the input that failed to parse.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the failure, starting from 1.
+    line: usize,
+    /// The column of the failure, starting from 1.
+    column: usize,
+    /// The line of the input containing the failure.
+    snippet: String,
+    /// The token at the position of the failure.
+    token: String,
+}
+
+impl ParseError {
+    /// Initializes a new parse error at the start of `remainder`, which must be a suffix of `input`.
+    /// Leading whitespace and comments in `remainder` are skipped.
+    pub fn new(input: &str, remainder: &str) -> Self {
+        // Skip the leading whitespace and comments.
+        let remainder = match crate::Sanitizer::parse(remainder) {
+            Ok((remainder, _)) => remainder,
+            Err(_) => remainder.trim_start(),
+        };
+        // Compute the byte offset of the failure in the input.
+        let offset = input.len().saturating_sub(remainder.len());
+        let (before, after) = input.split_at(offset);
+        // Compute the line and column of the failure.
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+        // Extract the offending line and token.
+        let line_end = after.find('\n').map_or(input.len(), |index| offset + index);
+        let snippet = input[line_start..line_end].trim().to_string();
+        let token = after.split_whitespace().next().unwrap_or_default().trim_end_matches(';').to_string();
+
+        Self { line, column, snippet, token }
+    }
+
+    /// Initializes a new parse error from the innermost failure of the given result, if it failed.
+    pub fn from_result<O>(input: &str, result: &ParserResult<O>) -> Option<Self> {
+        match result {
+            Err(NomErr::Error(error)) | Err(NomErr::Failure(error)) => {
+                error.errors.first().map(|(remainder, _)| Self::new(input, remainder))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the line of the failure, starting from 1.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column of the failure, starting from 1.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the line of the input containing the failure.
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+
+    /// Returns the token at the position of the failure.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    /// Prints the parse error with its position.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: failed to parse '{}' in \"{}\"",
+            self.line, self.column, self.token, self.snippet
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 /// Operations to parse a string literal into an object.
 pub trait Parser: core::fmt::Display + core::str::FromStr {
     /// Parses a string literal into an object.
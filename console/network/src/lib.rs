@@ -115,6 +115,10 @@ pub trait Network:
     /// The maximum number of fields in data (must not exceed u16::MAX).
     #[allow(clippy::cast_possible_truncation)]
     const MAX_DATA_SIZE_IN_FIELDS: u32 = ((128 * 1024 * 8) / Field::<Self>::SIZE_IN_DATA_BITS) as u32;
+    /// The maximum number of field elements in the input to a Poseidon hash instruction.
+    /// Note: Poseidon absorbs its input at the rate of the hash (2, 4, or 8 field elements per permutation),
+    /// so this bounds `hash.psd2`, the costliest case, to 128 permutations.
+    const MAX_POSEIDON_INPUT_SIZE_IN_FIELDS: usize = 1 << 8; // 256 field elements
 
     /// The minimum number of entries in a struct.
     const MIN_STRUCT_ENTRIES: usize = 1; // This ensures the struct is not empty.
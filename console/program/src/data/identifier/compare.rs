@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Ord for Identifier<N> {
+    /// Ordering is determined by the lexicographic ordering of the identifier strings.
+    /// As the string encoding is independent of the network, so is the ordering.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_string().cmp(&other.to_string())
+    }
+}
+
+impl<N: Network> PartialOrd for Identifier<N> {
+    /// Ordering is determined by the lexicographic ordering of the identifier strings.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_sort() -> Result<()> {
+        let mut candidate = ["foo_bar", "a", "Zebra", "foo", "b1", "bar", "foo_", "aa"]
+            .iter()
+            .map(|string| Identifier::<CurrentNetwork>::from_str(string))
+            .collect::<Result<Vec<_>>>()?;
+        candidate.sort();
+
+        let expected = ["Zebra", "a", "aa", "b1", "bar", "foo", "foo_", "foo_bar"];
+        assert_eq!(expected.to_vec(), candidate.iter().map(|identifier| identifier.to_string()).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ord_is_consistent_with_eq() -> Result<()> {
+        let identifiers = ["foo", "bar", "foo_bar", "a1", "A1"]
+            .iter()
+            .map(|string| Identifier::<CurrentNetwork>::from_str(string))
+            .collect::<Result<Vec<_>>>()?;
+
+        for a in &identifiers {
+            for b in &identifiers {
+                assert_eq!(a == b, a.cmp(b) == Ordering::Equal);
+                assert_eq!(a.cmp(b), b.cmp(a).reverse());
+                assert_eq!(Some(a.cmp(b)), a.partial_cmp(b));
+            }
+        }
+        Ok(())
+    }
+}
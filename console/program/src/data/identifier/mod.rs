@@ -13,10 +13,12 @@
 // limitations under the License.
 
 mod bytes;
+mod compare;
 mod equal;
 mod from_bits;
 mod from_field;
 mod parse;
+mod reserved;
 mod serialize;
 mod size_in_bits;
 mod to_bits;
@@ -34,6 +36,7 @@ use snarkvm_console_types::{prelude::*, Field};
 /// The identifier must be alphanumeric, and may include underscores.
 /// The identifier must not consist solely of underscores.
 /// The identifier must fit within the data capacity of a base field element.
+/// The identifier must not be a reserved word, such as a literal type name.
 #[derive(Copy, Clone)]
 pub struct Identifier<N: Network>(Field<N>, u8); // Number of bytes in the identifier.
 
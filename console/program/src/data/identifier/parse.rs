@@ -52,6 +52,9 @@ impl<N: Network> FromStr for Identifier<N> {
             bail!("Identifier is too large. Identifiers must be <= {max_bytes} bytes long")
         }
 
+        // Ensure the identifier is not a reserved word.
+        ensure!(!Self::is_reserved(identifier), "Identifier '{identifier}' is a reserved word");
+
         // Note: The string bytes themselves are **not** little-endian. Rather, they are order-preserving
         // for reconstructing the string when recovering the field element back into bytes.
         Ok(Self(
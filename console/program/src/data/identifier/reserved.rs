@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::LiteralType;
+
+impl<N: Network> Identifier<N> {
+    /// The reserved words, in addition to the literal type names, that may not be used as identifiers.
+    const RESERVED: &'static [&'static str] = &["record"];
+
+    /// Returns `true` if the given string is a reserved word, such as `field` or `record`.
+    pub(super) fn is_reserved(identifier: &str) -> bool {
+        Self::RESERVED.contains(&identifier) || LiteralType::from_str(identifier).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_reserved() -> Result<()> {
+        // Ensure the literal type names and the reserved words are rejected.
+        for reserved in ["field", "group", "u8", "i128", "record"] {
+            let error = Identifier::<CurrentNetwork>::from_str(reserved).unwrap_err();
+            assert_eq!(format!("Identifier '{reserved}' is a reserved word"), error.to_string());
+            assert!(Identifier::<CurrentNetwork>::parse(reserved).is_err());
+        }
+        // Ensure identifiers that only contain a reserved word are accepted.
+        assert_eq!("fields", Identifier::<CurrentNetwork>::from_str("fields")?.to_string());
+        assert_eq!("u8_max", Identifier::<CurrentNetwork>::from_str("u8_max")?.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_errors() -> Result<()> {
+        let max_bytes = Field::<CurrentNetwork>::size_in_data_bits() / 8;
+
+        // Ensure an identifier at the length boundary is accepted.
+        let identifier = "a".repeat(max_bytes);
+        assert_eq!(identifier, Identifier::<CurrentNetwork>::from_str(&identifier)?.to_string());
+
+        // Ensure each rule reports a distinct error.
+        let error = Identifier::<CurrentNetwork>::from_str(&"a".repeat(max_bytes + 1)).unwrap_err();
+        assert_eq!(
+            format!("Identifier is too large. Identifiers must be <= {max_bytes} bytes long"),
+            error.to_string()
+        );
+        let error = Identifier::<CurrentNetwork>::from_str("1foo").unwrap_err();
+        assert_eq!("Identifier must start with a letter", error.to_string());
+        let error = Identifier::<CurrentNetwork>::from_str("record").unwrap_err();
+        assert_eq!("Identifier 'record' is a reserved word", error.to_string());
+        Ok(())
+    }
+}
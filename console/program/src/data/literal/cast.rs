@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Converts the integer `$value` into an integer literal of type `$to_type`, using the conversion `$convert`.
+macro_rules! cast_into {
+    ($value:expr, $to_type:expr, $convert:ident) => {
+        match $to_type {
+            LiteralType::I8 => Literal::I8(I8::new($convert!($value, i8))),
+            LiteralType::I16 => Literal::I16(I16::new($convert!($value, i16))),
+            LiteralType::I32 => Literal::I32(I32::new($convert!($value, i32))),
+            LiteralType::I64 => Literal::I64(I64::new($convert!($value, i64))),
+            LiteralType::I128 => Literal::I128(I128::new($convert!($value, i128))),
+            LiteralType::U8 => Literal::U8(U8::new($convert!($value, u8))),
+            LiteralType::U16 => Literal::U16(U16::new($convert!($value, u16))),
+            LiteralType::U32 => Literal::U32(U32::new($convert!($value, u32))),
+            LiteralType::U64 => Literal::U64(U64::new($convert!($value, u64))),
+            LiteralType::U128 => Literal::U128(U128::new($convert!($value, u128))),
+            _ => bail!("Cannot cast an integer into a '{}'", $to_type),
+        }
+    };
+}
+
+/// Casts the integer literal `$literal` into an integer literal of type `$to_type`, using the conversion `$convert`.
+macro_rules! cast_integer {
+    ($literal:expr, $to_type:expr, $convert:ident) => {
+        match $literal {
+            Literal::I8(integer) => cast_into!(**integer, $to_type, $convert),
+            Literal::I16(integer) => cast_into!(**integer, $to_type, $convert),
+            Literal::I32(integer) => cast_into!(**integer, $to_type, $convert),
+            Literal::I64(integer) => cast_into!(**integer, $to_type, $convert),
+            Literal::I128(integer) => cast_into!(**integer, $to_type, $convert),
+            Literal::U8(integer) => cast_into!(**integer, $to_type, $convert),
+            Literal::U16(integer) => cast_into!(**integer, $to_type, $convert),
+            Literal::U32(integer) => cast_into!(**integer, $to_type, $convert),
+            Literal::U64(integer) => cast_into!(**integer, $to_type, $convert),
+            Literal::U128(integer) => cast_into!(**integer, $to_type, $convert),
+            literal => bail!("Cannot cast a '{}' into a '{}'", literal.to_type(), $to_type),
+        }
+    };
+}
+
+impl<N: Network> Literal<N> {
+    /// Casts the integer literal into the given integer type, returning an error if the value does not fit.
+    pub fn cast(&self, to_type: LiteralType) -> Result<Self> {
+        macro_rules! checked {
+            ($value:expr, $primitive:ty) => {
+                match <$primitive>::try_from($value) {
+                    Ok(value) => value,
+                    Err(_) => match $value < Zero::zero() {
+                        true => bail!(
+                            "Integer '{}' does not fit in a '{}' ({self} is below {}::MIN)",
+                            $value,
+                            stringify!($primitive),
+                            stringify!($primitive)
+                        ),
+                        false => bail!(
+                            "Integer '{}' does not fit in a '{}' ({self} exceeds {}::MAX)",
+                            $value,
+                            stringify!($primitive),
+                            stringify!($primitive)
+                        ),
+                    },
+                }
+            };
+        }
+        Ok(cast_integer!(self, to_type, checked))
+    }
+
+    /// Casts the integer literal into the given integer type, truncating the value if it does not fit.
+    pub fn cast_lossy(&self, to_type: LiteralType) -> Result<Self> {
+        macro_rules! lossy {
+            ($value:expr, $primitive:ty) => {
+                $value as $primitive
+            };
+        }
+        Ok(cast_integer!(self, to_type, lossy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_cast(literal: &str, to_type: LiteralType, expected: Option<&str>, expected_lossy: &str) -> Result<()> {
+        let literal = Literal::<CurrentNetwork>::from_str(literal)?;
+        match expected {
+            Some(expected) => assert_eq!(literal.cast(to_type)?, Literal::from_str(expected)?),
+            None => assert!(literal.cast(to_type).is_err()),
+        }
+        assert_eq!(literal.cast_lossy(to_type)?, Literal::from_str(expected_lossy)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_widening() -> Result<()> {
+        check_cast("200u8", LiteralType::U64, Some("200u64"), "200u64")?;
+        check_cast("-5i8", LiteralType::I128, Some("-5i128"), "-5i128")?;
+        check_cast("70000u32", LiteralType::I64, Some("70000i64"), "70000i64")
+    }
+
+    #[test]
+    fn test_cast_narrowing() -> Result<()> {
+        check_cast("255u64", LiteralType::U8, Some("255u8"), "255u8")?;
+        check_cast("256u64", LiteralType::U8, None, "0u8")?;
+        check_cast("-129i32", LiteralType::I8, None, "127i8")?;
+        check_cast("-128i32", LiteralType::I8, Some("-128i8"), "-128i8")
+    }
+
+    #[test]
+    fn test_cast_signedness() -> Result<()> {
+        check_cast("-1i8", LiteralType::U8, None, "255u8")?;
+        check_cast("255u8", LiteralType::I8, None, "-1i8")?;
+        check_cast("127u8", LiteralType::I8, Some("127i8"), "127i8")?;
+        check_cast("-1i64", LiteralType::U128, None, "340282366920938463463374607431768211455u128")
+    }
+
+    #[test]
+    fn test_cast_error_message() {
+        let check = |literal: &str, to_type: LiteralType, expected: &str| {
+            let literal = Literal::<CurrentNetwork>::from_str(literal).unwrap();
+            let error = literal.cast(to_type).unwrap_err().to_string();
+            assert!(error.starts_with("Integer '"), "{error}");
+            assert!(error.ends_with(expected), "{error}");
+        };
+        check("256u64", LiteralType::U8, "does not fit in a 'u8' (256u64 exceeds u8::MAX)");
+        check("-129i32", LiteralType::I8, "does not fit in a 'i8' (-129i32 is below i8::MIN)");
+        check("-1i8", LiteralType::U128, "does not fit in a 'u128' (-1i8 is below u128::MIN)");
+    }
+
+    #[test]
+    fn test_cast_non_integer_fails() {
+        let field = Literal::<CurrentNetwork>::from_str("1field").unwrap();
+        assert!(field.cast(LiteralType::U8).is_err());
+        assert!(field.cast_lossy(LiteralType::U8).is_err());
+
+        let integer = Literal::<CurrentNetwork>::from_str("1u8").unwrap();
+        assert!(integer.cast(LiteralType::Field).is_err());
+        assert!(integer.cast_lossy(LiteralType::Boolean).is_err());
+    }
+}
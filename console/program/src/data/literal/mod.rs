@@ -13,12 +13,15 @@
 // limitations under the License.
 
 mod bytes;
+mod cast;
+mod default;
 mod equal;
 mod from_bits;
 mod parse;
 mod sample;
 mod serialize;
 mod size_in_bits;
+mod size_in_bytes;
 mod to_bits;
 mod to_type;
 mod variant;
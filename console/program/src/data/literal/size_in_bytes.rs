@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Literal<N> {
+    /// Returns the number of bytes in the byte encoding of this literal, including the 2-byte variant tag.
+    pub fn size_in_bytes(&self) -> usize {
+        let size = match self {
+            Self::Address(..) => Address::<N>::size_in_bytes(),
+            Self::Boolean(..) => Boolean::<N>::size_in_bytes(),
+            Self::Field(..) => Field::<N>::size_in_bytes(),
+            Self::Group(..) => Group::<N>::size_in_bytes(),
+            Self::I8(..) => I8::<N>::size_in_bytes(),
+            Self::I16(..) => I16::<N>::size_in_bytes(),
+            Self::I32(..) => I32::<N>::size_in_bytes(),
+            Self::I64(..) => I64::<N>::size_in_bytes(),
+            Self::I128(..) => I128::<N>::size_in_bytes(),
+            Self::U8(..) => U8::<N>::size_in_bytes(),
+            Self::U16(..) => U16::<N>::size_in_bytes(),
+            Self::U32(..) => U32::<N>::size_in_bytes(),
+            Self::U64(..) => U64::<N>::size_in_bytes(),
+            Self::U128(..) => U128::<N>::size_in_bytes(),
+            Self::Scalar(..) => Scalar::<N>::size_in_bytes(),
+            // A string is prefixed with its length as a u16.
+            Self::String(string) => 2 + string.len(),
+        };
+        // Account for the u16 variant tag.
+        2 + size
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns `true` if `self` and `other` are equal, without exiting early on a mismatch.
+    ///
+    /// Note: The comparison only walks the full structure for same-typed plaintexts;
+    /// a literal and a struct are immediately reported as unequal.
+    pub fn ct_eq(&self, other: &Self) -> Boolean<N> {
+        match (self, other) {
+            (Self::Literal(a, _), Self::Literal(b, _)) => a.is_equal(b),
+            (Self::Struct(a, _), Self::Struct(b, _)) => {
+                // Check every member, even after a mismatch is found.
+                let mut equal = Boolean::new(a.len() == b.len());
+                for ((name_a, plaintext_a), (name_b, plaintext_b)) in a.iter().zip(b.iter()) {
+                    equal = equal & name_a.is_equal(name_b) & plaintext_a.ct_eq(plaintext_b);
+                }
+                equal
+            }
+            (Self::Literal(..), _) | (Self::Struct(..), _) => Boolean::new(false),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the path and value of every literal in the plaintext, in declaration order.
+    /// If the plaintext is a literal, this returns the literal with an empty path.
+    pub fn flatten(&self) -> Vec<(Vec<Identifier<N>>, Literal<N>)> {
+        let mut leaves = Vec::new();
+        self.flatten_into(&mut Vec::new(), &mut leaves);
+        leaves
+    }
+
+    /// Appends the path and value of every literal in the plaintext to `leaves`, prefixing each path with `path`.
+    fn flatten_into(&self, path: &mut Vec<Identifier<N>>, leaves: &mut Vec<(Vec<Identifier<N>>, Literal<N>)>) {
+        match self {
+            Self::Literal(literal, ..) => leaves.push((path.clone(), literal.clone())),
+            Self::Struct(members, ..) => {
+                for (identifier, member) in members {
+                    path.push(*identifier);
+                    member.flatten_into(path, leaves);
+                    path.pop();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_flatten(plaintext: &str, expected: &[(&str, &str)]) -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(plaintext)?;
+        let leaves = plaintext.flatten();
+        // Ensure the leaves match the expected paths and values, in order.
+        assert_eq!(leaves.len(), expected.len());
+        for ((path, literal), (expected_path, expected_literal)) in leaves.iter().zip_eq(expected) {
+            let path = path.iter().map(|identifier| identifier.to_string()).collect::<Vec<_>>().join(".");
+            assert_eq!(&path, expected_path);
+            assert_eq!(literal, &Literal::from_str(expected_literal)?);
+            // Ensure each non-empty path locates its leaf.
+            if !path.is_empty() {
+                let path = path.split('.').map(Identifier::from_str).collect::<Result<Vec<_>>>()?;
+                assert_eq!(plaintext.find(&path)?, Plaintext::from(literal));
+            }
+        }
+        // Ensure the ordering is stable across calls.
+        assert_eq!(leaves, plaintext.flatten());
+        Ok(())
+    }
+
+    #[test]
+    fn test_flatten_literal() -> Result<()> {
+        check_flatten("5u8", &[("", "5u8")])
+    }
+
+    #[test]
+    fn test_flatten_struct() -> Result<()> {
+        check_flatten("{ b: true, a: 1field, c: 2u64 }", &[("b", "true"), ("a", "1field"), ("c", "2u64")])
+    }
+
+    #[test]
+    fn test_flatten_nested_struct() -> Result<()> {
+        check_flatten(
+            "{ owner: { x: 1u8, y: { z: 2u16, w: 3u32 } }, amount: 4u64, flag: { on: false } }",
+            &[
+                ("owner.x", "1u8"),
+                ("owner.y.z", "2u16"),
+                ("owner.y.w", "3u32"),
+                ("amount", "4u64"),
+                ("flag.on", "false"),
+            ],
+        )
+    }
+}
@@ -17,20 +17,26 @@ use super::*;
 impl<N: Network> FromBits for Plaintext<N> {
     /// Initializes a new plaintext from a list of little-endian bits *without* trailing zeros.
     fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        // Returns the next `size` bits, ensuring the bits are not truncated.
+        let next = |counter: usize, size: usize| match bits_le.get(counter..counter + size) {
+            Some(bits) => Ok(bits),
+            None => Err(anyhow!("Failed to deserialize plaintext: found truncated bits")),
+        };
+
         let mut counter = 0;
 
-        let variant = [bits_le[counter], bits_le[counter + 1]];
+        let variant = next(counter, 2)?;
         counter += 2;
 
         // Literal
         if variant == [false, false] {
-            let literal_variant = u8::from_bits_le(&bits_le[counter..counter + 8])?;
+            let literal_variant = u8::from_bits_le(next(counter, 8)?)?;
             counter += 8;
 
-            let literal_size = u16::from_bits_le(&bits_le[counter..counter + 16])?;
+            let literal_size = u16::from_bits_le(next(counter, 16)?)?;
             counter += 16;
 
-            let literal = Literal::from_bits_le(literal_variant, &bits_le[counter..counter + literal_size as usize])?;
+            let literal = Literal::from_bits_le(literal_variant, next(counter, literal_size as usize)?)?;
 
             // Store the plaintext bits in the cache.
             let cache = OnceCell::new();
@@ -42,21 +48,21 @@ impl<N: Network> FromBits for Plaintext<N> {
         }
         // Struct
         else if variant == [false, true] {
-            let num_members = u8::from_bits_le(&bits_le[counter..counter + 8])?;
+            let num_members = u8::from_bits_le(next(counter, 8)?)?;
             counter += 8;
 
             let mut members = IndexMap::with_capacity(num_members as usize);
             for _ in 0..num_members {
-                let identifier_size = u8::from_bits_le(&bits_le[counter..counter + 8])?;
+                let identifier_size = u8::from_bits_le(next(counter, 8)?)?;
                 counter += 8;
 
-                let identifier = Identifier::from_bits_le(&bits_le[counter..counter + identifier_size as usize])?;
+                let identifier = Identifier::from_bits_le(next(counter, identifier_size as usize)?)?;
                 counter += identifier_size as usize;
 
-                let member_size = u16::from_bits_le(&bits_le[counter..counter + 16])?;
+                let member_size = u16::from_bits_le(next(counter, 16)?)?;
                 counter += 16;
 
-                let value = Plaintext::from_bits_le(&bits_le[counter..counter + member_size as usize])?;
+                let value = Plaintext::from_bits_le(next(counter, member_size as usize)?)?;
                 counter += member_size as usize;
 
                 if members.insert(identifier, value).is_some() {
@@ -80,20 +86,26 @@ impl<N: Network> FromBits for Plaintext<N> {
 
     /// Initializes a new plaintext from a list of big-endian bits *without* trailing zeros.
     fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        // Returns the next `size` bits, ensuring the bits are not truncated.
+        let next = |counter: usize, size: usize| match bits_be.get(counter..counter + size) {
+            Some(bits) => Ok(bits),
+            None => Err(anyhow!("Failed to deserialize plaintext: found truncated bits")),
+        };
+
         let mut counter = 0;
 
-        let variant = [bits_be[counter], bits_be[counter + 1]];
+        let variant = next(counter, 2)?;
         counter += 2;
 
         // Literal
         if variant == [false, false] {
-            let literal_variant = u8::from_bits_be(&bits_be[counter..counter + 8])?;
+            let literal_variant = u8::from_bits_be(next(counter, 8)?)?;
             counter += 8;
 
-            let literal_size = u16::from_bits_be(&bits_be[counter..counter + 16])?;
+            let literal_size = u16::from_bits_be(next(counter, 16)?)?;
             counter += 16;
 
-            let literal = Literal::from_bits_be(literal_variant, &bits_be[counter..counter + literal_size as usize])?;
+            let literal = Literal::from_bits_be(literal_variant, next(counter, literal_size as usize)?)?;
 
             // Store the plaintext bits in the cache.
             let cache = OnceCell::new();
@@ -105,21 +117,21 @@ impl<N: Network> FromBits for Plaintext<N> {
         }
         // Struct
         else if variant == [false, true] {
-            let num_members = u8::from_bits_be(&bits_be[counter..counter + 8])?;
+            let num_members = u8::from_bits_be(next(counter, 8)?)?;
             counter += 8;
 
             let mut members = IndexMap::with_capacity(num_members as usize);
             for _ in 0..num_members {
-                let identifier_size = u8::from_bits_be(&bits_be[counter..counter + 8])?;
+                let identifier_size = u8::from_bits_be(next(counter, 8)?)?;
                 counter += 8;
 
-                let identifier = Identifier::from_bits_be(&bits_be[counter..counter + identifier_size as usize])?;
+                let identifier = Identifier::from_bits_be(next(counter, identifier_size as usize)?)?;
                 counter += identifier_size as usize;
 
-                let member_size = u16::from_bits_be(&bits_be[counter..counter + 16])?;
+                let member_size = u16::from_bits_be(next(counter, 16)?)?;
                 counter += 16;
 
-                let value = Plaintext::from_bits_be(&bits_be[counter..counter + member_size as usize])?;
+                let value = Plaintext::from_bits_be(next(counter, member_size as usize)?)?;
                 counter += member_size as usize;
 
                 if members.insert(identifier, value).is_some() {
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde_json::{json, Map, Value as JsonValue};
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the plaintext as a JSON object, tagged with its type.
+    /// Literals are encoded as strings, so that wide integers are lossless.
+    pub fn to_json(&self) -> JsonValue {
+        match self {
+            Self::Literal(literal, ..) => {
+                json!({ "type": literal.to_type().type_name(), "value": literal.to_string() })
+            }
+            Self::Struct(members, ..) => {
+                let members = members
+                    .iter()
+                    .map(|(identifier, plaintext)| (identifier.to_string(), plaintext.to_json()))
+                    .collect::<Map<_, _>>();
+                json!({ "type": "struct", "members": members })
+            }
+        }
+    }
+
+    /// Returns the plaintext from a JSON object, tagged with its type.
+    pub fn from_json(json: &JsonValue) -> Result<Self> {
+        match json.get("type").and_then(JsonValue::as_str) {
+            Some("struct") => {
+                // Retrieve the members.
+                let members = match json.get("members").and_then(JsonValue::as_object) {
+                    Some(members) => members,
+                    None => bail!("Expected a 'members' object in the struct: {json}"),
+                };
+                // Ensure the number of members is within the maximum limit.
+                ensure!(
+                    members.len() <= N::MAX_STRUCT_ENTRIES,
+                    "Found a plaintext that exceeds size ({})",
+                    members.len()
+                );
+                // Recover the members.
+                let members = members
+                    .iter()
+                    .map(|(identifier, plaintext)| Ok((Identifier::from_str(identifier)?, Self::from_json(plaintext)?)))
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                Ok(Self::Struct(members, Default::default()))
+            }
+            Some(type_name) => {
+                // Retrieve the literal.
+                let literal = match json.get("value").and_then(JsonValue::as_str) {
+                    Some(value) => Literal::from_str(value)?,
+                    None => bail!("Expected a 'value' string in the literal: {json}"),
+                };
+                // Ensure the literal matches the declared type.
+                ensure!(
+                    literal.to_type().type_name() == type_name,
+                    "Expected a literal of type '{type_name}', found '{literal}'"
+                );
+                Ok(Self::from(literal))
+            }
+            None => bail!("Expected a 'type' string in the plaintext: {json}"),
+        }
+    }
+}
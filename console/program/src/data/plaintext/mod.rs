@@ -13,14 +13,19 @@
 // limitations under the License.
 
 mod bytes;
+mod ct_eq;
 mod encrypt;
 mod equal;
 mod find;
+mod flatten;
 mod from_bits;
 mod from_fields;
+mod json;
 mod num_randomizers;
 mod parse;
 mod serialize;
+mod set;
+mod size_in_bytes;
 mod size_in_fields;
 mod to_bits;
 mod to_fields;
@@ -113,6 +113,8 @@ impl<N: Network> Plaintext<N> {
             // Prints the literal, i.e. 10field
             Self::Literal(literal, ..) => write!(f, "{:indent$}{literal}", "", indent = depth * INDENT),
             // Prints the struct, i.e. { first: 10i64, second: 198u64 }
+            // Prints the empty struct, i.e. {}
+            Self::Struct(struct_, ..) if struct_.is_empty() => write!(f, "{{}}"),
             Self::Struct(struct_, ..) => {
                 // Print the opening brace.
                 write!(f, "{{")?;
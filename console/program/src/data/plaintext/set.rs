@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Sets the plaintext member at the given path to the given value.
+    /// The member must be a literal of the same type as the given value.
+    pub fn set(&mut self, path: &[Identifier<N>], new_value: Plaintext<N>) -> Result<()> {
+        match path.split_first() {
+            // Halts if the path is empty.
+            None => bail!("Attempted to set member with an empty path."),
+            Some((first, rest)) => match self {
+                // Halts if the value is not a struct.
+                Self::Literal(..) => bail!("'{self}' is not a struct"),
+                // Update the member (in the value).
+                Self::Struct(members, bits_le) => {
+                    // Retrieve the member.
+                    let member = match members.get_mut(first) {
+                        Some(member) => member,
+                        None => bail!("Failed to locate member '{first}'"),
+                    };
+                    // Update the member, or recursively update its submember.
+                    match rest.is_empty() {
+                        true => member.set_leaf(new_value)?,
+                        false => member.set(rest, new_value)?,
+                    }
+                    // Reset the cached bits, as the struct has changed.
+                    *bits_le = OnceCell::new();
+                    Ok(())
+                }
+            },
+        }
+    }
+
+    /// Replaces this plaintext with the given value, if this plaintext is a literal of the same type.
+    pub(crate) fn set_leaf(&mut self, new_value: Plaintext<N>) -> Result<()> {
+        match (&*self, &new_value) {
+            (Self::Literal(literal, ..), Self::Literal(new_literal, ..)) => ensure!(
+                literal.to_type() == new_literal.to_type(),
+                "Cannot set a '{}' member to a '{}' value",
+                literal.to_type(),
+                new_literal.to_type()
+            ),
+            (Self::Literal(literal, ..), Self::Struct(..)) => {
+                bail!("Cannot set a '{}' member to a struct", literal.to_type())
+            }
+            (Self::Struct(..), _) => bail!("Cannot set '{self}', as it is a struct and not a literal"),
+        }
+        *self = new_value;
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the number of bytes in the byte encoding of this plaintext.
+    pub fn size_in_bytes(&self) -> usize {
+        match self {
+            // The variant tag, followed by the literal.
+            Self::Literal(literal, ..) => 1 + literal.size_in_bytes(),
+            // The variant tag and the u8 member count, followed by each member.
+            Self::Struct(struct_, ..) => {
+                2 + struct_
+                    .iter()
+                    .map(|(name, value)| {
+                        // The identifier is prefixed with its length as a u8,
+                        // and the member value is prefixed with its length as a u16.
+                        1 + (name.size_in_bits() / 8) as usize + 2 + value.size_in_bytes()
+                    })
+                    .sum::<usize>()
+            }
+        }
+    }
+
+    /// Returns the nesting depth of this plaintext.
+    /// A literal has a depth of 0, and a struct is one deeper than its deepest member.
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::Literal(..) => 0,
+            Self::Struct(struct_, ..) => 1 + struct_.values().map(|value| value.depth()).max().unwrap_or(0),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns `true` if `self` and `other` are equal, without exiting early on a mismatch.
+    ///
+    /// Note: The comparison only walks the full structure for same-typed records.
+    pub fn ct_eq(&self, other: &Self) -> Boolean<N> {
+        // Check every entry, even after a mismatch is found.
+        let mut equal = Boolean::new(self.data.len() == other.data.len());
+        for ((name_a, entry_a), (name_b, entry_b)) in self.data.iter().zip(other.data.iter()) {
+            let entry = match (entry_a, entry_b) {
+                (Entry::Constant(a), Entry::Constant(b))
+                | (Entry::Public(a), Entry::Public(b))
+                | (Entry::Private(a), Entry::Private(b)) => a.ct_eq(b),
+                (Entry::Constant(..), _) | (Entry::Public(..), _) | (Entry::Private(..), _) => Boolean::new(false),
+            };
+            equal = equal & name_a.is_equal(name_b) & entry;
+        }
+
+        // Check the `owner`, `data`, and `nonce`.
+        self.owner.is_equal(&other.owner) & equal & self.nonce.is_equal(&other.nonce)
+    }
+}
@@ -17,6 +17,8 @@ use super::*;
 impl<N: Network> Record<N, Ciphertext<N>> {
     /// Decrypts `self` into plaintext using the given view key.
     pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Record<N, Plaintext<N>>> {
+        // Ensure the view key belongs to the record owner.
+        ensure!(self.is_owner(view_key), "Failed to decrypt the record - the view key does not belong to the owner");
         // Compute the record view key.
         let record_view_key = (self.nonce * **view_key).to_x_coordinate();
         // Decrypt the record.
@@ -118,6 +120,10 @@ mod tests {
         let ciphertext = record.encrypt(randomizer)?;
         // Decrypt the record.
         assert_eq!(record, ciphertext.decrypt(&view_key)?);
+
+        // Ensure the record fails to decrypt with a view key that does not belong to the owner.
+        let wrong_view_key = ViewKey::try_from(&PrivateKey::<N>::new(rng)?)?;
+        assert!(ciphertext.decrypt(&wrong_view_key).is_err());
         Ok(())
     }
 
@@ -141,4 +147,45 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_encrypt_and_decrypt_mixed_visibility() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a view key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Prepare a record with constant, public, and private entries.
+        let randomizer = Scalar::rand(&mut rng);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, a: 1u8.constant, b: {{ c: 2u16.public, d: true.public }}, e: 3u32.private, f: {{ g: 4field.private }}, _nonce: {}.public }}",
+            CurrentNetwork::g_scalar_multiply(&randomizer)
+        ))?;
+
+        // Encrypt the record.
+        let ciphertext = record.encrypt(randomizer)?;
+        // Ensure only the owner and the private entries are encrypted.
+        assert!(ciphertext.owner().is_private());
+        for (identifier, entry) in ciphertext.data() {
+            match (identifier.to_string().as_str(), entry) {
+                ("a", Entry::Constant(plaintext)) => assert_eq!(plaintext, &Plaintext::from_str("1u8")?),
+                ("b", Entry::Public(plaintext)) => assert_eq!(plaintext, &Plaintext::from_str("{ c: 2u16, d: true }")?),
+                ("e" | "f", Entry::Private(..)) => (),
+                _ => bail!("Unexpected entry '{identifier}' in the record ciphertext"),
+            }
+        }
+
+        // Ensure the record round-trips.
+        assert_eq!(record, ciphertext.decrypt(&view_key)?);
+
+        // Ensure the record fails to decrypt with a view key that does not belong to the owner.
+        let wrong_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        assert!(ciphertext.decrypt(&wrong_view_key).is_err());
+
+        // Ensure the record fails to encrypt with a randomizer that does not correspond to the nonce.
+        assert!(record.encrypt(Scalar::rand(&mut rng)).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde_json::{json, Value as JsonValue};
+
+impl<N: Network> Entry<N, Plaintext<N>> {
+    /// Returns the entry as a JSON object, with its visibility and plaintext.
+    pub fn to_json(&self) -> JsonValue {
+        let (visibility, plaintext) = match self {
+            Self::Constant(plaintext) => ("constant", plaintext),
+            Self::Public(plaintext) => ("public", plaintext),
+            Self::Private(plaintext) => ("private", plaintext),
+        };
+        json!({ "visibility": visibility, "plaintext": plaintext.to_json() })
+    }
+
+    /// Returns the entry from a JSON object, with its visibility and plaintext.
+    pub fn from_json(json: &JsonValue) -> Result<Self> {
+        // Recover the plaintext.
+        let plaintext = match json.get("plaintext") {
+            Some(plaintext) => Plaintext::from_json(plaintext)?,
+            None => bail!("Expected a 'plaintext' in the entry: {json}"),
+        };
+        // Recover the entry.
+        match json.get("visibility").and_then(JsonValue::as_str) {
+            Some("constant") => Ok(Self::Constant(plaintext)),
+            Some("public") => Ok(Self::Public(plaintext)),
+            Some("private") => Ok(Self::Private(plaintext)),
+            _ => bail!("Expected a 'visibility' of 'constant', 'public', or 'private' in the entry: {json}"),
+        }
+    }
+}
@@ -15,8 +15,10 @@
 mod bytes;
 mod equal;
 mod find;
+mod json;
 mod num_randomizers;
 mod parse;
+mod set;
 mod to_bits;
 
 use crate::{Ciphertext, Identifier, Literal, Plaintext};
@@ -35,3 +37,60 @@ pub enum Entry<N: Network, Private: Visibility> {
     /// A private entry encrypted under the address of the record owner.
     Private(Private),
 }
+
+impl<N: Network, Private: Visibility> Entry<N, Private> {
+    /// Returns `true` if `self` is constant.
+    pub const fn is_constant(&self) -> bool {
+        matches!(self, Self::Constant(..))
+    }
+
+    /// Returns `true` if `self` is public.
+    pub const fn is_public(&self) -> bool {
+        matches!(self, Self::Public(..))
+    }
+
+    /// Returns `true` if `self` is private.
+    pub const fn is_private(&self) -> bool {
+        matches!(self, Self::Private(..))
+    }
+}
+
+impl<N: Network> Entry<N, Plaintext<N>> {
+    /// Returns the plaintext of the entry, regardless of its visibility.
+    pub const fn plaintext(&self) -> &Plaintext<N> {
+        match self {
+            Self::Constant(plaintext) | Self::Public(plaintext) | Self::Private(plaintext) => plaintext,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_visibility_predicates() {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8 }").unwrap();
+
+        let constant = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::Constant(plaintext.clone());
+        assert!(constant.is_constant());
+        assert!(!constant.is_public());
+        assert!(!constant.is_private());
+        assert_eq!(constant.plaintext(), &plaintext);
+
+        let public = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::Public(plaintext.clone());
+        assert!(!public.is_constant());
+        assert!(public.is_public());
+        assert!(!public.is_private());
+        assert_eq!(public.plaintext(), &plaintext);
+
+        let private = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::Private(plaintext.clone());
+        assert!(!private.is_constant());
+        assert!(!private.is_public());
+        assert!(private.is_private());
+        assert_eq!(private.plaintext(), &plaintext);
+    }
+}
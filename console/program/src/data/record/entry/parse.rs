@@ -162,6 +162,8 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                 write!(f, "{:indent$}{literal}.{visibility}", "", indent = depth * INDENT)
             }
             // Prints the struct, i.e. { first: 10i64.private, second: 198u64.private }
+            // Prints the empty struct, i.e. {}
+            Plaintext::Struct(struct_, ..) if struct_.is_empty() => write!(f, "{{}}"),
             Plaintext::Struct(struct_, ..) => {
                 // Print the opening brace.
                 write!(f, "{{")?;
@@ -193,7 +195,7 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                                 // Print the last member without a comma.
                                 true => write!(f, "\n{:indent$}}}", "", indent = depth * INDENT),
                                 // Print the member with a comma.
-                                false => write!(f, "\n{:indent$}}},", "", indent = depth * INDENT),
+                                false => write!(f, ","),
                             }
                         }
                     }
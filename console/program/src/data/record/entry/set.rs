@@ -0,0 +1,28 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Entry<N, Plaintext<N>> {
+    /// Sets the entry member at the given path to the given value, preserving the entry visibility.
+    /// If the path is empty, the entry itself is set.
+    pub fn set(&mut self, path: &[Identifier<N>], new_value: Plaintext<N>) -> Result<()> {
+        match self {
+            Self::Constant(plaintext) | Self::Public(plaintext) | Self::Private(plaintext) => match path.is_empty() {
+                true => plaintext.set_leaf(new_value),
+                false => plaintext.set(path, new_value),
+            },
+        }
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde_json::{json, Map, Value as JsonValue};
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the record as a JSON object, with the visibility of its owner and entries.
+    pub fn to_json(&self) -> JsonValue {
+        let owner = match &self.owner {
+            Owner::Public(address) => json!({ "visibility": "public", "value": address.to_string() }),
+            Owner::Private(..) => json!({ "visibility": "private", "value": (*self.owner).to_string() }),
+        };
+        let data = self
+            .data
+            .iter()
+            .map(|(identifier, entry)| (identifier.to_string(), entry.to_json()))
+            .collect::<Map<_, _>>();
+        json!({ "type": "record", "owner": owner, "data": data, "nonce": self.nonce.to_string() })
+    }
+
+    /// Returns the record from a JSON object, with the visibility of its owner and entries.
+    pub fn from_json(json: &JsonValue) -> Result<Self> {
+        // Ensure the JSON is a record.
+        ensure!(json.get("type").and_then(JsonValue::as_str) == Some("record"), "Expected a record: {json}");
+
+        // Recover the owner.
+        let (visibility, address) = match json.get("owner") {
+            Some(owner) => match owner.get("value").and_then(JsonValue::as_str) {
+                Some(address) => (owner.get("visibility").and_then(JsonValue::as_str), Address::from_str(address)?),
+                None => bail!("Expected a 'value' string in the record owner: {owner}"),
+            },
+            None => bail!("Expected an 'owner' in the record: {json}"),
+        };
+        let owner = match visibility {
+            Some("public") => Owner::Public(address),
+            Some("private") => Owner::Private(Plaintext::from(Literal::Address(address))),
+            _ => bail!("Expected a 'visibility' of 'public' or 'private' in the record owner: {json}"),
+        };
+
+        // Recover the entries.
+        let data = match json.get("data").and_then(JsonValue::as_object) {
+            Some(data) => data
+                .iter()
+                .map(|(identifier, entry)| Ok((Identifier::from_str(identifier)?, Entry::from_json(entry)?)))
+                .collect::<Result<IndexMap<_, _>>>()?,
+            None => bail!("Expected a 'data' object in the record: {json}"),
+        };
+
+        // Recover the nonce.
+        let nonce = match json.get("nonce").and_then(JsonValue::as_str) {
+            Some(nonce) => Group::from_str(nonce)?,
+            None => bail!("Expected a 'nonce' string in the record: {json}"),
+        };
+
+        Self::from_plaintext(owner, data, nonce)
+    }
+}
@@ -19,20 +19,26 @@ mod helpers;
 pub use helpers::Owner;
 
 mod bytes;
+mod ct_eq;
 mod decrypt;
 mod encrypt;
 mod equal;
 mod find;
 mod is_owner;
+mod json;
 mod num_randomizers;
 mod parse_ciphertext;
 mod parse_plaintext;
+mod scan;
 mod serial_number;
 mod serialize;
+mod set;
+mod size_in_bytes;
 mod tag;
 mod to_bits;
 mod to_commitment;
 mod to_fields;
+mod to_public_only;
 
 use crate::{Ciphertext, Identifier, Literal, Plaintext, ProgramID};
 use snarkvm_console_account::{Address, PrivateKey, ViewKey};
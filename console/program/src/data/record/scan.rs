@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns the index and plaintext of every record in `records` that decrypts under the given view key.
+    /// Records owned by another address, or that fail to decrypt, are skipped.
+    /// Note: The owner is checked before decrypting, which only decrypts the owner, and is much cheaper.
+    pub fn scan(records: &[Self], view_key: &ViewKey<N>) -> Vec<(usize, Record<N, Plaintext<N>>)> {
+        // Compute the x-coordinate of the address once, for every owner check.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+
+        records
+            .iter()
+            .enumerate()
+            .filter(|(_, record)| record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate))
+            .filter_map(|(index, record)| {
+                // Compute the record view key, and decrypt the record.
+                let record_view_key = (record.nonce * **view_key).to_x_coordinate();
+                record.decrypt_symmetric(&record_view_key).ok().map(|record| (index, record))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a record owned by the given address, with a private owner if `is_private`, and encrypts it.
+    fn sample_ciphertext(
+        address: Address<CurrentNetwork>,
+        is_private: bool,
+        rng: &mut TestRng,
+    ) -> Result<(Record<CurrentNetwork, Ciphertext<CurrentNetwork>>, Record<CurrentNetwork, Plaintext<CurrentNetwork>>)>
+    {
+        let owner = match is_private {
+            true => Owner::Private(Plaintext::from(Literal::Address(address))),
+            false => Owner::Public(address),
+        };
+        let randomizer = Scalar::rand(rng);
+        let record = Record {
+            owner,
+            data: IndexMap::from_iter([(
+                Identifier::from_str("amount")?,
+                Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng)))),
+            )]),
+            nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+        };
+        Ok((record.encrypt(randomizer)?, record))
+    }
+
+    #[test]
+    fn test_scan() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the scanning account, and another account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let other_view_key = ViewKey::try_from(&other_private_key)?;
+        let other_address = Address::try_from(&other_private_key)?;
+
+        // Sample a batch where only some records belong to the scanning account.
+        let owners =
+            [(other_address, true), (address, true), (other_address, false), (address, false), (address, true)];
+        let mut ciphertexts = Vec::with_capacity(owners.len());
+        let mut expected = Vec::new();
+        for (index, (owner, is_private)) in owners.into_iter().enumerate() {
+            let (ciphertext, plaintext) = sample_ciphertext(owner, is_private, rng)?;
+            ciphertexts.push(ciphertext);
+            if owner == address {
+                expected.push((index, plaintext));
+            }
+        }
+
+        // Ensure exactly the records of the scanning account are found, with their indices.
+        let candidate = Record::scan(&ciphertexts, &view_key);
+        assert_eq!(vec![1, 3, 4], candidate.iter().map(|(index, _)| *index).collect::<Vec<_>>());
+        assert_eq!(expected, candidate);
+
+        // Ensure the other account only finds its own records.
+        let candidate = Record::scan(&ciphertexts, &other_view_key);
+        assert_eq!(vec![0, 2], candidate.iter().map(|(index, _)| *index).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_all_miss() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample records that all belong to another account.
+        let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let ciphertexts = (0..3)
+            .map(|i| sample_ciphertext(other_address, i % 2 == 0, rng).map(|(ciphertext, _)| ciphertext))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Ensure the scan finds nothing, including on an empty batch.
+        let view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        assert!(Record::scan(&ciphertexts, &view_key).is_empty());
+        assert!(Record::scan(&[], &view_key).is_empty());
+        Ok(())
+    }
+}
@@ -33,3 +33,86 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         N::commit_bhp512(&(N::serial_number_domain(), commitment).to_bits_le(), &sn_nonce)
     }
 }
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the serial number of the record, using the private key of the record owner.
+    pub fn to_serial_number(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+    ) -> Result<Field<N>> {
+        // Ensure the private key corresponds to the record owner.
+        ensure!(
+            **self.owner() == Address::try_from(private_key)?,
+            "The private key does not belong to the record owner"
+        );
+        // Compute the record commitment.
+        let commitment = self.to_commitment(program_id, record_name)?;
+        // Compute the serial number.
+        Self::serial_number(*private_key, commitment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InputID, Request, Value, ValueType};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    fn sample_record(owner: Address<CurrentNetwork>) -> Record<CurrentNetwork, Plaintext<CurrentNetwork>> {
+        Record::from_str(&format!(
+            "{{ owner: {owner}.private, token_amount: 100u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_to_serial_number() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let program_id = ProgramID::from_str("token.aleo")?;
+        let function_name = Identifier::from_str("transfer")?;
+        let record_name = Identifier::from_str("token")?;
+
+        for _ in 0..ITERATIONS {
+            // Sample a private key and a record belonging to its address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let record = sample_record(Address::try_from(&private_key)?);
+
+            // Compute the serial number.
+            let candidate = record.to_serial_number(&private_key, &program_id, &record_name)?;
+
+            // Ensure it matches the serial number produced when signing a request that spends the record.
+            let input_types = [ValueType::from_str("token.record")?];
+            let inputs = [Value::Record(record)];
+            let request =
+                Request::sign(&private_key, program_id, function_name, inputs.into_iter(), &input_types, rng)?;
+            match request.input_ids() {
+                [InputID::Record(_, _, serial_number, _)] => assert_eq!(*serial_number, candidate),
+                _ => bail!("Expected a single record input ID"),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_serial_number_fails_on_mismatched_key() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a record belonging to one account, and the private key of another.
+        let owner_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let record = sample_record(Address::try_from(&owner_private_key)?);
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+        // Ensure the serial number is not derived.
+        let result =
+            record.to_serial_number(&private_key, &ProgramID::from_str("token.aleo")?, &Identifier::from_str("token")?);
+        assert!(result.is_err());
+        Ok(())
+    }
+}
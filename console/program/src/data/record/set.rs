@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Sets the entry member at the given path to the given value, preserving the entry visibility.
+    pub fn set(&mut self, path: &[Identifier<N>], new_value: Plaintext<N>) -> Result<()> {
+        // Ensure the path is not requesting the `owner`.
+        if path.len() == 1 && path[0] == Identifier::from_str("owner")? {
+            bail!("Cannot set the record owner.")
+        }
+
+        // Ensure the path is not empty.
+        match path.split_first() {
+            Some((first, rest)) => match self.data.get_mut(first) {
+                Some(entry) => entry.set(rest, new_value),
+                None => bail!("Record entry `{first}` not found."),
+            },
+            None => bail!("Attempted to set record entry with an empty path."),
+        }
+    }
+}
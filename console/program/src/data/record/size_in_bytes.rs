@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the number of bytes in the byte encoding of this record.
+    pub fn size_in_bytes(&self) -> usize {
+        // The owner is prefixed with its visibility tag.
+        let owner = 1 + match &self.owner {
+            Owner::Public(..) => Address::<N>::size_in_bytes(),
+            Owner::Private(plaintext) => plaintext.size_in_bytes(),
+        };
+        // Each entry is written as its identifier (prefixed with its length as a u8),
+        // followed by the entry (prefixed with its length as a u16) and its visibility tag.
+        let entries = self
+            .data
+            .iter()
+            .map(|(name, entry)| 1 + (name.size_in_bits() / 8) as usize + 2 + 1 + entry.plaintext().size_in_bytes())
+            .sum::<usize>();
+        // The owner, the u8 entry count, the entries, and the nonce.
+        owner + 1 + entries + Group::<N>::size_in_bytes()
+    }
+
+    /// Returns the nesting depth of this record.
+    /// A record is one deeper than its deepest entry.
+    pub fn depth(&self) -> usize {
+        let entries = self.data.values().map(|entry| entry.plaintext().depth());
+        1 + entries.max().unwrap_or(0)
+    }
+}
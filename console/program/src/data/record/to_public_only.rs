@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Private: Visibility> Record<N, Private> {
+    /// Returns a plaintext struct containing only the constant and public members of the record.
+    ///
+    /// Private members are skipped in their entirety, including private structs, and the `owner`
+    /// is included only if it is public. The nonce is not a member, and is therefore omitted.
+    pub fn to_public_only(&self) -> Result<Plaintext<N>> {
+        // Initialize the members, starting with the owner if it is public.
+        let mut members = IndexMap::with_capacity(self.data.len() + 1);
+        if let Owner::Public(owner) = &self.owner {
+            members.insert(Identifier::from_str("owner")?, Plaintext::from(Literal::Address(*owner)));
+        }
+        // Retain the constant and public entries, preserving their order and nesting.
+        for (identifier, entry) in &self.data {
+            match entry {
+                Entry::Constant(plaintext) | Entry::Public(plaintext) => {
+                    members.insert(*identifier, plaintext.clone());
+                }
+                Entry::Private(..) => continue,
+            }
+        }
+        Ok(Plaintext::Struct(members, Default::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_public_only() -> Result<()> {
+        // Sample a record with mixed visibility.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, a: 1u8.private, b: 2u16.public, c: { d: 3u32.public, e: true.public }, f: 4field.constant, g: { h: 5u64.private }, _nonce: 0group.public }",
+        )?;
+        let candidate = record.to_public_only()?;
+
+        // Ensure the private members are absent, and the public and constant members are retained.
+        let expected = Plaintext::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, b: 2u16, c: { d: 3u32, e: true }, f: 4field }",
+        )?;
+        assert_eq!(candidate, expected);
+        assert!(candidate.find(&[Identifier::from_str("a")?]).is_err());
+        assert!(candidate.find(&[Identifier::from_str("g")?]).is_err());
+
+        // Ensure a private owner is omitted.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, a: 1u8.private, _nonce: 0group.public }",
+        )?;
+        match record.to_public_only()? {
+            Plaintext::Struct(members, ..) => assert!(members.is_empty()),
+            Plaintext::Literal(..) => bail!("Expected a struct"),
+        }
+        Ok(())
+    }
+}
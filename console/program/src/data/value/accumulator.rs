@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An accumulator that sums an unsigned integer member across a set of values (i.e. the balances of records).
+#[derive(Clone, Debug)]
+pub struct ValueAccumulator<N: Network> {
+    /// The path to the integer member in each value.
+    path: Vec<Identifier<N>>,
+    /// The running total.
+    total: u128,
+}
+
+impl<N: Network> ValueAccumulator<N> {
+    /// Initializes a new accumulator for the integer member at the given path.
+    /// If the path is empty, each value is expected to be an integer literal.
+    pub fn new(path: &[Identifier<N>]) -> Self {
+        Self { path: path.to_vec(), total: 0 }
+    }
+
+    /// Adds the integer member of the given value to the total.
+    /// Returns an error if the member is not an unsigned integer, or if the total overflows.
+    pub fn add(&mut self, value: &Value<N>) -> Result<()> {
+        // Retrieve the member from the value.
+        let member = match self.path.is_empty() {
+            true => value.clone(),
+            false => value.find(&self.path)?,
+        };
+        // Extract the amount from the member.
+        let amount = match member {
+            Value::Plaintext(Plaintext::Literal(literal, ..)) => match literal {
+                Literal::U8(amount) => *amount as u128,
+                Literal::U16(amount) => *amount as u128,
+                Literal::U32(amount) => *amount as u128,
+                Literal::U64(amount) => *amount as u128,
+                Literal::U128(amount) => *amount,
+                _ => bail!("Cannot accumulate '{literal}', as it is not an unsigned integer"),
+            },
+            Value::Plaintext(Plaintext::Struct(..)) => bail!("Cannot accumulate a struct, expected an unsigned integer"),
+            Value::Record(..) => bail!("Cannot accumulate a record, expected an unsigned integer"),
+        };
+        // Update the total, ensuring it does not overflow.
+        match self.total.checked_add(amount) {
+            Some(total) => self.total = total,
+            None => bail!("Overflow: the accumulated total exceeds {}", u128::MAX),
+        }
+        Ok(())
+    }
+
+    /// Returns the accumulated total.
+    pub fn total(&self) -> U128<N> {
+        U128::new(self.total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_accumulate_records() -> Result<()> {
+        let mut accumulator = ValueAccumulator::<CurrentNetwork>::new(&[Identifier::from_str("microcredits")?]);
+
+        // Accumulate the balances of several records.
+        for amount in [100u64, 250, 0, u64::MAX] {
+            let record = Value::<CurrentNetwork>::from_str(&format!(
+                "{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, microcredits: {amount}u64.private, _nonce: 0group.public }}"
+            ))?;
+            accumulator.add(&record)?;
+        }
+        assert_eq!(accumulator.total(), U128::new(350 + u64::MAX as u128));
+
+        // Ensure a record without the member fails.
+        let record = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, foo: 5u64.private, _nonce: 0group.public }",
+        )?;
+        assert!(accumulator.add(&record).is_err());
+
+        // Ensure a signed member fails.
+        let record = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, microcredits: 5i64.private, _nonce: 0group.public }",
+        )?;
+        assert!(accumulator.add(&record).is_err());
+        assert_eq!(accumulator.total(), U128::new(350 + u64::MAX as u128));
+        Ok(())
+    }
+
+    #[test]
+    fn test_accumulate_overflow() -> Result<()> {
+        let mut accumulator = ValueAccumulator::<CurrentNetwork>::new(&[]);
+        accumulator.add(&Value::from_str(&format!("{}u128", u128::MAX - 1))?)?;
+        accumulator.add(&Value::from_str("1u128")?)?;
+        assert_eq!(accumulator.total(), U128::new(u128::MAX));
+
+        // Ensure the overflow is detected, and the total is unchanged.
+        assert!(accumulator.add(&Value::from_str("1u8")?).is_err());
+        assert_eq!(accumulator.total(), U128::new(u128::MAX));
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns `true` if `self` and `other` are equal, without exiting early on a mismatch.
+    ///
+    /// Note: The comparison is only constant-time for same-typed values;
+    /// a plaintext and a record are immediately reported as unequal.
+    pub fn ct_eq(&self, other: &Self) -> Boolean<N> {
+        match (self, other) {
+            (Self::Plaintext(a), Self::Plaintext(b)) => a.ct_eq(b),
+            (Self::Record(a), Self::Record(b)) => a.ct_eq(b),
+            (Self::Plaintext(..), _) | (Self::Record(..), _) => Boolean::new(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const VALUES: [&str; 10] = [
+        "5u8",
+        "6u8",
+        "5u16",
+        "{ a: 5u8, b: { c: true, d: 1field } }",
+        "{ a: 5u8, b: { c: false, d: 1field } }",
+        "{ a: 5u8, b: { c: true } }",
+        "{ a: 5u8 }",
+        "{ b: 5u8 }",
+        "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u64.private, _nonce: 0group.public }",
+        "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u64.public, _nonce: 0group.public }",
+    ];
+
+    #[test]
+    fn test_ct_eq() -> Result<()> {
+        let values = VALUES.iter().map(|value| Value::<CurrentNetwork>::from_str(value)).collect::<Result<Vec<_>>>()?;
+
+        for (i, a) in values.iter().enumerate() {
+            // Check that a value is equal to a copy of itself.
+            let copy = Value::<CurrentNetwork>::from_str(&a.to_string())?;
+            assert!(*a.ct_eq(&copy));
+
+            for (j, b) in values.iter().enumerate() {
+                // Check that `ct_eq` agrees with `==`, on both equal and unequal values.
+                assert_eq!(i == j, *a.ct_eq(b), "Mismatch between '{a}' and '{b}'");
+                assert_eq!(a == b, *a.ct_eq(b), "Mismatch between '{a}' and '{b}'");
+            }
+        }
+        Ok(())
+    }
+}
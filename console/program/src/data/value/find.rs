@@ -19,16 +19,8 @@ impl<N: Network> Value<N> {
     pub fn find(&self, path: &[Identifier<N>]) -> Result<Self> {
         match self {
             Self::Plaintext(plaintext) => Ok(Self::Plaintext(plaintext.find(path)?)),
-            Self::Record(record) => {
-                // Find the entry.
-                let entry = record.find(path)?;
-                // Extract the plaintext from the entry.
-                match entry {
-                    Entry::Constant(plaintext) => Ok(Self::Plaintext(plaintext)),
-                    Entry::Public(plaintext) => Ok(Self::Plaintext(plaintext)),
-                    Entry::Private(plaintext) => Ok(Self::Plaintext(plaintext)),
-                }
-            }
+            // Find the entry, and extract its plaintext.
+            Self::Record(record) => Ok(Self::Plaintext(record.find(path)?.plaintext().clone())),
         }
     }
 }
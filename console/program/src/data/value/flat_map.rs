@@ -0,0 +1,286 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{EntryType, Owner, PlaintextType, RecordType, Struct, ValueType};
+
+use indexmap::IndexMap;
+use std::collections::BTreeMap;
+
+impl<N: Network> Value<N> {
+    /// Returns the value as a flat map from dotted member paths to literal strings.
+    /// A literal value is stored under the empty path, and the nonce of a record under `_nonce`.
+    pub fn to_flat_map(&self) -> BTreeMap<String, String> {
+        let mut map = BTreeMap::new();
+        match self {
+            Self::Plaintext(plaintext) => flatten_plaintext(plaintext, "", &mut map),
+            Self::Record(record) => {
+                match record.owner() {
+                    Owner::Public(owner) => map.insert("owner".to_string(), owner.to_string()),
+                    Owner::Private(owner) => map.insert("owner".to_string(), owner.to_string()),
+                };
+                for (name, entry) in record.data() {
+                    match entry {
+                        Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                            flatten_plaintext(plaintext, &name.to_string(), &mut map)
+                        }
+                    }
+                }
+                map.insert("_nonce".to_string(), record.nonce().to_string());
+            }
+        }
+        map
+    }
+
+    /// Returns a value of the given type from a flat map of dotted member paths to literal strings.
+    /// The struct and record definitions are resolved using `get_struct` and `get_record`.
+    /// Returns an error if a key is missing, or if the map contains keys that are not in the type.
+    pub fn from_flat_map(
+        value_type: &ValueType<N>,
+        map: &BTreeMap<String, String>,
+        get_struct: impl Fn(&Identifier<N>) -> Result<Struct<N>>,
+        get_record: impl Fn(&Identifier<N>) -> Result<RecordType<N>>,
+    ) -> Result<Self> {
+        // Track the keys that have not been consumed.
+        let mut remaining = map.clone();
+        // Reconstruct the value.
+        let value = match value_type {
+            ValueType::Constant(plaintext_type)
+            | ValueType::Public(plaintext_type)
+            | ValueType::Private(plaintext_type) => {
+                Self::Plaintext(unflatten_plaintext(plaintext_type, "", &mut remaining, &get_struct)?)
+            }
+            ValueType::Record(record_name) => {
+                let record_type = get_record(record_name)?;
+                Self::Record(unflatten_record(&record_type, &mut remaining, &get_struct)?)
+            }
+            ValueType::ExternalRecord(locator) => {
+                bail!("Cannot reconstruct external record '{locator}' from a flat map")
+            }
+        };
+        // Ensure every key was consumed.
+        if let Some(key) = remaining.keys().next() {
+            bail!("Found unexpected key '{key}' in the flat map for '{value_type}'")
+        }
+        Ok(value)
+    }
+}
+
+/// Returns the dotted path for `name` under `prefix`.
+fn join(prefix: &str, name: &str) -> String {
+    match prefix.is_empty() {
+        true => name.to_string(),
+        false => format!("{prefix}.{name}"),
+    }
+}
+
+/// Inserts the literals of the given plaintext into the map, keyed by their dotted paths.
+fn flatten_plaintext<N: Network>(plaintext: &Plaintext<N>, path: &str, map: &mut BTreeMap<String, String>) {
+    match plaintext {
+        Plaintext::Literal(literal, ..) => {
+            map.insert(path.to_string(), literal.to_string());
+        }
+        Plaintext::Struct(members, ..) => {
+            for (name, member) in members {
+                flatten_plaintext(member, &join(path, &name.to_string()), map);
+            }
+        }
+    }
+}
+
+/// Removes the literal at the given path from the map, and returns it.
+fn take_literal<N: Network>(path: &str, map: &mut BTreeMap<String, String>) -> Result<Literal<N>> {
+    match map.remove(path) {
+        Some(literal) => Literal::from_str(&literal),
+        None => bail!("Missing key '{path}' in the flat map"),
+    }
+}
+
+/// Reconstructs a plaintext of the given type from the map, consuming its keys.
+fn unflatten_plaintext<N: Network>(
+    plaintext_type: &PlaintextType<N>,
+    path: &str,
+    map: &mut BTreeMap<String, String>,
+    get_struct: &impl Fn(&Identifier<N>) -> Result<Struct<N>>,
+) -> Result<Plaintext<N>> {
+    match plaintext_type {
+        PlaintextType::Literal(literal_type) => {
+            let literal = take_literal(path, map)?;
+            ensure!(
+                literal.to_type() == *literal_type,
+                "Expected a '{literal_type}' at '{path}', but found '{literal}'"
+            );
+            Ok(Plaintext::from(literal))
+        }
+        PlaintextType::Struct(struct_name) => {
+            let struct_ = get_struct(struct_name)?;
+            let members = struct_
+                .members()
+                .iter()
+                .map(|(name, member_type)| {
+                    Ok((*name, unflatten_plaintext(member_type, &join(path, &name.to_string()), map, get_struct)?))
+                })
+                .collect::<Result<IndexMap<_, _>>>()?;
+            Ok(Plaintext::Struct(members, Default::default()))
+        }
+    }
+}
+
+/// Reconstructs a record of the given type from the map, consuming its keys.
+fn unflatten_record<N: Network>(
+    record_type: &RecordType<N>,
+    map: &mut BTreeMap<String, String>,
+    get_struct: &impl Fn(&Identifier<N>) -> Result<Struct<N>>,
+) -> Result<Record<N, Plaintext<N>>> {
+    // Reconstruct the owner.
+    let owner = match take_literal("owner", map)? {
+        Literal::Address(address) => match record_type.owner().is_public() {
+            true => Owner::Public(address),
+            false => Owner::Private(Plaintext::from(Literal::Address(address))),
+        },
+        literal => bail!("Expected an address for the record owner, but found '{literal}'"),
+    };
+    // Reconstruct the entries.
+    let data = record_type
+        .entries()
+        .iter()
+        .map(|(name, entry_type)| {
+            let path = name.to_string();
+            let entry = match entry_type {
+                EntryType::Constant(plaintext_type) => {
+                    Entry::Constant(unflatten_plaintext(plaintext_type, &path, map, get_struct)?)
+                }
+                EntryType::Public(plaintext_type) => {
+                    Entry::Public(unflatten_plaintext(plaintext_type, &path, map, get_struct)?)
+                }
+                EntryType::Private(plaintext_type) => {
+                    Entry::Private(unflatten_plaintext(plaintext_type, &path, map, get_struct)?)
+                }
+            };
+            Ok((*name, entry))
+        })
+        .collect::<Result<IndexMap<_, _>>>()?;
+    // Reconstruct the nonce.
+    let nonce = match take_literal("_nonce", map)? {
+        Literal::Group(nonce) => nonce,
+        literal => bail!("Expected a group for the record nonce, but found '{literal}'"),
+    };
+    Record::<N, Plaintext<N>>::from_plaintext(owner, data, nonce)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const OWNER: &str = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+
+    fn get_struct(name: &Identifier<CurrentNetwork>) -> Result<Struct<CurrentNetwork>> {
+        match name.to_string().as_str() {
+            "point" => Struct::from_str("struct point:\n    x as i32;\n    y as i32;"),
+            "meta" => Struct::from_str("struct meta:\n    origin as point;\n    tag as u8;"),
+            _ => bail!("Struct '{name}' is not defined"),
+        }
+    }
+
+    fn get_record(name: &Identifier<CurrentNetwork>) -> Result<RecordType<CurrentNetwork>> {
+        match name.to_string().as_str() {
+            "token" => RecordType::from_str(
+                "record token:\n    owner as address.private;\n    balance as u64.private;\n    info as meta.public;",
+            ),
+            _ => bail!("Record '{name}' is not defined"),
+        }
+    }
+
+    fn sample_record() -> Result<Value<CurrentNetwork>> {
+        Value::from_str(&format!(
+            "{{ owner: {OWNER}.private, balance: 100u64.private, info: {{ origin: {{ x: -1i32.public, y: 2i32.public }}, tag: 3u8.public }}, _nonce: 0group.public }}"
+        ))
+    }
+
+    #[test]
+    fn test_flat_map_record() -> Result<()> {
+        let value = sample_record()?;
+
+        // Ensure the record is flattened into dotted paths.
+        let map = value.to_flat_map();
+        let expected = BTreeMap::from_iter(
+            [
+                ("owner", OWNER.to_string()),
+                ("balance", "100u64".to_string()),
+                ("info.origin.x", "-1i32".to_string()),
+                ("info.origin.y", "2i32".to_string()),
+                ("info.tag", "3u8".to_string()),
+                ("_nonce", "0group".to_string()),
+            ]
+            .map(|(key, value)| (key.to_string(), value)),
+        );
+        assert_eq!(expected, map);
+
+        // Ensure the record is reconstructed.
+        let value_type = ValueType::from_str("token.record")?;
+        let candidate = Value::from_flat_map(&value_type, &map, get_struct, get_record)?;
+        assert_eq!(value, candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_flat_map_plaintext() -> Result<()> {
+        let value = Value::<CurrentNetwork>::from_str("{ origin: { x: 5i32, y: 6i32 }, tag: 7u8 }")?;
+        let map = value.to_flat_map();
+        assert_eq!(3, map.len());
+
+        let value_type = ValueType::from_str("meta.private")?;
+        assert_eq!(value, Value::from_flat_map(&value_type, &map, get_struct, get_record)?);
+
+        // Ensure a literal is stored under the empty path.
+        let value = Value::<CurrentNetwork>::from_str("5u8")?;
+        let map = value.to_flat_map();
+        assert_eq!(Some(&"5u8".to_string()), map.get(""));
+        assert_eq!(value, Value::from_flat_map(&ValueType::from_str("u8.public")?, &map, get_struct, get_record)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_flat_map_missing_key() -> Result<()> {
+        let mut map = sample_record()?.to_flat_map();
+        map.remove("info.origin.y");
+
+        let value_type = ValueType::from_str("token.record")?;
+        assert!(Value::from_flat_map(&value_type, &map, get_struct, get_record).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_flat_map_extra_key() -> Result<()> {
+        let mut map = sample_record()?.to_flat_map();
+        map.insert("info.origin.z".to_string(), "0i32".to_string());
+
+        let value_type = ValueType::from_str("token.record")?;
+        assert!(Value::from_flat_map(&value_type, &map, get_struct, get_record).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_flat_map_wrong_type() -> Result<()> {
+        let mut map = sample_record()?.to_flat_map();
+        map.insert("balance".to_string(), "100u32".to_string());
+
+        let value_type = ValueType::from_str("token.record")?;
+        assert!(Value::from_flat_map(&value_type, &map, get_struct, get_record).is_err());
+        Ok(())
+    }
+}
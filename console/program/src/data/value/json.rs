@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde_json::Value as JsonValue;
+
+impl<N: Network> Value<N> {
+    /// Returns the value as a JSON object, tagged with its type and the visibility of any record entries.
+    /// Note: This encoding is structured, unlike the `Serialize` implementation, which uses the string format.
+    pub fn to_json(&self) -> JsonValue {
+        match self {
+            Self::Plaintext(plaintext) => plaintext.to_json(),
+            Self::Record(record) => record.to_json(),
+        }
+    }
+
+    /// Returns the value from a JSON object, tagged with its type and the visibility of any record entries.
+    pub fn from_json(json: &JsonValue) -> Result<Self> {
+        match json.get("type").and_then(JsonValue::as_str) {
+            Some("record") => Ok(Self::Record(Record::from_json(json)?)),
+            _ => Ok(Self::Plaintext(Plaintext::from_json(json)?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_json(expected: Value<CurrentNetwork>) -> Result<()> {
+        // Ensure the value round-trips through a JSON string.
+        let json = serde_json::to_string(&expected.to_json())?;
+        let candidate = Value::from_json(&serde_json::from_str(&json)?)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_string(), candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_literals() -> Result<()> {
+        let literals = [
+            "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah",
+            "true",
+            "1234field",
+            "0group",
+            "-128i8",
+            "-32768i16",
+            "-2147483648i32",
+            "-9223372036854775808i64",
+            "-170141183460469231731687303715884105728i128",
+            "255u8",
+            "65535u16",
+            "4294967295u32",
+            "18446744073709551615u64",
+            "340282366920938463463374607431768211455u128",
+            "5678scalar",
+            "\"hello world\"",
+        ];
+        for literal in literals {
+            check_json(Value::from_str(literal)?)?;
+        }
+
+        // Ensure wide integers are encoded as strings.
+        let json = Value::<CurrentNetwork>::from_str("340282366920938463463374607431768211455u128")?.to_json();
+        assert_eq!(serde_json::json!({ "type": "u128", "value": "340282366920938463463374607431768211455u128" }), json);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_nested() -> Result<()> {
+        // Check a nested plaintext struct.
+        check_json(Value::<CurrentNetwork>::from_str("{ a: 1u8, b: { c: 2field, d: { e: true } } }")?)?;
+
+        // Check a record with mixed visibility, including a nested struct entry.
+        let record = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, a: 1u64.constant, b: 2i128.public, c: { d: 3field.private, e: { f: false.private } }, _nonce: 0group.public }",
+        )?;
+        check_json(record.clone())?;
+        let json = record.to_json();
+        assert_eq!("private", json["owner"]["visibility"]);
+        assert_eq!("constant", json["data"]["a"]["visibility"]);
+        assert_eq!("public", json["data"]["b"]["visibility"]);
+        assert_eq!("private", json["data"]["c"]["visibility"]);
+        assert_eq!("struct", json["data"]["c"]["plaintext"]["type"]);
+
+        // Check a record with a public owner.
+        check_json(Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, a: 1u64.private, _nonce: 0group.public }",
+        )?)
+    }
+
+    #[test]
+    fn test_json_malformed() {
+        let check = |json: &str, expected: &str| {
+            let error = Value::<CurrentNetwork>::from_json(&serde_json::from_str(json).unwrap()).unwrap_err();
+            assert!(error.to_string().contains(expected), "Unexpected error: {error}");
+        };
+
+        check(r#"{ "value": "1u8" }"#, "Expected a 'type' string");
+        check(r#"{ "type": "u8" }"#, "Expected a 'value' string");
+        check(r#"{ "type": "u16", "value": "1u8" }"#, "Expected a literal of type 'u16'");
+        check(r#"{ "type": "struct", "members": [] }"#, "Expected a 'members' object");
+        check(r#"{ "type": "record", "data": {}, "nonce": "0group" }"#, "Expected an 'owner'");
+        check(
+            r#"{ "type": "record", "owner": { "visibility": "public", "value": "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah" }, "data": { "a": { "visibility": "secret", "plaintext": { "type": "u8", "value": "1u8" } } }, "nonce": "0group" }"#,
+            "Expected a 'visibility' of 'constant', 'public', or 'private'",
+        );
+    }
+}
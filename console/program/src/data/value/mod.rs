@@ -12,15 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod accumulator;
+pub use accumulator::ValueAccumulator;
+
 mod bytes;
+mod ct_eq;
 mod equal;
 mod find;
+mod flat_map;
+mod json;
+mod native;
 mod parse;
+mod public_projection;
 mod serialize;
+mod set;
+mod size_in_bytes;
+mod tagged_bits;
 mod to_bits;
+mod to_commitment;
 mod to_fields;
 
-use crate::{Entry, Identifier, Plaintext, Record};
+use crate::{Entry, Identifier, Literal, Plaintext, Record};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
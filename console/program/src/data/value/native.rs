@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_types::Boolean;
+
+/// Implements conversions between `Value` and the native type `$native`, stored as the literal `Literal::$variant`.
+macro_rules! impl_native {
+    ($native:ty, $variant:ident, $literal:ident) => {
+        impl<N: Network> From<$native> for Value<N> {
+            /// Initializes a plaintext value from a native `$native`.
+            fn from(value: $native) -> Self {
+                Self::Plaintext(Plaintext::from(Literal::$variant($literal::new(value))))
+            }
+        }
+
+        impl<N: Network> TryFrom<&Value<N>> for $native {
+            type Error = Error;
+
+            /// Returns the native `$native` stored in the given value.
+            fn try_from(value: &Value<N>) -> Result<Self> {
+                match value {
+                    Value::Plaintext(Plaintext::Literal(Literal::$variant(literal), _)) => Ok(**literal),
+                    _ => bail!("Expected a '{}' value, found '{value}'", stringify!($native)),
+                }
+            }
+        }
+
+        impl<N: Network> TryFrom<Value<N>> for $native {
+            type Error = Error;
+
+            /// Returns the native `$native` stored in the given value.
+            fn try_from(value: Value<N>) -> Result<Self> {
+                Self::try_from(&value)
+            }
+        }
+    };
+}
+
+impl_native!(bool, Boolean, Boolean);
+impl_native!(i8, I8, I8);
+impl_native!(i16, I16, I16);
+impl_native!(i32, I32, I32);
+impl_native!(i64, I64, I64);
+impl_native!(i128, I128, I128);
+impl_native!(u8, U8, U8);
+impl_native!(u16, U16, U16);
+impl_native!(u32, U32, U32);
+impl_native!(u64, U64, U64);
+impl_native!(u128, U128, U128);
+
+impl<N: Network> TryFrom<[u8; 32]> for Value<N> {
+    type Error = Error;
+
+    /// Initializes a field value from its little-endian bytes.
+    fn try_from(bytes: [u8; 32]) -> Result<Self> {
+        let field = Field::<N>::from_bytes_le(&bytes)?;
+        Ok(Self::Plaintext(Plaintext::from(Literal::Field(field))))
+    }
+}
+
+impl<N: Network> TryFrom<&Value<N>> for [u8; 32] {
+    type Error = Error;
+
+    /// Returns the little-endian bytes of the field stored in the given value.
+    fn try_from(value: &Value<N>) -> Result<Self> {
+        match value {
+            Value::Plaintext(Plaintext::Literal(Literal::Field(field), _)) => match field.to_bytes_le()?.try_into() {
+                Ok(bytes) => Ok(bytes),
+                Err(bytes) => bail!("Expected 32 bytes for a field, found {}", bytes.len()),
+            },
+            _ => bail!("Expected a 'field' value, found '{value}'"),
+        }
+    }
+}
+
+impl<N: Network> TryFrom<Value<N>> for [u8; 32] {
+    type Error = Error;
+
+    /// Returns the little-endian bytes of the field stored in the given value.
+    fn try_from(value: Value<N>) -> Result<Self> {
+        Self::try_from(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Ensures the native value round-trips through `Value`, and is printed as the expected literal.
+    fn check_round_trip<T>(native: T, expected: &str) -> Result<()>
+    where
+        T: Copy + Debug + PartialEq + TryFrom<Value<CurrentNetwork>, Error = Error>,
+        Value<CurrentNetwork>: From<T>,
+    {
+        let value = Value::<CurrentNetwork>::from(native);
+        assert_eq!(value, Value::from_str(expected)?);
+        assert_eq!(T::try_from(value)?, native);
+        Ok(())
+    }
+
+    #[test]
+    fn test_native_round_trip() -> Result<()> {
+        check_round_trip(true, "true")?;
+        check_round_trip(false, "false")?;
+        check_round_trip(i8::MIN, "-128i8")?;
+        check_round_trip(-2i16, "-2i16")?;
+        check_round_trip(i32::MAX, "2147483647i32")?;
+        check_round_trip(-4i64, "-4i64")?;
+        check_round_trip(i128::MIN, "-170141183460469231731687303715884105728i128")?;
+        check_round_trip(u8::MAX, "255u8")?;
+        check_round_trip(6u16, "6u16")?;
+        check_round_trip(7u32, "7u32")?;
+        check_round_trip(u64::MAX, "18446744073709551615u64")?;
+        check_round_trip(u128::MAX, "340282366920938463463374607431768211455u128")
+    }
+
+    #[test]
+    fn test_field_bytes_round_trip() -> Result<()> {
+        let mut bytes = [0u8; 32];
+        bytes[0] = 5;
+        let value = Value::<CurrentNetwork>::try_from(bytes)?;
+        assert_eq!(value, Value::from_str("5field")?);
+        assert_eq!(<[u8; 32]>::try_from(value)?, bytes);
+
+        // Ensure a non-canonical field encoding fails.
+        assert!(Value::<CurrentNetwork>::try_from([u8::MAX; 32]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_native_mismatch_fails() -> Result<()> {
+        // Ensure a literal of a different type fails.
+        let value = Value::<CurrentNetwork>::from_str("1u64")?;
+        assert!(u32::try_from(&value).is_err());
+        assert!(i64::try_from(&value).is_err());
+        assert!(bool::try_from(&value).is_err());
+        assert!(<[u8; 32]>::try_from(&value).is_err());
+
+        // Ensure a struct fails.
+        let value = Value::<CurrentNetwork>::from_str("{ a: 1u32 }")?;
+        assert!(u32::try_from(&value).is_err());
+
+        // Ensure a record fails.
+        let value = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, _nonce: 0group.public }",
+        )?;
+        assert!(u32::try_from(&value).is_err());
+        assert!(<[u8; 32]>::try_from(value).is_err());
+        Ok(())
+    }
+}
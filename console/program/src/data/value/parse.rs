@@ -57,11 +57,21 @@ impl<N: Network> Display for Value<N> {
     }
 }
 
+impl<N: Network> Value<N> {
+    /// Returns the value as a multi-line string, with one member per line and nested members indented.
+    /// Note: This is the same output as `Display`, which is already indented and parses back into the value.
+    pub fn to_string_pretty(&self) -> String {
+        self.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use snarkvm_console_network::Testnet3;
 
+    use indexmap::IndexMap;
+
     type CurrentNetwork = Testnet3;
 
     #[test]
@@ -90,4 +100,52 @@ mod tests {
         assert!(matches!(expected, Value::Record(..)));
         assert_eq!(string, format!("{expected}"));
     }
+
+    #[test]
+    fn test_value_to_string_pretty() {
+        // Prepare a record with a nested struct that is not the last member.
+        let expected = r"{
+  owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private,
+  position: {
+    inner: {
+      x: 1u8.private
+    },
+    y: 2u8.private
+  },
+  amount: 5u64.public,
+  _nonce: 6122363155094913586073041054293642159180066699840940609722305038224296461351group.public
+}";
+        let value = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, position: { inner: { x: 1u8.private }, y: 2u8.private }, amount: 5u64.public, _nonce: 6122363155094913586073041054293642159180066699840940609722305038224296461351group.public }",
+        )
+        .unwrap();
+        assert_eq!(expected, value.to_string_pretty());
+        // Ensure the pretty output parses back into the same value.
+        assert_eq!(value, Value::from_str(&value.to_string_pretty()).unwrap());
+
+        // Prepare a plaintext with a nested struct that is not the last member.
+        let expected = r"{
+  a: {
+    b: 1u8
+  },
+  c: 2u8
+}";
+        let value = Value::<CurrentNetwork>::from_str("{ a: { b: 1u8 }, c: 2u8 }").unwrap();
+        assert_eq!(expected, value.to_string_pretty());
+        assert_eq!(value, Value::from_str(&value.to_string_pretty()).unwrap());
+    }
+
+    #[test]
+    fn test_value_to_string_pretty_empty_struct() {
+        // Ensure an empty struct is printed with a closing brace.
+        let empty = Plaintext::<CurrentNetwork>::Struct(IndexMap::new(), Default::default());
+        assert_eq!("{}", Value::Plaintext(empty.clone()).to_string_pretty());
+
+        // Ensure a nested empty struct is printed with a closing brace.
+        let nested = Plaintext::<CurrentNetwork>::Struct(
+            IndexMap::from_iter([(Identifier::from_str("a").unwrap(), empty)]),
+            Default::default(),
+        );
+        assert_eq!("{\n  a: {}\n}", Value::Plaintext(nested).to_string_pretty());
+    }
 }
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the publicly-visible portion of the value, as a plaintext.
+    /// A plaintext value is returned as is, while a record retains only its constant and public members.
+    pub fn public_projection(&self) -> Result<Plaintext<N>> {
+        match self {
+            Self::Plaintext(plaintext) => Ok(plaintext.clone()),
+            Self::Record(record) => record.to_public_only(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_public_projection() -> Result<()> {
+        // Ensure a plaintext value is unchanged.
+        let value = Value::<CurrentNetwork>::from_str("{ a: 1u8, b: { c: true } }")?;
+        assert_eq!(value.public_projection()?, Plaintext::from_str("{ a: 1u8, b: { c: true } }")?);
+
+        // Ensure a record value drops its private members.
+        let value = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, a: 1u8.private, b: 2u16.public, c: { d: 3u32.constant }, _nonce: 0group.public }",
+        )?;
+        assert_eq!(value.public_projection()?, Plaintext::from_str("{ b: 2u16, c: { d: 3u32 } }")?);
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Sets the value at the given path to the given plaintext.
+    /// If the value is a record, the visibility of the record entry is preserved.
+    pub fn set(&mut self, path: &[Identifier<N>], new_value: Plaintext<N>) -> Result<()> {
+        match self {
+            Self::Plaintext(plaintext) => plaintext.set(path, new_value),
+            Self::Record(record) => record.set(path, new_value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn path(path: &[&str]) -> Vec<Identifier<CurrentNetwork>> {
+        path.iter().map(|identifier| Identifier::from_str(identifier).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_set_plaintext() -> Result<()> {
+        let mut value = Value::<CurrentNetwork>::from_str("{ a: 1u8, b: { c: 2field, d: true } }")?;
+
+        // Ensure the top-level and nested members are updated.
+        value.set(&path(&["a"]), Plaintext::from_str("3u8")?)?;
+        value.set(&path(&["b", "c"]), Plaintext::from_str("4field")?)?;
+        assert_eq!(Value::from_str("{ a: 3u8, b: { c: 4field, d: true } }")?, value);
+        assert_eq!(Value::from_str("4field")?, value.find(&path(&["b", "c"]))?);
+        // Ensure the bits are recomputed after the update.
+        assert_eq!(Value::from_str("{ a: 3u8, b: { c: 4field, d: true } }")?.to_bits_le(), value.to_bits_le());
+
+        // Ensure the path must exist.
+        assert!(value.set(&path(&["e"]), Plaintext::from_str("3u8")?).is_err());
+        assert!(value.set(&path(&["a", "c"]), Plaintext::from_str("3u8")?).is_err());
+        // Ensure the path must target a leaf.
+        assert!(value.set(&path(&["b"]), Plaintext::from_str("{ c: 2field, d: true }")?).is_err());
+        // Ensure the new value must match the type of the leaf.
+        assert!(value.set(&path(&["a"]), Plaintext::from_str("3u16")?).is_err());
+        assert!(value.set(&path(&["b", "d"]), Plaintext::from_str("{ e: true }")?).is_err());
+        // Ensure the failed updates did not modify the value.
+        assert_eq!(Value::from_str("{ a: 3u8, b: { c: 4field, d: true } }")?, value);
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_record() -> Result<()> {
+        let record = |a: &str, b: &str, c: &str| {
+            Value::<CurrentNetwork>::from_str(&format!(
+                "{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, a: {a}u64.constant, b: {b}u64.public, c: {{ d: {c}u64.private }}, _nonce: 0group.public }}"
+            ))
+            .unwrap()
+        };
+
+        let mut value = record("1", "2", "3");
+
+        // Ensure the entries of each visibility are updated, preserving their visibility.
+        value.set(&path(&["a"]), Plaintext::from_str("4u64")?)?;
+        value.set(&path(&["b"]), Plaintext::from_str("5u64")?)?;
+        value.set(&path(&["c", "d"]), Plaintext::from_str("6u64")?)?;
+        assert_eq!(record("4", "5", "6"), value);
+
+        // Ensure the owner cannot be set.
+        let owner = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+        assert!(value.set(&path(&["owner"]), Plaintext::from_str(owner)?).is_err());
+        // Ensure the path must exist.
+        assert!(value.set(&path(&["e"]), Plaintext::from_str("1u64")?).is_err());
+        assert!(value.set(&path(&["c", "e"]), Plaintext::from_str("1u64")?).is_err());
+        // Ensure the path must target a leaf.
+        assert!(value.set(&path(&["c"]), Plaintext::from_str("{ d: 1u64 }")?).is_err());
+        // Ensure the new value must match the type of the leaf.
+        assert!(value.set(&path(&["b"]), Plaintext::from_str("1u32")?).is_err());
+        // Ensure the failed updates did not modify the value.
+        assert_eq!(record("4", "5", "6"), value);
+        Ok(())
+    }
+}
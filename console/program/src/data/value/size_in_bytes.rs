@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the number of bytes in the byte encoding of this value.
+    pub fn size_in_bytes(&self) -> usize {
+        // The variant tag, followed by the plaintext or record.
+        1 + match self {
+            Self::Plaintext(plaintext) => plaintext.size_in_bytes(),
+            Self::Record(record) => record.size_in_bytes(),
+        }
+    }
+
+    /// Returns the nesting depth of this value.
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::Plaintext(plaintext) => plaintext.depth(),
+            Self::Record(record) => record.depth(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_size_in_bytes(value: &str) -> Result<()> {
+        let value = Value::<CurrentNetwork>::from_str(value)?;
+        assert_eq!(value.to_bytes_le()?.len(), value.size_in_bytes());
+        Ok(())
+    }
+
+    #[test]
+    fn test_size_in_bytes() -> Result<()> {
+        // Literals.
+        check_size_in_bytes("true")?;
+        check_size_in_bytes("5u8")?;
+        check_size_in_bytes("-5i128")?;
+        check_size_in_bytes("1field")?;
+        check_size_in_bytes("0group")?;
+        check_size_in_bytes("3scalar")?;
+        check_size_in_bytes("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah")?;
+        check_size_in_bytes("\"\"")?;
+        check_size_in_bytes("\"hello world\"")?;
+        // Structs.
+        check_size_in_bytes("{ a: 1u32 }")?;
+        check_size_in_bytes(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, token_amount: 100u64 }",
+        )?;
+        check_size_in_bytes("{ a: { b: { c: \"nested\", d: 1i8 } }, e: 0field }")?;
+        // Records.
+        check_size_in_bytes(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, _nonce: 0group.public }",
+        )?;
+        check_size_in_bytes(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, token_amount: 100u64.private, data: { a: 1u8.constant, b: { c: true.constant } }, _nonce: 0group.public }",
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_depth() -> Result<()> {
+        let depth = |value: &str| Value::<CurrentNetwork>::from_str(value).map(|value| value.depth());
+
+        assert_eq!(0, depth("5u8")?);
+        assert_eq!(1, depth("{ a: 1u32, b: 2u32 }")?);
+        assert_eq!(2, depth("{ a: { b: 1u32 }, c: 2u32 }")?);
+        assert_eq!(3, depth("{ a: { b: { c: \"nested\", d: 1i8 } }, e: 0field }")?);
+        assert_eq!(
+            1,
+            depth(
+                "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u64.private, _nonce: 0group.public }"
+            )?
+        );
+        assert_eq!(
+            3,
+            depth(
+                "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, data: { a: 1u8.constant, b: { c: true.constant } }, _nonce: 0group.public }"
+            )?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Owner;
+
+impl<N: Network> Value<N> {
+    /// Returns the value as a list of self-describing **little-endian** bits.
+    ///
+    /// Unlike `to_bits_le`, which matches the circuit, this encoding includes the value variant
+    /// and the size of each component, so it can be decoded with `from_tagged_bits_le`. The layout is:
+    /// - `variant` (8 bits): `0` for a plaintext, `1` for a record.
+    /// - plaintext: `size` (32 bits), followed by the plaintext bits (`Plaintext::to_bits_le`).
+    /// - record: `owner` (1 visibility bit, followed by the address bits),
+    ///   `num_entries` (8 bits), then for each entry: `identifier_size` (8 bits), the identifier bits,
+    ///   `entry_size` (32 bits), and the entry bits (`Entry::to_bits_le`), followed by the `nonce` bits.
+    pub fn to_tagged_bits_le(&self) -> Vec<bool> {
+        match self {
+            Self::Plaintext(plaintext) => {
+                let mut bits_le = 0u8.to_bits_le();
+                let plaintext_bits = plaintext.to_bits_le();
+                bits_le.extend(
+                    u32::try_from(plaintext_bits.len())
+                        .or_halt_with::<N>("Plaintext exceeds u32::MAX bits")
+                        .to_bits_le(),
+                );
+                bits_le.extend(plaintext_bits);
+                bits_le
+            }
+            Self::Record(record) => {
+                let mut bits_le = 1u8.to_bits_le();
+                bits_le.extend(record.owner().to_bits_le());
+                bits_le.extend(
+                    u8::try_from(record.data().len()).or_halt_with::<N>("Record entries exceed u8::MAX").to_bits_le(),
+                );
+                for (identifier, entry) in record.data() {
+                    let entry_bits = entry.to_bits_le();
+                    bits_le.extend(identifier.size_in_bits().to_bits_le());
+                    bits_le.extend(identifier.to_bits_le());
+                    bits_le.extend(
+                        u32::try_from(entry_bits.len())
+                            .or_halt_with::<N>("Record entry exceeds u32::MAX bits")
+                            .to_bits_le(),
+                    );
+                    bits_le.extend(entry_bits);
+                }
+                bits_le.extend(record.nonce().to_bits_le());
+                bits_le
+            }
+        }
+    }
+
+    /// Initializes a new value from a list of self-describing **little-endian** bits from `to_tagged_bits_le`.
+    pub fn from_tagged_bits_le(bits_le: &[bool]) -> Result<Self> {
+        let mut counter = 0;
+
+        // Returns the next `size` bits, ensuring the bits are not truncated.
+        let mut next = |size: usize| match bits_le.get(counter..counter + size) {
+            Some(bits) => {
+                counter += size;
+                Ok(bits)
+            }
+            None => Err(anyhow!("Failed to deserialize value: expected {size} bits at index {counter}")),
+        };
+
+        let value = match u8::from_bits_le(next(8)?)? {
+            0 => {
+                let size = u32::from_bits_le(next(32)?)?;
+                Self::Plaintext(Plaintext::from_bits_le(next(size as usize)?)?)
+            }
+            1 => {
+                // Recover the owner.
+                let is_private = next(1)?[0];
+                let address = Address::from_bits_le(next(Address::<N>::size_in_bits())?)?;
+                let owner = match is_private {
+                    true => Owner::Private(Plaintext::from(Literal::Address(address))),
+                    false => Owner::Public(address),
+                };
+
+                // Recover the entries.
+                let num_entries = u8::from_bits_le(next(8)?)?;
+                let mut data = IndexMap::with_capacity(num_entries as usize);
+                for _ in 0..num_entries {
+                    let identifier_size = u8::from_bits_le(next(8)?)?;
+                    let identifier = Identifier::from_bits_le(next(identifier_size as usize)?)?;
+
+                    let entry_size = u32::from_bits_le(next(32)?)?;
+                    let entry_bits = next(entry_size as usize)?;
+                    let plaintext = match entry_bits.get(2..) {
+                        Some(plaintext_bits) => Plaintext::from_bits_le(plaintext_bits)?,
+                        None => {
+                            bail!("Failed to deserialize value: record entry '{identifier}' is missing its visibility")
+                        }
+                    };
+                    let entry = match [entry_bits[0], entry_bits[1]] {
+                        [false, false] => Entry::Constant(plaintext),
+                        [false, true] => Entry::Public(plaintext),
+                        [true, false] => Entry::Private(plaintext),
+                        [true, true] => {
+                            bail!("Failed to deserialize value: unknown visibility for entry '{identifier}'")
+                        }
+                    };
+
+                    if data.insert(identifier, entry).is_some() {
+                        bail!("Failed to deserialize value: duplicate entry '{identifier}' in record");
+                    }
+                }
+
+                // Recover the nonce.
+                let nonce = Group::from_bits_le(next(Group::<N>::size_in_bits())?)?;
+
+                Self::Record(Record::<N, Plaintext<N>>::from_plaintext(owner, data, nonce)?)
+            }
+            variant => bail!("Failed to deserialize value: unknown variant {variant}"),
+        };
+
+        // Ensure all of the bits were consumed.
+        ensure!(
+            counter == bits_le.len(),
+            "Failed to deserialize value: found {} trailing bits",
+            bits_le.len() - counter
+        );
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const LITERALS: &str = "a: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, b: true, c: 1field, d: 0group, e: -1i8, f: -2i16, g: -3i32, h: -4i64, i: -5i128, j: 1u8, k: 2u16, l: 3u32, m: 4u64, n: 5u128, o: 1scalar, p: \"hello\"";
+
+    fn check_tagged_bits(expected: &Value<CurrentNetwork>) -> Result<()> {
+        let candidate = Value::from_tagged_bits_le(&expected.to_tagged_bits_le())?;
+        assert_eq!(*expected, candidate);
+        assert_eq!(expected.to_string(), candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tagged_bits_plaintext() -> Result<()> {
+        // Check each literal type.
+        let value = Value::<CurrentNetwork>::from_str(&format!("{{ {LITERALS} }}"))?;
+        if let Value::Plaintext(Plaintext::Struct(members, ..)) = &value {
+            for member in members.values() {
+                check_tagged_bits(&Value::Plaintext(member.clone()))?;
+            }
+        }
+        // Check a nested struct.
+        check_tagged_bits(&Value::from_str(&format!("{{ x: {{ {LITERALS} }}, y: {{ z: 1u8 }} }}"))?)
+    }
+
+    #[test]
+    fn test_tagged_bits_record() -> Result<()> {
+        for owner in ["public", "private"] {
+            let record = Value::<CurrentNetwork>::from_str(&format!(
+                "{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.{owner}, a: 1u64.constant, b: -2i128.public, c: {{ d: 3field.private, e: \"hello\".private }}, f: true.public, _nonce: 0group.public }}"
+            ))?;
+            check_tagged_bits(&record)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_tagged_bits_malformed() -> Result<()> {
+        let record = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, a: { b: 1u64.public }, _nonce: 0group.public }",
+        )?;
+        let bits_le = record.to_tagged_bits_le();
+
+        // Ensure every truncation fails, without panicking.
+        for length in 0..bits_le.len() {
+            assert!(Value::<CurrentNetwork>::from_tagged_bits_le(&bits_le[..length]).is_err());
+        }
+
+        // Ensure trailing bits fail.
+        let error = Value::<CurrentNetwork>::from_tagged_bits_le(&[bits_le.clone(), vec![false]].concat()).unwrap_err();
+        assert_eq!("Failed to deserialize value: found 1 trailing bits", error.to_string());
+
+        // Ensure an unknown variant fails.
+        let mut corrupted = bits_le.clone();
+        corrupted[1] = true;
+        let error = Value::<CurrentNetwork>::from_tagged_bits_le(&corrupted).unwrap_err();
+        assert_eq!("Failed to deserialize value: unknown variant 3", error.to_string());
+
+        // Ensure corrupting every bit does not panic.
+        for i in 0..bits_le.len() {
+            let mut corrupted = bits_le.clone();
+            corrupted[i] = !corrupted[i];
+            let _ = Value::<CurrentNetwork>::from_tagged_bits_le(&corrupted);
+        }
+        Ok(())
+    }
+}
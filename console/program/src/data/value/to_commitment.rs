@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::ProgramID;
+
+impl<N: Network> Value<N> {
+    /// Returns the record commitment, matching the commitment computed in-circuit for the record.
+    pub fn to_record_commitment(&self, program_id: &ProgramID<N>, record_name: &Identifier<N>) -> Result<Field<N>> {
+        match self {
+            Self::Record(record) => record.to_commitment(program_id, record_name),
+            Self::Plaintext(..) => bail!("Illegal operation: a record commitment requires a record value"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_record_commitment() -> Result<()> {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        let record_name = Identifier::from_str("token")?;
+
+        // Ensure the commitment of a record value matches the record commitment.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u64.private, _nonce: 0group.public }",
+        )?;
+        let value = Value::Record(record.clone());
+        assert_eq!(
+            record.to_commitment(&program_id, &record_name)?,
+            value.to_record_commitment(&program_id, &record_name)?
+        );
+
+        // Ensure the commitment depends on the record name.
+        let other_name = Identifier::from_str("other")?;
+        assert_ne!(
+            value.to_record_commitment(&program_id, &record_name)?,
+            value.to_record_commitment(&program_id, &other_name)?
+        );
+
+        // Ensure a plaintext value is rejected.
+        let value = Value::<CurrentNetwork>::from_str("100u64")?;
+        assert!(value.to_record_commitment(&program_id, &record_name).is_err());
+        Ok(())
+    }
+}
@@ -17,6 +17,7 @@ mod parse;
 mod serialize;
 
 use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Boolean, Field, Group, Scalar, I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
 
 use core::fmt::{self, Debug, Display};
 use num_derive::FromPrimitive;
@@ -80,4 +81,44 @@ impl LiteralType {
             Self::String => "string",
         }
     }
+
+    /// Returns the number of bits of a literal of this type, or `None` if it depends on the literal.
+    pub fn size_in_bits<N: Network>(&self) -> Option<u16> {
+        let size = match self {
+            Self::Address => Address::<N>::size_in_bits(),
+            Self::Boolean => Boolean::<N>::size_in_bits(),
+            Self::Field => Field::<N>::size_in_bits(),
+            Self::Group => Group::<N>::size_in_bits(),
+            Self::I8 => I8::<N>::size_in_bits(),
+            Self::I16 => I16::<N>::size_in_bits(),
+            Self::I32 => I32::<N>::size_in_bits(),
+            Self::I64 => I64::<N>::size_in_bits(),
+            Self::I128 => I128::<N>::size_in_bits(),
+            Self::U8 => U8::<N>::size_in_bits(),
+            Self::U16 => U16::<N>::size_in_bits(),
+            Self::U32 => U32::<N>::size_in_bits(),
+            Self::U64 => U64::<N>::size_in_bits(),
+            Self::U128 => U128::<N>::size_in_bits(),
+            Self::Scalar => Scalar::<N>::size_in_bits(),
+            Self::String => return None,
+        };
+        u16::try_from(size).ok()
+    }
+
+    /// Returns `true` if the literal type is a signed or unsigned integer type.
+    pub const fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            Self::I8
+                | Self::I16
+                | Self::I32
+                | Self::I64
+                | Self::I128
+                | Self::U8
+                | Self::U16
+                | Self::U32
+                | Self::U64
+                | Self::U128
+        )
+    }
 }
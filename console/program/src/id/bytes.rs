@@ -19,7 +19,7 @@ impl<N: Network> FromBytes for ProgramID<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let name = FromBytes::read_le(&mut reader)?;
         let network = FromBytes::read_le(&mut reader)?;
-        Ok(Self::from((name, network)))
+        Self::new(name, network).map_err(|e| error(e.to_string()))
     }
 }
 
@@ -30,3 +30,25 @@ impl<N: Network> ToBytes for ProgramID<N> {
         self.network.write_le(&mut writer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let expected = ProgramID::<CurrentNetwork>::from_str("hello.aleo")?;
+        let candidate = ProgramID::from_bytes_le(&expected.to_bytes_le()?)?;
+        assert_eq!(expected, candidate);
+
+        // Ensure an unsupported network-level domain is rejected.
+        let unsupported =
+            ProgramID::<CurrentNetwork>::from((Identifier::from_str("hello")?, Identifier::from_str("btc")?));
+        let error = ProgramID::<CurrentNetwork>::from_bytes_le(&unsupported.to_bytes_le()?).unwrap_err();
+        assert!(error.to_string().contains("Invalid network identifier: expected '.aleo'"));
+        Ok(())
+    }
+}
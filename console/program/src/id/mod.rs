@@ -23,6 +23,9 @@ use crate::Identifier;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Address, Field};
 
+/// The supported network-level domains (NLDs).
+pub const NETWORK_LEVEL_DOMAINS: [&str; 1] = ["aleo"];
+
 /// A program ID is of the form `{name}.{network}`.
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct ProgramID<N: Network> {
@@ -73,7 +76,7 @@ impl<N: Network> TryFrom<&str> for ProgramID<N> {
         let mut split = program_id.split('.');
         // Parse the name and network.
         if let (Some(name), Some(network), None) = (split.next(), split.next(), split.next()) {
-            Ok(Self { name: Identifier::from_str(name)?, network: Identifier::from_str(network)? })
+            Self::new(Identifier::from_str(name)?, Identifier::from_str(network)?)
         } else {
             bail!("Invalid program ID '{program_id}'")
         }
@@ -81,6 +84,15 @@ impl<N: Network> TryFrom<&str> for ProgramID<N> {
 }
 
 impl<N: Network> ProgramID<N> {
+    /// Initializes a program ID from a name and network-level domain identifier,
+    /// ensuring the network-level domain is supported.
+    #[inline]
+    pub fn new(name: Identifier<N>, network: Identifier<N>) -> Result<Self> {
+        // Ensure the network-level domain is supported.
+        Self::check_network(&network)?;
+        Ok(Self { name, network })
+    }
+
     /// Returns the program name.
     #[inline]
     pub const fn name(&self) -> &Identifier<N> {
@@ -93,11 +105,34 @@ impl<N: Network> ProgramID<N> {
         &self.network
     }
 
+    /// Returns `true` if `self` and `other` have the same program name, regardless of the network-level domain.
+    #[inline]
+    pub fn name_eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+
+    /// Returns the program ID with its network-level domain (NLD) replaced by the given `network`.
+    #[inline]
+    pub fn with_network(self, network: Identifier<N>) -> Self {
+        Self { name: self.name, network }
+    }
+
     /// Returns `true` if the network-level domain is `aleo`.
     #[inline]
     pub fn is_aleo(&self) -> bool {
         self.network() == &Identifier::from_str("aleo").expect("Failed to parse Aleo domain")
     }
+
+    /// Ensures the given network-level domain (NLD) is supported.
+    fn check_network(network: &Identifier<N>) -> Result<()> {
+        match NETWORK_LEVEL_DOMAINS.contains(&network.to_string().as_str()) {
+            true => Ok(()),
+            false => {
+                let expected = NETWORK_LEVEL_DOMAINS.iter().map(|nld| format!("'.{nld}'")).collect::<Vec<_>>();
+                bail!("Invalid network identifier: expected {}", expected.join(" or "))
+            }
+        }
+    }
 }
 
 impl<N: Network> Ord for ProgramID<N> {
@@ -154,4 +189,37 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_name_eq() -> Result<()> {
+        let bar_aleo = ProgramID::<CurrentNetwork>::from_str("bar.aleo")?;
+        let bar_testnet = ProgramID::from((Identifier::from_str("bar")?, Identifier::from_str("testnet")?));
+        let foo_aleo = ProgramID::<CurrentNetwork>::from_str("foo.aleo")?;
+
+        assert!(bar_aleo.name_eq(&bar_testnet));
+        assert!(bar_testnet.name_eq(&bar_aleo));
+        assert_ne!(bar_aleo, bar_testnet);
+
+        assert!(!bar_aleo.name_eq(&foo_aleo));
+        assert!(!bar_testnet.name_eq(&foo_aleo));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_network() -> Result<()> {
+        let bar_aleo = ProgramID::<CurrentNetwork>::from_str("bar.aleo")?;
+
+        let bar_testnet = bar_aleo.with_network(Identifier::from_str("testnet")?);
+        assert_eq!("bar.testnet", bar_testnet.to_string());
+        assert_eq!(bar_aleo.name(), bar_testnet.name());
+        assert_eq!("testnet", bar_testnet.network().to_string());
+        assert!(!bar_testnet.is_aleo());
+
+        let candidate = bar_testnet.with_network(Identifier::from_str("aleo")?);
+        assert_eq!(bar_aleo, candidate);
+        assert_eq!("bar.aleo", candidate.to_string());
+
+        Ok(())
+    }
 }
@@ -21,7 +21,10 @@ impl<N: Network> Parser for ProgramID<N> {
         // Parse the name from the string.
         let (string, name) = Identifier::parse(string)?;
         // Parse the "." and network-level domain (NLD) from the string.
-        let (string, (_, network)) = pair(tag("."), Identifier::parse)(string)?;
+        let (string, (_, network)) = pair(
+            tag("."),
+            map_res(Identifier::parse, |network| Self::check_network(&network).map(|_| network)),
+        )(string)?;
         // Return the program ID.
         Ok((string, Self { name, network }))
     }
@@ -40,7 +43,13 @@ impl<N: Network> FromStr for ProgramID<N> {
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => {
+                // Report an unsupported network-level domain, as the parser error does not describe it.
+                if let Some(Ok(network)) = string.split_once('.').map(|(_, network)| Identifier::from_str(network)) {
+                    Self::check_network(&network)?;
+                }
+                bail!("Failed to parse string. {error}")
+            }
         }
     }
 }
@@ -73,7 +82,24 @@ mod tests {
         assert_eq!(id.network(), &Identifier::<CurrentNetwork>::from_str("aleo")?);
 
         assert!(ProgramID::<CurrentNetwork>::parse("foo").is_err());
+        assert!(ProgramID::<CurrentNetwork>::parse("foo.").is_err());
+        assert!(ProgramID::<CurrentNetwork>::parse("foo.btc").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_network() -> Result<()> {
+        assert_eq!("bar.aleo", ProgramID::<CurrentNetwork>::from_str("bar.aleo")?.to_string());
+        assert_eq!("bar.aleo", ProgramID::<CurrentNetwork>::try_from("bar.aleo")?.to_string());
+
+        let error = ProgramID::<CurrentNetwork>::from_str("bar.btc").unwrap_err();
+        assert_eq!("Invalid network identifier: expected '.aleo'", error.to_string());
+        let error = ProgramID::<CurrentNetwork>::try_from("bar.btc").unwrap_err();
+        assert_eq!("Invalid network identifier: expected '.aleo'", error.to_string());
 
+        assert!(ProgramID::<CurrentNetwork>::from_str("bar").is_err());
+        assert!(ProgramID::<CurrentNetwork>::from_str("bar.").is_err());
         Ok(())
     }
 
@@ -16,6 +16,11 @@ use super::*;
 
 impl<N: Network> ProgramID<N> {
     /// Returns the program address for this program ID.
+    ///
+    /// The address is derived as `HashToGroupPSD4(name || network)`, where `name` and `network`
+    /// are the field encodings of the program name and network-level domain. As the derivation
+    /// is deterministic and has no known discrete log, a program address can receive records
+    /// but cannot be spent from with a private key.
     pub fn to_address(&self) -> Result<Address<N>> {
         // Compute the program address as `HashToGroup(program_id)`.
         let group = N::hash_to_group_psd4(&[self.name().to_field()?, self.network().to_field()?])?;
@@ -23,3 +28,66 @@ impl<N: Network> ProgramID<N> {
         Ok(Address::new(group))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::identifier::tests::sample_identifier_as_string;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_to_address() -> Result<()> {
+        // Ensure the program addresses match the known fixtures.
+        for (program_id, expected) in
+            [("credits.aleo", "aleo1lqmly7ez2k48ajf5hs92ulphaqr05qm4n8qwzj8v0yprmasgpqgsez59gg")]
+        {
+            let program_id = ProgramID::<CurrentNetwork>::from_str(program_id)?;
+            assert_eq!(Address::from_str(expected)?, program_id.to_address()?);
+        }
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random program ID.
+            let name = sample_identifier_as_string::<CurrentNetwork>(&mut rng)?;
+            let program_id = ProgramID::<CurrentNetwork>::from_str(&format!("{name}.aleo"))?;
+
+            // Ensure the derivation is stable.
+            let expected = program_id.to_address()?;
+            assert_eq!(expected, program_id.to_address()?);
+            // Ensure a parsed copy of the program ID derives the same address.
+            assert_eq!(expected, ProgramID::<CurrentNetwork>::from_str(&program_id.to_string())?.to_address()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_address_is_unique() -> Result<()> {
+        let credits = ProgramID::<CurrentNetwork>::from_str("credits.aleo")?;
+        let token = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        assert_ne!(credits.to_address()?, token.to_address()?);
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample two random program IDs.
+            let a = ProgramID::<CurrentNetwork>::from_str(&format!(
+                "{}.aleo",
+                sample_identifier_as_string::<CurrentNetwork>(&mut rng)?
+            ))?;
+            let b = ProgramID::<CurrentNetwork>::from_str(&format!(
+                "{}.aleo",
+                sample_identifier_as_string::<CurrentNetwork>(&mut rng)?
+            ))?;
+            // Ensure distinct program IDs derive distinct addresses.
+            if a != b {
+                assert_ne!(a.to_address()?, b.to_address()?);
+            }
+        }
+        Ok(())
+    }
+}
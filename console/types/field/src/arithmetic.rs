@@ -14,6 +14,16 @@
 
 use super::*;
 
+impl<E: Environment> Field<E> {
+    /// Returns the `quotient` of `self` and `other`, or an error if `other` is zero.
+    pub fn try_div(&self, other: &Self) -> Result<Self> {
+        match other.is_zero() {
+            true => bail!("Field division by zero: {self} / {other}"),
+            false => Ok(Field::new(self.field / other.field)),
+        }
+    }
+}
+
 impl<E: Environment> Neg for Field<E> {
     type Output = Field<E>;
 
@@ -138,10 +148,7 @@ impl<E: Environment> Div<Field<E>> for Field<E> {
     /// Returns the `quotient` of `self` and `other`.
     #[inline]
     fn div(self, other: Field<E>) -> Self::Output {
-        match other.is_zero() {
-            true => E::halt(format!("Field division by zero: {self} / {other}")),
-            false => Field::new(self.field / other.field),
-        }
+        self.try_div(&other).or_halt::<E>()
     }
 }
 
@@ -151,10 +158,7 @@ impl<E: Environment> Div<&Field<E>> for Field<E> {
     /// Returns the `quotient` of `self` and `other`.
     #[inline]
     fn div(self, other: &Field<E>) -> Self::Output {
-        match other.is_zero() {
-            true => E::halt(format!("Field division by zero: {self} / {other}")),
-            false => Field::new(self.field / other.field),
-        }
+        self.try_div(other).or_halt::<E>()
     }
 }
 
@@ -162,10 +166,7 @@ impl<E: Environment> DivAssign<Field<E>> for Field<E> {
     /// Divides `self` by `other`.
     #[inline]
     fn div_assign(&mut self, other: Field<E>) {
-        match other.is_zero() {
-            true => E::halt(format!("Field division by zero: {self} / {other}")),
-            false => self.field /= other.field,
-        }
+        *self = self.try_div(&other).or_halt::<E>();
     }
 }
 
@@ -173,10 +174,7 @@ impl<E: Environment> DivAssign<&Field<E>> for Field<E> {
     /// Divides `self` by `other`.
     #[inline]
     fn div_assign(&mut self, other: &Field<E>) {
-        match other.is_zero() {
-            true => E::halt(format!("Field division by zero: {self} / {other}")),
-            false => self.field /= other.field,
-        }
+        *self = self.try_div(other).or_halt::<E>();
     }
 }
 
@@ -200,6 +198,16 @@ impl<E: Environment> Pow<&Field<E>> for Field<E> {
     }
 }
 
+impl<E: Environment> ModPow<Field<E>> for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns `self` to the power of `exponent`, where the exponent is a field element.
+    #[inline]
+    fn mod_pow(&self, exponent: &Field<E>) -> Self::Output {
+        Field::new(self.field.pow(exponent.field.to_bigint()))
+    }
+}
+
 impl<E: Environment> Double for Field<E> {
     type Output = Field<E>;
 
@@ -218,7 +226,7 @@ impl<E: Environment> Inverse for Field<E> {
     fn inverse(&self) -> Result<Self::Output> {
         match self.field.inverse() {
             Some(inverse) => Ok(Field::new(inverse)),
-            None => bail!("Failed to invert a field element: {self}"),
+            None => bail!("Cannot invert zero in the field."),
         }
     }
 }
@@ -246,6 +254,17 @@ impl<E: Environment> SquareRoot for Field<E> {
     }
 }
 
+impl<E: Environment> SquareRootCanonical for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns the lexicographically smaller of the two square roots of `self`.
+    #[inline]
+    fn square_root_canonical(&self) -> Result<Self::Output> {
+        let square_root = self.square_root()?;
+        Ok(core::cmp::min(square_root, -square_root))
+    }
+}
+
 impl<E: Environment> Sum<Field<E>> for Field<E> {
     /// Returns the `sum` of `self` and `other`.
     #[inline]
@@ -292,5 +311,61 @@ mod tests {
 
         let result = std::panic::catch_unwind(|| one / zero);
         assert!(result.is_err()); // Probe further for specific error type here, if desired
+
+        let error = one.try_div(&zero).unwrap_err();
+        assert_eq!(format!("Field division by zero: {one} / {zero}"), error.to_string());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..1_000 {
+            let given = Field::<CurrentEnvironment>::rand(&mut rng);
+            if !given.is_zero() {
+                let inverse = given.inverse().unwrap();
+                assert_eq!(Field::one(), given * inverse);
+                assert_eq!(given, inverse.inverse().unwrap());
+            }
+        }
+
+        let error = Field::<CurrentEnvironment>::zero().inverse().unwrap_err();
+        assert_eq!("Cannot invert zero in the field.", error.to_string());
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..1_000 {
+            let base = Field::<CurrentEnvironment>::rand(&mut rng);
+            let exponent = Field::<CurrentEnvironment>::rand(&mut rng);
+            assert_eq!(base.pow(exponent), base.mod_pow(&exponent));
+
+            // Check small exponents.
+            assert_eq!(Field::one(), base.mod_pow(&Field::zero()));
+            assert_eq!(base, base.mod_pow(&Field::one()));
+            assert_eq!(base * base, base.mod_pow(&Field::from_u8(2)));
+            assert_eq!(base * base * base, base.mod_pow(&Field::from_u8(3)));
+        }
+        assert_eq!(Field::<CurrentEnvironment>::one(), Field::zero().mod_pow(&Field::zero()));
+    }
+
+    #[test]
+    fn test_square_root_canonical() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..1_000 {
+            let given = Field::<CurrentEnvironment>::rand(&mut rng);
+            match given.square_root_canonical() {
+                Ok(root) => {
+                    assert_eq!(given, root * root);
+                    assert!(root <= -root);
+                    assert_eq!(root, (-root).square().square_root_canonical().unwrap());
+                }
+                Err(_) => assert!(given.square_root().is_err()),
+            }
+        }
+        assert_eq!(Field::<CurrentEnvironment>::zero(), Field::zero().square_root_canonical().unwrap());
     }
 }
@@ -52,6 +52,15 @@ impl<E: Environment> Compare for Field<E> {
     }
 }
 
+impl<E: Environment> IsZero for Field<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is zero.
+    fn is_equal_to_zero(&self) -> Self::Output {
+        Boolean::new(self.is_zero())
+    }
+}
+
 impl<E: Environment> Ternary for Field<E> {
     type Boolean = Boolean<E>;
     type Output = Self;
@@ -14,19 +14,159 @@
 
 use super::*;
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns a description of the bound exceeded by the given expression, e.g. `255u8 + 1u8 exceeds u8::MAX`.
+    fn exceeds(&self, is_above: bool, expression: String) -> String {
+        match is_above {
+            true => format!("{expression} exceeds {}::MAX", I::type_name()),
+            false => format!("{expression} is below {}::MIN", I::type_name()),
+        }
+    }
+
+    /// Returns the reason the given checked division or remainder failed, e.g. `division by zero`.
+    fn division_failure(&self, other: &Self, operation: &str, symbol: &str) -> String {
+        match other.is_zero() {
+            true => format!("{operation} by zero"),
+            false => self.exceeds(true, format!("{self} {symbol} {other}")),
+        }
+    }
+
+    /// Returns the `negation` of `self`, or an error if the negation overflows.
+    pub fn try_neg(&self) -> Result<Self> {
+        match I::is_signed() {
+            true => match self.integer.checked_neg() {
+                Some(integer) => Ok(Integer::new(integer)),
+                None => {
+                    bail!("Integer negation failed on: {} ({})", self.integer, self.exceeds(true, format!("-({self})")))
+                }
+            },
+            false => bail!("Negation of unsigned integers is not supported."),
+        }
+    }
+
+    /// Returns the `absolute value` of `self`, or an error if the absolute value overflows.
+    pub fn try_abs_checked(&self) -> Result<Self> {
+        match I::is_signed() {
+            true => match self.integer.checked_abs() {
+                Some(integer) => Ok(Integer::new(integer)),
+                None => bail!(
+                    "Integer absolute value failed on: {} ({})",
+                    self.integer,
+                    self.exceeds(true, format!("|{self}|"))
+                ),
+            },
+            false => Ok(*self),
+        }
+    }
+
+    /// Returns the `sum` of `self` and `other`, or an error if the sum overflows.
+    pub fn try_add(&self, other: &Self) -> Result<Self> {
+        match self.integer.checked_add(&other.integer) {
+            Some(integer) => Ok(Integer::new(integer)),
+            None => bail!(
+                "Integer addition failed on: {self} and {other} ({})",
+                self.exceeds(other.integer >= I::zero(), format!("{self} + {other}"))
+            ),
+        }
+    }
+
+    /// Returns the `difference` of `self` and `other`, or an error if the difference overflows.
+    pub fn try_sub(&self, other: &Self) -> Result<Self> {
+        match self.integer.checked_sub(&other.integer) {
+            Some(integer) => Ok(Integer::new(integer)),
+            None => bail!(
+                "Integer subtraction failed on: {self} and {other} ({})",
+                self.exceeds(other.integer < I::zero(), format!("{self} - {other}"))
+            ),
+        }
+    }
+
+    /// Returns the `product` of `self` and `other`, or an error if the product overflows.
+    pub fn try_mul(&self, other: &Self) -> Result<Self> {
+        match self.integer.checked_mul(&other.integer) {
+            Some(integer) => Ok(Integer::new(integer)),
+            None => bail!(
+                "Integer multiplication failed on: {self} and {other} ({})",
+                self.exceeds((self.integer < I::zero()) == (other.integer < I::zero()), format!("{self} * {other}"))
+            ),
+        }
+    }
+
+    /// Returns the `quotient` of `self` and `other`, or an error if `other` is zero or the quotient overflows.
+    pub fn try_div(&self, other: &Self) -> Result<Self> {
+        match self.integer.checked_div(&other.integer) {
+            Some(integer) => Ok(Integer::new(integer)),
+            None => bail!(
+                "Integer division failed on: {self} and {other} ({})",
+                self.division_failure(other, "division", "/")
+            ),
+        }
+    }
+
+    /// Returns the `quotient` of `self` and `other`, wrapping around at the boundary of the type,
+    /// or an error if `other` is zero.
+    pub fn try_div_wrapped(&self, other: &Self) -> Result<Self> {
+        match other.is_zero() {
+            true => bail!("Integer division by zero: {self} / {other}"),
+            false => Ok(Integer::new(self.integer.wrapping_div(&other.integer))),
+        }
+    }
+
+    /// Returns the result of taking the modulus of `self` with respect to `other`, or an error if `other` is zero.
+    pub fn try_modulo(&self, other: &Self) -> Result<Self> {
+        match I::is_signed() {
+            true => bail!("Taking the modulus of signed integers is not supported"),
+            false => match other.is_zero() {
+                true => bail!("Integer modulus by zero: {self} % {other}"),
+                false => Ok(Integer::new(self.integer.modulo(&other.integer))),
+            },
+        }
+    }
+
+    /// Returns the `remainder` of `self` divided by `other`, or an error if `other` is zero or the remainder overflows.
+    pub fn try_rem(&self, other: &Self) -> Result<Self> {
+        match self.integer.checked_rem(&other.integer) {
+            Some(integer) => Ok(Integer::new(integer)),
+            None => bail!(
+                "Integer remainder failed on: {self} and {other} ({})",
+                self.division_failure(other, "remainder", "%")
+            ),
+        }
+    }
+
+    /// Returns the `remainder` of `self` divided by `other`, wrapping around at the boundary of the type,
+    /// or an error if `other` is zero.
+    pub fn try_rem_wrapped(&self, other: &Self) -> Result<Self> {
+        match other.is_zero() {
+            true => bail!("Integer remainder by zero: {self} % {other}"),
+            false => Ok(Integer::new(self.integer.wrapping_rem(&other.integer))),
+        }
+    }
+
+    /// Returns `self` to the power of `other`, or an error if the power overflows.
+    pub fn try_pow<M: Magnitude>(&self, other: &Integer<E, M>) -> Result<Self> {
+        // Unwrap is safe as we only cast up.
+        match self.integer.checked_pow(&other.integer.to_u32().unwrap()) {
+            Some(integer) => Ok(Integer::new(integer)),
+            None => {
+                // The result is only negative for a negative base and an odd exponent.
+                let is_above = self.integer >= I::zero() || other.integer.to_u32().unwrap() % 2 == 0;
+                bail!(
+                    "Integer power failed on: {self} and {other} ({})",
+                    self.exceeds(is_above, format!("{self} ** {other}"))
+                )
+            }
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Neg for Integer<E, I> {
     type Output = Integer<E, I>;
 
     /// Returns the `negation` of `self`.
     #[inline]
     fn neg(self) -> Self::Output {
-        match I::is_signed() {
-            true => match self.integer.checked_neg() {
-                Some(integer) => Integer::new(integer),
-                None => E::halt(format!("Integer negation failed on: {}", self.integer)),
-            },
-            false => E::halt("Negation of unsigned integers is not supported."),
-        }
+        self.try_neg().or_halt::<E>()
     }
 }
 
@@ -36,13 +176,7 @@ impl<E: Environment, I: IntegerType> AbsChecked for Integer<E, I> {
     /// Returns the `absolute value` of `self`.
     #[inline]
     fn abs_checked(self) -> Self::Output {
-        match I::is_signed() {
-            true => match self.integer.checked_abs() {
-                Some(integer) => Integer::new(integer),
-                None => E::halt(format!("Integer absolute value failed on: {}", self.integer)),
-            },
-            false => self,
-        }
+        self.try_abs_checked().or_halt::<E>()
     }
 }
 
@@ -59,16 +193,26 @@ impl<E: Environment, I: IntegerType> AbsWrapped for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> NegWrapped for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `negation` of `self`, wrapping around at the boundary of the type.
+    #[inline]
+    fn neg_wrapped(self) -> Self::Output {
+        match I::is_signed() {
+            true => Integer::new(self.integer.wrapping_neg()),
+            false => E::halt("Negation of unsigned integers is not supported."),
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Add<Integer<E, I>> for Integer<E, I> {
     type Output = Integer<E, I>;
 
     /// Returns the `sum` of `self` and `other`.
     #[inline]
     fn add(self, other: Integer<E, I>) -> Self::Output {
-        match self.integer.checked_add(&other.integer) {
-            Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer addition failed on: {self} and {other}")),
-        }
+        self.try_add(&other).or_halt::<E>()
     }
 }
 
@@ -78,10 +222,7 @@ impl<E: Environment, I: IntegerType> Add<&Integer<E, I>> for Integer<E, I> {
     /// Returns the `sum` of `self` and `other`.
     #[inline]
     fn add(self, other: &Integer<E, I>) -> Self::Output {
-        match self.integer.checked_add(&other.integer) {
-            Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer addition failed on: {self} and {other}")),
-        }
+        self.try_add(other).or_halt::<E>()
     }
 }
 
@@ -99,10 +240,7 @@ impl<E: Environment, I: IntegerType> AddAssign<Integer<E, I>> for Integer<E, I>
     /// Adds `other` to `self`.
     #[inline]
     fn add_assign(&mut self, other: Integer<E, I>) {
-        match self.integer.checked_add(&other.integer) {
-            Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer addition failed on: {self} and {other}")),
-        }
+        *self = self.try_add(&other).or_halt::<E>();
     }
 }
 
@@ -110,10 +248,7 @@ impl<E: Environment, I: IntegerType> AddAssign<&Integer<E, I>> for Integer<E, I>
     /// Adds `other` to `self`.
     #[inline]
     fn add_assign(&mut self, other: &Integer<E, I>) {
-        match self.integer.checked_add(&other.integer) {
-            Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer addition failed on: {self} and {other}")),
-        }
+        *self = self.try_add(other).or_halt::<E>();
     }
 }
 
@@ -123,10 +258,7 @@ impl<E: Environment, I: IntegerType> Sub<Integer<E, I>> for Integer<E, I> {
     /// Returns the `difference` of `self` and `other`.
     #[inline]
     fn sub(self, other: Integer<E, I>) -> Self::Output {
-        match self.integer.checked_sub(&other.integer) {
-            Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer subtraction failed on: {self} and {other}")),
-        }
+        self.try_sub(&other).or_halt::<E>()
     }
 }
 
@@ -136,10 +268,7 @@ impl<E: Environment, I: IntegerType> Sub<&Integer<E, I>> for Integer<E, I> {
     /// Returns the `difference` of `self` and `other`.
     #[inline]
     fn sub(self, other: &Integer<E, I>) -> Self::Output {
-        match self.integer.checked_sub(&other.integer) {
-            Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer subtraction failed on: {self} and {other}")),
-        }
+        self.try_sub(other).or_halt::<E>()
     }
 }
 
@@ -157,10 +286,7 @@ impl<E: Environment, I: IntegerType> SubAssign<Integer<E, I>> for Integer<E, I>
     /// Subtracts `other` from `self`.
     #[inline]
     fn sub_assign(&mut self, other: Integer<E, I>) {
-        match self.integer.checked_sub(&other.integer) {
-            Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer subtraction failed on: {self} and {other}")),
-        }
+        *self = self.try_sub(&other).or_halt::<E>();
     }
 }
 
@@ -168,10 +294,7 @@ impl<E: Environment, I: IntegerType> SubAssign<&Integer<E, I>> for Integer<E, I>
     /// Subtracts `other` from `self`.
     #[inline]
     fn sub_assign(&mut self, other: &Integer<E, I>) {
-        match self.integer.checked_sub(&other.integer) {
-            Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer subtraction failed on: {self} and {other}")),
-        }
+        *self = self.try_sub(other).or_halt::<E>();
     }
 }
 
@@ -181,10 +304,7 @@ impl<E: Environment, I: IntegerType> Mul<Integer<E, I>> for Integer<E, I> {
     /// Returns the `product` of `self` and `other`.
     #[inline]
     fn mul(self, other: Integer<E, I>) -> Self::Output {
-        match self.integer.checked_mul(&other.integer) {
-            Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer multiplication failed on: {self} and {other}")),
-        }
+        self.try_mul(&other).or_halt::<E>()
     }
 }
 
@@ -194,10 +314,7 @@ impl<E: Environment, I: IntegerType> Mul<&Integer<E, I>> for Integer<E, I> {
     /// Returns the `product` of `self` and `other`.
     #[inline]
     fn mul(self, other: &Integer<E, I>) -> Self::Output {
-        match self.integer.checked_mul(&other.integer) {
-            Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer multiplication failed on: {self} and {other}")),
-        }
+        self.try_mul(other).or_halt::<E>()
     }
 }
 
@@ -215,10 +332,7 @@ impl<E: Environment, I: IntegerType> MulAssign<Integer<E, I>> for Integer<E, I>
     /// Multiplies `self` by `other`.
     #[inline]
     fn mul_assign(&mut self, other: Integer<E, I>) {
-        match self.integer.checked_mul(&other.integer) {
-            Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer multiplication failed on: {self} and {other}")),
-        }
+        *self = self.try_mul(&other).or_halt::<E>();
     }
 }
 
@@ -226,10 +340,7 @@ impl<E: Environment, I: IntegerType> MulAssign<&Integer<E, I>> for Integer<E, I>
     /// Multiplies `self` by `other`.
     #[inline]
     fn mul_assign(&mut self, other: &Integer<E, I>) {
-        match self.integer.checked_mul(&other.integer) {
-            Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer multiplication failed on: {self} and {other}")),
-        }
+        *self = self.try_mul(other).or_halt::<E>();
     }
 }
 
@@ -239,10 +350,7 @@ impl<E: Environment, I: IntegerType> Div<Integer<E, I>> for Integer<E, I> {
     /// Returns the `quotient` of `self` and `other`.
     #[inline]
     fn div(self, other: Integer<E, I>) -> Self::Output {
-        match self.integer.checked_div(&other.integer) {
-            Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer division failed on: {self} and {other}")),
-        }
+        self.try_div(&other).or_halt::<E>()
     }
 }
 
@@ -252,10 +360,7 @@ impl<E: Environment, I: IntegerType> Div<&Integer<E, I>> for Integer<E, I> {
     /// Returns the `quotient` of `self` and `other`.
     #[inline]
     fn div(self, other: &Integer<E, I>) -> Self::Output {
-        match self.integer.checked_div(&other.integer) {
-            Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer division failed on: {self} and {other}")),
-        }
+        self.try_div(other).or_halt::<E>()
     }
 }
 
@@ -265,10 +370,7 @@ impl<E: Environment, I: IntegerType> DivWrapped<Integer<E, I>> for Integer<E, I>
     /// Returns the `quotient` of `self` and `other`.
     #[inline]
     fn div_wrapped(&self, other: &Integer<E, I>) -> Self::Output {
-        match other.is_zero() {
-            true => E::halt(format!("Integer division by zero: {self} / {other}")),
-            false => Integer::new(self.integer.wrapping_div(&other.integer)),
-        }
+        self.try_div_wrapped(other).or_halt::<E>()
     }
 }
 
@@ -276,10 +378,7 @@ impl<E: Environment, I: IntegerType> DivAssign<Integer<E, I>> for Integer<E, I>
     /// Divides `self` by `other`.
     #[inline]
     fn div_assign(&mut self, other: Integer<E, I>) {
-        match self.integer.checked_div(&other.integer) {
-            Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer division failed on: {self} and {other}")),
-        }
+        *self = self.try_div(&other).or_halt::<E>();
     }
 }
 
@@ -287,10 +386,7 @@ impl<E: Environment, I: IntegerType> DivAssign<&Integer<E, I>> for Integer<E, I>
     /// Divides `self` by `other`.
     #[inline]
     fn div_assign(&mut self, other: &Integer<E, I>) {
-        match self.integer.checked_div(&other.integer) {
-            Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer division failed on: {self} and {other}")),
-        }
+        *self = self.try_div(other).or_halt::<E>();
     }
 }
 
@@ -300,12 +396,36 @@ impl<E: Environment, I: IntegerType> Modulo<Integer<E, I>> for Integer<E, I> {
     /// Returns the result of taking the modulus of `self` with respect to `other`.
     #[inline]
     fn modulo(&self, other: &Integer<E, I>) -> Self {
+        self.try_modulo(other).or_halt::<E>()
+    }
+}
+
+impl<E: Environment, I: IntegerType> Gcd<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the greatest common divisor of `self` and `other`, using the binary GCD algorithm.
+    #[inline]
+    fn gcd(&self, other: &Integer<E, I>) -> Self::Output {
         match I::is_signed() {
-            true => E::halt("Taking the modulus of signed integers is not supported"),
-            false => match other.is_zero() {
-                true => E::halt(format!("Integer modulus by zero: {self} % {other}")),
-                false => Integer::new(self.integer.modulo(&other.integer)),
-            },
+            true => E::halt("Taking the greatest common divisor of signed integers is not supported"),
+            false => {
+                let (mut a, mut b) = (self.integer, other.integer);
+                // Note: `gcd(a, 0) == a` and `gcd(0, b) == b`.
+                if a == I::zero() || b == I::zero() {
+                    return Integer::new(a | b);
+                }
+                // Factor out the largest power of two dividing both operands.
+                let shift = (a | b).trailing_zeros() as usize;
+                a = a >> a.trailing_zeros() as usize;
+                while b != I::zero() {
+                    b = b >> b.trailing_zeros() as usize;
+                    if a > b {
+                        core::mem::swap(&mut a, &mut b);
+                    }
+                    b = b - a;
+                }
+                Integer::new(a << shift)
+            }
         }
     }
 }
@@ -316,10 +436,7 @@ impl<E: Environment, I: IntegerType> Rem<Integer<E, I>> for Integer<E, I> {
     /// Returns the `remainder` of `self` divided by `other`.
     #[inline]
     fn rem(self, other: Integer<E, I>) -> Self {
-        match self.integer.checked_rem(&other.integer) {
-            Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer remainder failed on: {self} and {other}")),
-        }
+        self.try_rem(&other).or_halt::<E>()
     }
 }
 
@@ -329,10 +446,7 @@ impl<E: Environment, I: IntegerType> Rem<&Integer<E, I>> for Integer<E, I> {
     /// Returns the `remainder` of `self` divided by `other`.
     #[inline]
     fn rem(self, other: &Integer<E, I>) -> Self {
-        match self.integer.checked_rem(&other.integer) {
-            Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer remainder failed on: {self} and {other}")),
-        }
+        self.try_rem(other).or_halt::<E>()
     }
 }
 
@@ -342,10 +456,7 @@ impl<E: Environment, I: IntegerType> RemWrapped<Integer<E, I>> for Integer<E, I>
     /// Returns the `remainder` of `self` divided by `other`.
     #[inline]
     fn rem_wrapped(&self, other: &Integer<E, I>) -> Self::Output {
-        match other.is_zero() {
-            true => E::halt(format!("Integer remainder by zero: {self} % {other}")),
-            false => Integer::new(self.integer.wrapping_rem(&other.integer)),
-        }
+        self.try_rem_wrapped(other).or_halt::<E>()
     }
 }
 
@@ -353,10 +464,7 @@ impl<E: Environment, I: IntegerType> RemAssign<Integer<E, I>> for Integer<E, I>
     /// Returns the `remainder` of `self` divided by `other`.
     #[inline]
     fn rem_assign(&mut self, other: Integer<E, I>) {
-        match self.integer.checked_rem(&other.integer) {
-            Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer remainder failed on: {self} and {other}")),
-        }
+        *self = self.try_rem(&other).or_halt::<E>();
     }
 }
 
@@ -364,10 +472,7 @@ impl<E: Environment, I: IntegerType> RemAssign<&Integer<E, I>> for Integer<E, I>
     /// Returns the `remainder` of `self` divided by `other`.
     #[inline]
     fn rem_assign(&mut self, other: &Integer<E, I>) {
-        match self.integer.checked_rem(&other.integer) {
-            Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer remainder failed on: {self} and {other}")),
-        }
+        *self = self.try_rem(other).or_halt::<E>();
     }
 }
 
@@ -377,11 +482,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Pow<Integer<E, M>> for Intege
     /// Returns the `power` of `self` to the power of `other`.
     #[inline]
     fn pow(self, other: Integer<E, M>) -> Self::Output {
-        match self.integer.checked_pow(&other.integer.to_u32().unwrap()) {
-            // Unwrap is safe as we only cast up.
-            Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer power failed on: {self} and {other}")),
-        }
+        self.try_pow(&other).or_halt::<E>()
     }
 }
 
@@ -391,11 +492,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Pow<&Integer<E, M>> for Integ
     /// Returns the `power` of `self` to the power of `other`.
     #[inline]
     fn pow(self, other: &Integer<E, M>) -> Self::Output {
-        match self.integer.checked_pow(&other.integer.to_u32().unwrap()) {
-            // Unwrap is safe as we only cast up.
-            Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer power failed on: {self} and {other}")),
-        }
+        self.try_pow(other).or_halt::<E>()
     }
 }
 
@@ -417,7 +514,60 @@ impl<E: Environment, I: IntegerType> Square for Integer<E, I> {
     fn square(&self) -> Self::Output {
         match self.integer.checked_mul(&self.integer) {
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer square failed on: {}", self.integer)),
+            None => E::halt(format!(
+                "Integer square failed on: {} ({})",
+                self.integer,
+                self.exceeds(true, format!("{self} ** 2"))
+            )),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_try_arithmetic_errors() {
+        let max = U8::<CurrentEnvironment>::new(u8::MAX);
+        let one = U8::<CurrentEnvironment>::one();
+        let zero = U8::<CurrentEnvironment>::zero();
+
+        // Ensure an overflow returns the halt message.
+        let error = max.try_add(&one).unwrap_err();
+        assert_eq!("Integer addition failed on: 255u8 and 1u8 (255u8 + 1u8 exceeds u8::MAX)", error.to_string());
+        assert!(zero.try_sub(&one).is_err());
+        assert!(max.try_mul(&max).is_err());
+        assert!(max.try_pow(&U8::<CurrentEnvironment>::new(2)).is_err());
+        assert!(one.try_shl_checked(&U8::<CurrentEnvironment>::new(8)).is_err());
+        let error = I8::<CurrentEnvironment>::new(i8::MIN).try_neg().unwrap_err();
+        assert_eq!("Integer negation failed on: -128 (-(-128i8) exceeds i8::MAX)", error.to_string());
+
+        // Ensure a division by zero returns the halt message.
+        let error = one.try_div(&zero).unwrap_err();
+        assert_eq!("Integer division failed on: 1u8 and 0u8 (division by zero)", error.to_string());
+        let error = one.try_rem(&zero).unwrap_err();
+        assert_eq!("Integer remainder failed on: 1u8 and 0u8 (remainder by zero)", error.to_string());
+        let (min, negative_one) = (I8::<CurrentEnvironment>::new(i8::MIN), -I8::<CurrentEnvironment>::one());
+        let error = min.try_div(&negative_one).unwrap_err();
+        assert_eq!("Integer division failed on: -128i8 and -1i8 (-128i8 / -1i8 exceeds i8::MAX)", error.to_string());
+        assert!(one.try_div_wrapped(&zero).is_err());
+        assert!(one.try_rem_wrapped(&zero).is_err());
+        assert!(one.try_modulo(&zero).is_err());
+
+        // Ensure an unsupported operand type returns an error.
+        assert!(one.try_neg().is_err());
+
+        // Ensure the operators halt with the same message.
+        let payload = std::panic::catch_unwind(|| max + one).unwrap_err();
+        assert_eq!(Some(&max.try_add(&one).unwrap_err().to_string()), payload.downcast_ref::<String>());
+        let payload = std::panic::catch_unwind(|| max.square()).unwrap_err();
+        assert_eq!(
+            Some(&"Integer square failed on: 255 (255u8 ** 2 exceeds u8::MAX)".to_string()),
+            payload.downcast_ref::<String>()
+        );
+    }
+}
@@ -14,6 +14,34 @@
 
 use super::*;
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the bit of `self` at the given index, in little-endian order, or an error if the index is out of range.
+    pub fn try_get_bit(&self, index: &U32<E>) -> Result<Boolean<E>> {
+        match self.to_bits_le().get(**index as usize) {
+            Some(bit) => Ok(Boolean::new(*bit)),
+            None => bail!("Bit index {index} is out of range for {}", Self::type_name()),
+        }
+    }
+
+    /// Shifts `self` to the left by `n` bits, or returns an error if `n` exceeds the bit width of the type.
+    pub fn try_shl_checked<M: Magnitude>(&self, n: &Integer<E, M>) -> Result<Self> {
+        // Unwrap is safe as we only cast up.
+        match self.integer.checked_shl(&n.integer.to_u32().unwrap()) {
+            Some(shifted) => Ok(Integer::new(shifted)),
+            None => bail!("Failed to shift {self} left by {n} bits"),
+        }
+    }
+
+    /// Shifts `self` to the right by `n` bits, or returns an error if `n` exceeds the bit width of the type.
+    pub fn try_shr_checked<M: Magnitude>(&self, n: &Integer<E, M>) -> Result<Self> {
+        // Unwrap is safe as we only cast up.
+        match self.integer.checked_shr(n.integer.to_u32().unwrap()) {
+            Some(shifted) => Ok(Integer::new(shifted)),
+            None => bail!("Failed to shift {self} right by {n} bits"),
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Equal for Integer<E, I> {
     type Output = Boolean<E>;
 
@@ -52,6 +80,97 @@ impl<E: Environment, I: IntegerType> Compare<Self> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> CountLeadingSignBits for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the number of leading bits equal to the sign bit, excluding the sign bit itself.
+    #[inline]
+    fn count_leading_sign_bits(&self) -> Self::Output {
+        match I::is_signed() {
+            true => {
+                // The leading sign bits of a negative integer are the leading zeros of its complement.
+                let integer = match self.integer < I::zero() {
+                    true => !self.integer,
+                    false => self.integer,
+                };
+                // Note: The sign bit of a non-negative integer is zero, so `leading_zeros` is at least one.
+                U32::new(integer.leading_zeros() - 1)
+            }
+            false => E::halt("Counting the leading sign bits of unsigned integers is not supported."),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> CountOnes for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the number of ones in the binary representation of `self`.
+    #[inline]
+    fn count_ones(&self) -> Self::Output {
+        U32::new(self.integer.count_ones())
+    }
+}
+
+impl<E: Environment, I: IntegerType> CountZeros for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the number of zeros in the binary representation of `self`.
+    #[inline]
+    fn count_zeros(&self) -> Self::Output {
+        U32::new(self.integer.count_zeros())
+    }
+}
+
+impl<E: Environment, I: IntegerType> GetBit<U32<E>> for Integer<E, I> {
+    type Output = Boolean<E>;
+
+    /// Returns the bit of `self` at the given index, in little-endian order.
+    #[inline]
+    fn get_bit(&self, index: &U32<E>) -> Self::Output {
+        self.try_get_bit(index).or_halt::<E>()
+    }
+}
+
+impl<E: Environment, I: IntegerType> IsZero for Integer<E, I> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is zero.
+    fn is_equal_to_zero(&self) -> Self::Output {
+        Boolean::new(self.is_zero())
+    }
+}
+
+impl<E: Environment, I: IntegerType> LeadingZeros for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the number of leading zeros in the binary representation of `self`.
+    /// If `self` is zero, this returns the number of bits in the integer.
+    #[inline]
+    fn leading_zeros(&self) -> Self::Output {
+        U32::new(self.integer.leading_zeros())
+    }
+}
+
+impl<E: Environment, I: IntegerType> Nand for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the bitwise `NAND` of `self` and `other`.
+    #[inline]
+    fn nand(&self, other: &Self) -> Self::Output {
+        Integer::new(!(self.integer & other.integer))
+    }
+}
+
+impl<E: Environment, I: IntegerType> Nor for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the bitwise `NOR` of `self` and `other`.
+    #[inline]
+    fn nor(&self, other: &Self) -> Self::Output {
+        Integer::new(!(self.integer | other.integer))
+    }
+}
+
 impl<E: Environment, I: IntegerType> Not for Integer<E, I> {
     type Output = Self;
 
@@ -152,11 +271,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Shl<Integer<E, M>> for Intege
     /// Shifts `self` to the left by `n` bits.
     #[inline]
     fn shl(self, n: Integer<E, M>) -> Self::Output {
-        // Unwrap is safe as we only cast up.
-        match self.integer.checked_shl(&n.integer.to_u32().unwrap()) {
-            Some(shifted) => Integer::new(shifted),
-            None => E::halt(format!("Failed to shift {self} left by {n} bits")),
-        }
+        self.try_shl_checked(&n).or_halt::<E>()
     }
 }
 
@@ -166,11 +281,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Shl<&Integer<E, M>> for Integ
     /// Shifts `self` to the left by `n` bits.
     #[inline]
     fn shl(self, n: &Integer<E, M>) -> Self::Output {
-        // Unwrap is safe as we only cast up.
-        match self.integer.checked_shl(&n.integer.to_u32().unwrap()) {
-            Some(shifted) => Integer::new(shifted),
-            None => E::halt(format!("Failed to shift {self} left by {n} bits")),
-        }
+        self.try_shl_checked(n).or_halt::<E>()
     }
 }
 
@@ -180,11 +291,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShlChecked<Integer<E, M>> for
     /// Shifts `self` to the left by `n` bits.
     #[inline]
     fn shl_checked(&self, n: &Integer<E, M>) -> Self::Output {
-        // Unwrap is safe as we only cast up.
-        match self.integer.checked_shl(&n.integer.to_u32().unwrap()) {
-            Some(shifted) => Integer::new(shifted),
-            None => E::halt(format!("Failed to shift {self} left by {n} bits")),
-        }
+        self.try_shl_checked(n).or_halt::<E>()
     }
 }
 
@@ -202,13 +309,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShlAssign<Integer<E, M>> for
     /// Shifts `self` to the left by `n` bits and assigns the result to `self`.
     #[inline]
     fn shl_assign(&mut self, n: Integer<E, M>) {
-        match self.integer.checked_shl(&n.integer.to_u32().unwrap()) {
-            // Unwrap is safe as we only cast up.
-            Some(shifted) => {
-                self.integer = shifted;
-            }
-            None => E::halt(format!("Failed to shift {self} left by {n} bits")),
-        }
+        *self = self.try_shl_checked(&n).or_halt::<E>();
     }
 }
 
@@ -218,11 +319,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Shr<Integer<E, M>> for Intege
     /// Shifts `self` to the right by `n` bits.
     #[inline]
     fn shr(self, n: Integer<E, M>) -> Self::Output {
-        match self.integer.checked_shr(n.integer.to_u32().unwrap()) {
-            // Unwrap is safe as we only cast up.
-            Some(shifted) => Integer::new(shifted),
-            None => E::halt(format!("Failed to shift {self} right by {n} bits")),
-        }
+        self.try_shr_checked(&n).or_halt::<E>()
     }
 }
 
@@ -232,11 +329,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Shr<&Integer<E, M>> for Integ
     /// Shifts `self` to the right by `n` bits.
     #[inline]
     fn shr(self, n: &Integer<E, M>) -> Self::Output {
-        match self.integer.checked_shr(n.integer.to_u32().unwrap()) {
-            // Unwrap is safe as we only cast up.
-            Some(shifted) => Integer::new(shifted),
-            None => E::halt(format!("Failed to shift {self} right by {n} bits")),
-        }
+        self.try_shr_checked(n).or_halt::<E>()
     }
 }
 
@@ -246,10 +339,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrChecked<Integer<E, M>> for
     /// Shifts `self` to the right by `n` bits.
     #[inline]
     fn shr_checked(&self, n: &Integer<E, M>) -> Self::Output {
-        match self.integer.checked_shr(n.integer.to_u32().unwrap()) {
-            Some(shifted) => Integer::new(shifted),
-            None => E::halt(format!("Failed to shift {self} right by {n} bits")),
-        }
+        self.try_shr_checked(n).or_halt::<E>()
     }
 }
 
@@ -267,12 +357,19 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrAssign<Integer<E, M>> for
     /// Shifts `self` to the right by `n` bits and assigns the result to `self`.
     #[inline]
     fn shr_assign(&mut self, n: Integer<E, M>) {
-        match self.integer.checked_shr(n.integer.to_u32().unwrap()) {
-            // Unwrap is safe as we only cast up.
-            Some(shifted) => {
-                self.integer = shifted;
-            }
-            None => E::halt(format!("Failed to shift {self} right by {n} bits")),
+        *self = self.try_shr_checked(&n).or_halt::<E>();
+    }
+}
+
+impl<E: Environment, I: IntegerType> SwapBytes for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `self` with the order of its bytes reversed.
+    #[inline]
+    fn swap_bytes(&self) -> Self::Output {
+        match I::BITS > 8 {
+            true => Integer::new(self.integer.swap_bytes()),
+            false => E::halt(format!("Swapping the bytes of {} is not supported", Self::type_name())),
         }
     }
 }
@@ -289,3 +386,14 @@ impl<E: Environment, I: IntegerType> Ternary for Integer<E, I> {
         }
     }
 }
+
+impl<E: Environment, I: IntegerType> TrailingZeros for Integer<E, I> {
+    type Output = U32<E>;
+
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    /// If `self` is zero, this returns the number of bits in the integer.
+    #[inline]
+    fn trailing_zeros(&self) -> Self::Output {
+        U32::new(self.integer.trailing_zeros())
+    }
+}
@@ -30,6 +30,30 @@ impl<E: Environment, I: IntegerType> ToBytes for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the big-endian bytes of the integer, i.e. the reverse of `to_bytes_le`.
+    pub fn to_bytes_be(&self) -> Result<Vec<u8>> {
+        let mut bytes = self.to_bytes_le()?;
+        bytes.reverse();
+        Ok(bytes)
+    }
+
+    /// Returns the integer from its big-endian bytes, i.e. the reverse of `from_bytes_le`.
+    pub fn from_bytes_be(bytes: &[u8]) -> Result<Self> {
+        // Ensure the number of bytes is exact, as the most significant byte comes first.
+        ensure!(
+            bytes.len() == Self::size_in_bytes(),
+            "Expected {} bytes for a '{}', found {}",
+            Self::size_in_bytes(),
+            I::type_name(),
+            bytes.len()
+        );
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+        Self::from_bytes_le(&bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,6 +78,13 @@ mod tests {
             for (expected, candidate) in expected_bytes.iter().zip_eq(&deref_bytes) {
                 assert_eq!(expected, candidate);
             }
+
+            // Check the big-endian representation is the reverse of the little-endian representation.
+            let expected_bytes_be = expected.to_bytes_be()?;
+            assert_eq!(expected_bytes_be, expected_bytes.iter().rev().copied().collect::<Vec<_>>());
+            assert_eq!(expected, Integer::from_bytes_be(&expected_bytes_be)?);
+            assert!(Integer::<CurrentEnvironment, I>::from_bytes_be(&expected_bytes_be[1..]).is_err());
+            assert!(Integer::<CurrentEnvironment, I>::from_bytes_be(&[expected_bytes_be, vec![0]].concat()).is_err());
         }
         Ok(())
     }
@@ -76,4 +107,15 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_be() -> Result<()> {
+        // Ensure the most significant byte comes first.
+        assert_eq!(Integer::<CurrentEnvironment, u16>::new(0x0102).to_bytes_be()?, [1u8, 2]);
+        assert_eq!(Integer::<CurrentEnvironment, u32>::new(0x01020304).to_bytes_be()?, [1u8, 2, 3, 4]);
+        assert_eq!(Integer::<CurrentEnvironment, i16>::new(-2).to_bytes_be()?, [0xffu8, 0xfe]);
+        assert_eq!(Integer::<CurrentEnvironment, u64>::from_bytes_be(&[0, 0, 0, 0, 0, 0, 1, 0])?, Integer::new(256));
+        assert_eq!(Integer::<CurrentEnvironment, i8>::from_bytes_be(&[0x80])?, Integer::new(i8::MIN));
+        Ok(())
+    }
 }
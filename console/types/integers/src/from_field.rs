@@ -18,6 +18,7 @@ impl<E: Environment, I: IntegerType> FromField for Integer<E, I> {
     type Field = Field<E>;
 
     /// Initialize an integer from a field element.
+    /// This method is checked, and returns an error if the field element exceeds the integer range.
     fn from_field(field: &Self::Field) -> Result<Self> {
         // Note: We are reconstituting the integer from the base field.
         // This is safe as the number of bits in the integer is less than the base field modulus,
@@ -57,6 +58,10 @@ mod tests {
             // Perform the operation.
             let candidate = Integer::from_field(&expected.to_field()?)?;
             assert_eq!(expected, candidate);
+
+            // Ensure a field element beyond the integer range fails, instead of being truncated.
+            let shift = (0..I::BITS).fold(Field::<CurrentEnvironment>::one(), |shift, _| shift.double());
+            assert!(Integer::<CurrentEnvironment, I>::from_field(&(expected.to_field()? + shift)).is_err());
         }
         Ok(())
     }
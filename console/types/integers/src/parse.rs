@@ -20,17 +20,33 @@ impl<E: Environment, I: IntegerType> Parser for Integer<E, I> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the negative sign '-' from the string.
         let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.unwrap_or_default().to_string())(string)?;
-        // Parse the digits from the string.
-        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
+        // Parse the digits from the string, in hexadecimal ('0x'), binary ('0b'), or decimal form.
+        let (string, primitive) = alt((
+            map(pair(tag("0x"), |string| parse_digits(string, 16)), |(_, digits)| digits),
+            map(pair(tag("0b"), |string| parse_digits(string, 2)), |(_, digits)| digits),
+            |string| parse_digits(string, 10),
+        ))(string)?;
         // Combine the sign and primitive.
-        let primitive = negation + primitive;
+        let primitive = negation + &primitive;
         // Parse the value from the string.
-        let (string, value) = map_res(tag(Self::type_name()), |_| primitive.replace('_', "").parse())(string)?;
+        let (string, value) = map_res(tag(Self::type_name()), |_| primitive.parse())(string)?;
 
         Ok((string, Integer::new(value)))
     }
 }
 
+/// Parses the digits of the given radix, with '_' separators allowed, into a decimal string.
+fn parse_digits(string: &str, radix: u32) -> ParserResult<String> {
+    let digits = match radix {
+        2 => "01",
+        16 => "0123456789abcdefABCDEF",
+        _ => "0123456789",
+    };
+    map_res(recognize(many1(terminated(one_of(digits), many0(char('_'))))), |digits: &str| {
+        u128::from_str_radix(&digits.replace('_', ""), radix).map(|value| value.to_string())
+    })(string)
+}
+
 impl<E: Environment, I: IntegerType> FromStr for Integer<E, I> {
     type Err = Error;
 
@@ -90,6 +106,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_hex_and_binary() -> Result<()> {
+        /// Checks the hexadecimal and binary forms parse to the decimal value, and display in decimal.
+        fn check<I: IntegerType>(hex: &str, binary: &str, decimal: &str) -> Result<()> {
+            let expected = Integer::<CurrentEnvironment, I>::from_str(decimal)?;
+            assert_eq!(expected, Integer::from_str(hex)?);
+            assert_eq!(expected, Integer::from_str(binary)?);
+            assert_eq!(decimal, Integer::<CurrentEnvironment, I>::from_str(hex)?.to_string());
+            Ok(())
+        }
+
+        check::<u8>("0xff_u8", "0b1111_1111_u8", "255u8")?;
+        check::<u16>("0xBEEFu16", "0b1011_1110_1110_1111u16", "48879u16")?;
+        check::<u32>("0x0001_0000u32", "0b1_0000_0000_0000_0000u32", "65536u32")?;
+        check::<u64>("0xffff_ffff_ffff_ffffu64", &format!("0b{}u64", "1".repeat(64)), "18446744073709551615u64")?;
+        check::<u128>("0x1u128", "0b1u128", "1u128")?;
+        check::<i8>("0x7f_i8", "0b111_1111_i8", "127i8")?;
+        check::<i8>("-0x80_i8", "-0b1000_0000_i8", "-128i8")?;
+        check::<i16>("-0x1i16", "-0b1i16", "-1i16")?;
+        check::<i32>("0x7fff_ffffi32", &format!("0b{}i32", "1".repeat(31)), "2147483647i32")?;
+        check::<i64>("0x10i64", "0b10000i64", "16i64")?;
+        check::<i128>("0x0i128", "0b0i128", "0i128")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_hex_and_binary_out_of_range() {
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0x100u8").is_err());
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0b1_0000_0000u8").is_err());
+        assert!(Integer::<CurrentEnvironment, i8>::from_str("0x80i8").is_err());
+        assert!(Integer::<CurrentEnvironment, i8>::from_str("-0x81i8").is_err());
+        assert!(Integer::<CurrentEnvironment, u128>::from_str(&format!("0x1{}u128", "0".repeat(32))).is_err());
+        // Ensure invalid digits and missing digits are rejected.
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0b102u8").is_err());
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0xu8").is_err());
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0x_u8").is_err());
+    }
+
     #[test]
     fn test_display() {
         /// Attempts to construct a integer from the given element,
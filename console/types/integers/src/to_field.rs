@@ -18,6 +18,11 @@ impl<E: Environment, I: IntegerType> ToField for Integer<E, I> {
     type Field = Field<E>;
 
     /// Converts an integer into a field element.
+    ///
+    /// The field element is formed from the integer bits, zero-extended to the size of the base field.
+    /// For signed integers, the bits are in two's complement form, so the upper bits of the field element
+    /// are always zero, and a negative integer `x` maps to `2^BITS + x` (e.g. `-1i8` maps to `255field`).
+    /// This is the encoding expected by `from_field`, so `from_field(to_field(x)) == x` for every integer.
     fn to_field(&self) -> Result<Self::Field> {
         // Note: We are reconstituting the integer as a base field.
         // This is safe as the number of bits in the integer is less than the base field modulus,
@@ -67,63 +72,99 @@ mod tests {
         Ok(())
     }
 
+    fn check_to_field_round_trip<I: IntegerType>() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Check the boundary values, followed by random integers.
+        let boundaries = [I::zero(), I::one(), I::zero().wrapping_sub(&I::one()), I::MIN, I::MAX];
+        let values = boundaries.into_iter().chain((0..ITERATIONS).map(|_| Uniform::rand(&mut rng)));
+
+        for expected in values {
+            let expected = Integer::<CurrentEnvironment, I>::new(expected);
+            assert_eq!(expected, Integer::from_field(&expected.to_field()?)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_field_signed_encoding() -> Result<()> {
+        // Ensure negative integers map to their two's complement bits, zero-extended.
+        assert_eq!(Field::from_u8(255), I8::<CurrentEnvironment>::new(-1).to_field()?);
+        assert_eq!(Field::from_u8(128), I8::<CurrentEnvironment>::new(i8::MIN).to_field()?);
+        assert_eq!(Field::from_u8(127), I8::<CurrentEnvironment>::new(i8::MAX).to_field()?);
+        assert_eq!(Field::from_u16(u16::MAX), I16::<CurrentEnvironment>::new(-1).to_field()?);
+        assert_eq!(Field::from_u128(u128::MAX), I128::<CurrentEnvironment>::new(-1).to_field()?);
+        assert_eq!(Field::from_u128(1 << 127), I128::<CurrentEnvironment>::new(i128::MIN).to_field()?);
+        Ok(())
+    }
+
     #[test]
     fn test_u8_to_field() -> Result<()> {
         type I = u8;
-        check_to_field::<I>()
+        check_to_field::<I>()?;
+        check_to_field_round_trip::<I>()
     }
 
     #[test]
     fn test_i8_to_field() -> Result<()> {
         type I = i8;
-        check_to_field::<I>()
+        check_to_field::<I>()?;
+        check_to_field_round_trip::<I>()
     }
 
     #[test]
     fn test_u16_to_field() -> Result<()> {
         type I = u16;
-        check_to_field::<I>()
+        check_to_field::<I>()?;
+        check_to_field_round_trip::<I>()
     }
 
     #[test]
     fn test_i16_to_field() -> Result<()> {
         type I = i16;
-        check_to_field::<I>()
+        check_to_field::<I>()?;
+        check_to_field_round_trip::<I>()
     }
 
     #[test]
     fn test_u32_to_field() -> Result<()> {
         type I = u32;
-        check_to_field::<I>()
+        check_to_field::<I>()?;
+        check_to_field_round_trip::<I>()
     }
 
     #[test]
     fn test_i32_to_field() -> Result<()> {
         type I = i32;
-        check_to_field::<I>()
+        check_to_field::<I>()?;
+        check_to_field_round_trip::<I>()
     }
 
     #[test]
     fn test_u64_to_field() -> Result<()> {
         type I = u64;
-        check_to_field::<I>()
+        check_to_field::<I>()?;
+        check_to_field_round_trip::<I>()
     }
 
     #[test]
     fn test_i64_to_field() -> Result<()> {
         type I = i64;
-        check_to_field::<I>()
+        check_to_field::<I>()?;
+        check_to_field_round_trip::<I>()
     }
 
     #[test]
     fn test_u128_to_field() -> Result<()> {
         type I = u128;
-        check_to_field::<I>()
+        check_to_field::<I>()?;
+        check_to_field_round_trip::<I>()
     }
 
     #[test]
     fn test_i128_to_field() -> Result<()> {
         type I = i128;
-        check_to_field::<I>()
+        check_to_field::<I>()?;
+        check_to_field_round_trip::<I>()
     }
 }
@@ -52,6 +52,15 @@ impl<E: Environment> Compare for Scalar<E> {
     }
 }
 
+impl<E: Environment> IsZero for Scalar<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is zero.
+    fn is_equal_to_zero(&self) -> Self::Output {
+        Boolean::new(self.is_zero())
+    }
+}
+
 impl<E: Environment> Ternary for Scalar<E> {
     type Boolean = Boolean<E>;
     type Output = Self;
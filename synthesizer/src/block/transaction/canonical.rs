@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The domain separator that prefixes the canonical encoding of a transaction.
+const CANONICAL_DOMAIN: &[u8] = b"snarkvm.transaction.v0";
+
+/// The tag of the program owner component.
+const OWNER_TAG: u8 = 0;
+/// The tag of the deployment component.
+const DEPLOYMENT_TAG: u8 = 1;
+/// The tag of the execution component.
+const EXECUTION_TAG: u8 = 2;
+/// The tag of the fee component.
+const FEE_TAG: u8 = 3;
+
+impl<N: Network> Transaction<N> {
+    /// Returns the domain-separated canonical encoding of the transaction, for hashing and signing.
+    ///
+    /// The layout is stable, and is given by:
+    /// ```text
+    /// "snarkvm.transaction.v0" || variant (u8) || component_1 || ... || component_n
+    /// component := tag (u8) || length (u64, little-endian) || component bytes
+    /// ```
+    /// where the components are, in order, the owner, deployment, and fee of a deployment,
+    /// the execution and optional fee of an execution, or the fee of a fee transaction.
+    /// The transaction ID is omitted, as it is derived from the components.
+    pub fn canonical_bytes(&self) -> Result<Vec<u8>> {
+        // Write the domain separator.
+        let mut bytes = CANONICAL_DOMAIN.to_vec();
+        // Write the variant and its components.
+        match self {
+            Self::Deploy(_, owner, deployment, fee) => {
+                bytes.push(0);
+                write_component(&mut bytes, OWNER_TAG, owner)?;
+                write_component(&mut bytes, DEPLOYMENT_TAG, &**deployment)?;
+                write_component(&mut bytes, FEE_TAG, fee)?;
+            }
+            Self::Execute(_, execution, fee) => {
+                bytes.push(1);
+                write_component(&mut bytes, EXECUTION_TAG, execution)?;
+                if let Some(fee) = fee {
+                    write_component(&mut bytes, FEE_TAG, fee)?;
+                }
+            }
+            Self::Fee(_, fee) => {
+                bytes.push(2);
+                write_component(&mut bytes, FEE_TAG, fee)?;
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// Initializes a transaction from its canonical encoding.
+    pub fn from_canonical_bytes(bytes: &[u8]) -> Result<Self> {
+        // Ensure the domain separator is present.
+        ensure!(bytes.starts_with(CANONICAL_DOMAIN), "Invalid domain separator in the canonical transaction");
+        let mut reader = &bytes[CANONICAL_DOMAIN.len()..];
+
+        // Read the variant and its components.
+        let transaction = match u8::read_le(&mut reader)? {
+            0 => {
+                let owner = read_component(&mut reader, OWNER_TAG)?;
+                let deployment = read_component(&mut reader, DEPLOYMENT_TAG)?;
+                let fee = read_component(&mut reader, FEE_TAG)?;
+                Self::from_deployment(owner, deployment, fee)?
+            }
+            1 => {
+                let execution = read_component(&mut reader, EXECUTION_TAG)?;
+                let fee = match reader.is_empty() {
+                    true => None,
+                    false => Some(read_component(&mut reader, FEE_TAG)?),
+                };
+                Self::from_execution(execution, fee)?
+            }
+            2 => Self::from_fee(read_component(&mut reader, FEE_TAG)?)?,
+            variant => bail!("Invalid transaction variant '{variant}' in the canonical transaction"),
+        };
+
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found trailing bytes in the canonical transaction");
+        Ok(transaction)
+    }
+}
+
+/// Writes the given component to the buffer, prefixed by its tag and length.
+fn write_component(bytes: &mut Vec<u8>, tag: u8, component: &impl ToBytes) -> Result<()> {
+    let component = component.to_bytes_le()?;
+    bytes.push(tag);
+    bytes.extend_from_slice(&u64::try_from(component.len())?.to_le_bytes());
+    bytes.extend_from_slice(&component);
+    Ok(())
+}
+
+/// Reads the component with the given tag from the reader, ensuring it spans exactly its length.
+fn read_component<T: FromBytes>(reader: &mut &[u8], tag: u8) -> Result<T> {
+    // Ensure the tag matches.
+    let candidate = u8::read_le(&mut *reader)?;
+    ensure!(candidate == tag, "Expected component tag {tag} in the canonical transaction, found {candidate}");
+    // Read the length, and ensure it is in bounds.
+    let length = usize::try_from(u64::read_le(&mut *reader)?)?;
+    ensure!(length <= reader.len(), "Component length exceeds the canonical transaction");
+    // Read the component, and ensure it consumes its length exactly.
+    let (mut component, remainder) = reader.split_at(length);
+    let value = T::read_le(&mut component)?;
+    ensure!(component.is_empty(), "Found trailing bytes in a component of the canonical transaction");
+    *reader = remainder;
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_canonical_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let transactions = [
+            crate::vm::test_helpers::sample_deployment_transaction(rng),
+            crate::vm::test_helpers::sample_execution_transaction_without_fee(rng),
+            crate::vm::test_helpers::sample_execution_transaction_with_fee(rng),
+            crate::vm::test_helpers::sample_fee_transaction(rng),
+        ];
+
+        for (i, expected) in transactions.iter().enumerate() {
+            // Ensure the canonical encoding round-trips.
+            let bytes = expected.canonical_bytes()?;
+            assert!(bytes.starts_with(CANONICAL_DOMAIN));
+            assert_eq!(*expected, Transaction::from_canonical_bytes(&bytes)?);
+
+            // Ensure distinct transactions have distinct canonical encodings.
+            for other in &transactions[i + 1..] {
+                assert_ne!(bytes, other.canonical_bytes()?);
+            }
+
+            // Ensure the domain separator, truncation, and trailing bytes are rejected.
+            assert!(Transaction::<CurrentNetwork>::from_canonical_bytes(&bytes[1..]).is_err());
+            assert!(Transaction::<CurrentNetwork>::from_canonical_bytes(&bytes[..bytes.len() - 1]).is_err());
+            assert!(Transaction::<CurrentNetwork>::from_canonical_bytes(&[&bytes[..], &[0u8]].concat()).is_err());
+            // Ensure the canonical encoding differs from the `ToBytes` encoding.
+            assert_ne!(bytes, expected.to_bytes_le()?);
+        }
+        Ok(())
+    }
+}
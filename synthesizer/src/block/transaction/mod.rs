@@ -22,6 +22,7 @@ mod fee;
 pub use fee::*;
 
 mod bytes;
+mod canonical;
 mod merkle;
 mod serialize;
 mod string;
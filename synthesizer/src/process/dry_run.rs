@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Process<N> {
+    /// Type-checks the given function against the program with the given ID, without evaluating it.
+    /// Returns the type of each input and destination register of the function.
+    #[inline]
+    pub fn dry_run(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function: &Function<N>,
+    ) -> Result<IndexMap<Register<N>, RegisterType<N>>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Propagate the operand types of each instruction to its destination registers.
+        let register_types = RegisterTypes::from_function(stack, function)
+            .map_err(|error| anyhow!("Function '{}' failed to type-check: {error}", function.name()))?;
+        // Return the register types.
+        Ok(register_types.registers())
+    }
+}
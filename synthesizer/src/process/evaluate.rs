@@ -27,8 +27,9 @@ impl<N: Network> Process<N> {
         println!("{}", format!(" • Evaluating '{}/{}'...", request.program_id(), request.function_name()).dimmed());
 
         // Evaluate the function.
-        let response =
-            self.get_stack(request.program_id())?.evaluate_function::<A>(CallStack::evaluate(authorization)?);
+        let call_stack = CallStack::evaluate_with_limit(authorization, self.instruction_limit)?
+            .with_evaluation_mode(self.evaluation_mode);
+        let response = self.get_stack(request.program_id())?.evaluate_function::<A>(call_stack);
         lap!(timer, "Evaluate the function");
 
         finish!(timer);
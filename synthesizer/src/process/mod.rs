@@ -17,6 +17,7 @@ pub use stack::*;
 
 mod authorize;
 mod deploy;
+mod dry_run;
 mod evaluate;
 mod execute;
 mod execute_fee;
@@ -28,13 +29,13 @@ mod tests;
 use crate::{
     atomic_batch_scope,
     block::{Deployment, Execution, Fee, FinalizeOperation, Input, Transition},
-    program::{Instruction, Program},
+    program::{Function, Instruction, Program},
     store::{FinalizeStorage, FinalizeStore},
 };
 use console::{
     account::PrivateKey,
     network::prelude::*,
-    program::{Identifier, Plaintext, ProgramID, Record, Request, Response, Value},
+    program::{Identifier, Plaintext, ProgramID, Record, Register, RegisterType, Request, Response, Value},
     types::{U16, U64},
 };
 use snarkvm_synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
@@ -56,6 +57,10 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Stack<N>>,
+    /// The maximum number of instructions to evaluate in a call, including nested calls, if any.
+    instruction_limit: Option<usize>,
+    /// The strategy for evaluating the instructions of a function.
+    evaluation_mode: EvaluationMode,
 }
 
 impl<N: Network> Process<N> {
@@ -65,7 +70,12 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            instruction_limit: None,
+            evaluation_mode: EvaluationMode::Serial,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -107,6 +117,33 @@ impl<N: Network> Process<N> {
         // Add the stack to the process.
         self.stacks.insert(*stack.program_id(), stack);
     }
+
+    /// Sets the maximum number of instructions to evaluate in a call, including nested calls.
+    #[inline]
+    pub fn with_instruction_limit(mut self, limit: usize) -> Self {
+        self.instruction_limit = Some(limit);
+        self
+    }
+
+    /// Returns the maximum number of instructions to evaluate in a call, if any.
+    #[inline]
+    pub const fn instruction_limit(&self) -> Option<usize> {
+        self.instruction_limit
+    }
+
+    /// Sets the strategy for evaluating the instructions of a function.
+    /// Note: Parallel evaluation only pays off for functions with many independent instructions.
+    #[inline]
+    pub fn with_evaluation_mode(mut self, mode: EvaluationMode) -> Self {
+        self.evaluation_mode = mode;
+        self
+    }
+
+    /// Returns the strategy for evaluating the instructions of a function.
+    #[inline]
+    pub const fn evaluation_mode(&self) -> EvaluationMode {
+        self.evaluation_mode
+    }
 }
 
 impl<N: Network> Process<N> {
@@ -116,7 +153,12 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            instruction_limit: None,
+            evaluation_mode: EvaluationMode::Serial,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -162,7 +204,12 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            instruction_limit: None,
+            evaluation_mode: EvaluationMode::Serial,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -183,7 +230,12 @@ impl<N: Network> Process<N> {
     #[inline]
     pub fn load_with_cache(cache: &mut HashMap<String, (ProvingKey<N>, VerifyingKey<N>)>) -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            instruction_limit: None,
+            evaluation_mode: EvaluationMode::Serial,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -243,6 +295,27 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id).map(Stack::program)
     }
 
+    /// Returns the estimated cost of the given function, including the cost of its callees.
+    #[inline]
+    pub fn get_function_cost(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+    ) -> Result<u64> {
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Ensure the function exists.
+        ensure!(
+            stack.program().contains_function(&function_name),
+            "Function '{function_name}' does not exist in program '{}'",
+            stack.program_id()
+        );
+        // Return the function cost.
+        stack.get_cost(&function_name)
+    }
+
     /// Returns the proving key for the given program ID and function name.
     #[inline]
     pub fn get_proving_key(
@@ -407,6 +480,44 @@ function compute:
             .clone()
     }
 
+    pub(crate) fn sample_authorization() -> Authorization<CurrentNetwork> {
+        static INSTANCE: OnceCell<Authorization<CurrentNetwork>> = OnceCell::new();
+        INSTANCE
+            .get_or_init(|| {
+                // Initialize a new program.
+                let program = Program::<CurrentNetwork>::from_str(
+                    r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+                )
+                .unwrap();
+
+                // Initialize the RNG.
+                let rng = &mut TestRng::default();
+                // Initialize a new caller account.
+                let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+                // Construct the process.
+                let process = sample_process(&program);
+                // Authorize the function call.
+                process
+                    .authorize::<CurrentAleo, _>(
+                        &caller_private_key,
+                        program.id(),
+                        Identifier::from_str("compute").unwrap(),
+                        ["5u32", "10u32"].into_iter(),
+                        rng,
+                    )
+                    .unwrap()
+            })
+            .replicate()
+    }
+
     pub fn sample_transition() -> Transition<CurrentNetwork> {
         // Retrieve the execution.
         let mut execution = sample_execution();
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Authorization<N> {
+    /// Reads the authorization from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid authorization version"));
+        }
+        // Read the number of requests.
+        let num_requests = u8::read_le(&mut reader)?;
+        // Read the requests.
+        let requests = (0..num_requests).map(|_| Request::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the new `Authorization` instance.
+        Ok(Self::new(&requests))
+    }
+}
+
+impl<N: Network> ToBytes for Authorization<N> {
+    /// Writes the authorization to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Retrieve the requests.
+        let requests = self.to_vec_deque();
+        // Write the version.
+        0u8.write_le(&mut writer)?;
+        // Write the number of requests.
+        (u8::try_from(requests.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+        // Write the requests.
+        for request in requests.iter() {
+            request.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        // Construct a new authorization.
+        let expected = crate::process::test_helpers::sample_authorization();
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Authorization::read_le(&expected_bytes[..])?);
+        assert!(Authorization::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Check an empty authorization.
+        let expected = Authorization::<CurrentNetwork>::new(&[]);
+        assert_eq!(expected, Authorization::read_le(&expected.to_bytes_le()?[..])?);
+        Ok(())
+    }
+}
@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bytes;
+mod serialize;
+mod string;
+
 use console::{network::prelude::*, program::Request};
 
 use parking_lot::RwLock;
@@ -69,3 +73,12 @@ impl<N: Network> Authorization<N> {
         self.requests.read().clone()
     }
 }
+
+impl<N: Network> PartialEq for Authorization<N> {
+    /// Returns `true` if the authorizations contain the same requests, in the same order.
+    fn eq(&self, other: &Self) -> bool {
+        *self.requests.read() == *other.requests.read()
+    }
+}
+
+impl<N: Network> Eq for Authorization<N> {}
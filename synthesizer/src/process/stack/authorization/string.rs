@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromStr for Authorization<N> {
+    type Err = Error;
+
+    /// Initializes the authorization from a JSON-string.
+    fn from_str(authorization: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(authorization)?)
+    }
+}
+
+impl<N: Network> Debug for Authorization<N> {
+    /// Prints the authorization as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Authorization<N> {
+    /// Displays the authorization as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        // Sample the authorization.
+        let expected = crate::process::test_helpers::sample_authorization();
+
+        // Check the string representation.
+        let candidate = expected.to_string();
+        assert_eq!(expected, Authorization::from_str(&candidate)?);
+
+        // Ensure trailing characters are rejected.
+        assert!(Authorization::<CurrentNetwork>::from_str(&format!("{candidate}}}")).is_err());
+        assert!(Authorization::<CurrentNetwork>::from_str(&format!("{candidate} 00")).is_err());
+        // Ensure invalid strings are rejected.
+        assert!(Authorization::<CurrentNetwork>::from_str("").is_err());
+        assert!(Authorization::<CurrentNetwork>::from_str("0xdeadbeef").is_err());
+        assert!(Authorization::<CurrentNetwork>::from_str(r#"{"requests": ["invalid"]}"#).is_err());
+        Ok(())
+    }
+}
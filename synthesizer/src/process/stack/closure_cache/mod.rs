@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Locator, Value},
+};
+
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// A cache of the outputs of pure closures, keyed by the closure locator and its inputs.
+#[derive(Clone)]
+pub struct ClosureCache<N: Network> {
+    /// The cached outputs, keyed by the closure locator and the bytes of its inputs.
+    outputs: Arc<RwLock<IndexMap<(Locator<N>, Vec<u8>), Vec<Value<N>>>>>,
+    /// The number of lookups that were found in the cache.
+    num_hits: Arc<RwLock<usize>>,
+}
+
+impl<N: Network> ClosureCache<N> {
+    /// Initializes a new, empty closure cache.
+    pub fn new() -> Self {
+        Self { outputs: Default::default(), num_hits: Default::default() }
+    }
+
+    /// Returns a new and independent replica of the closure cache.
+    pub fn replicate(&self) -> Self {
+        Self {
+            outputs: Arc::new(RwLock::new(self.outputs.read().clone())),
+            num_hits: Arc::new(RwLock::new(*self.num_hits.read())),
+        }
+    }
+
+    /// Returns the cached outputs of the closure on the given inputs, if they exist.
+    pub fn get(&self, locator: &Locator<N>, inputs: &[Value<N>]) -> Result<Option<Vec<Value<N>>>> {
+        let outputs = self.outputs.read().get(&(*locator, Self::to_key(inputs)?)).cloned();
+        if outputs.is_some() {
+            *self.num_hits.write() += 1;
+        }
+        Ok(outputs)
+    }
+
+    /// Caches the outputs of the closure on the given inputs.
+    pub fn insert(&self, locator: &Locator<N>, inputs: &[Value<N>], outputs: Vec<Value<N>>) -> Result<()> {
+        self.outputs.write().insert((*locator, Self::to_key(inputs)?), outputs);
+        Ok(())
+    }
+
+    /// Returns the number of cached closure evaluations.
+    pub fn len(&self) -> usize {
+        self.outputs.read().len()
+    }
+
+    /// Returns `true` if the closure cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.outputs.read().is_empty()
+    }
+
+    /// Returns the number of lookups that were found in the cache.
+    pub fn num_hits(&self) -> usize {
+        *self.num_hits.read()
+    }
+
+    /// Returns the cache key for the given inputs.
+    fn to_key(inputs: &[Value<N>]) -> Result<Vec<u8>> {
+        inputs.to_bytes_le()
+    }
+}
+
+impl<N: Network> Default for ClosureCache<N> {
+    /// Initializes a new, empty closure cache.
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -84,15 +84,7 @@ impl<N: Network> Stack<N> {
             // Sample the inputs.
             let inputs = input_types
                 .iter()
-                .map(|input_type| match input_type {
-                    ValueType::ExternalRecord(locator) => {
-                        // Retrieve the external stack.
-                        let stack = self.get_external_stack(locator.program_id())?;
-                        // Sample the input.
-                        stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
-                    }
-                    _ => self.sample_value(&burner_address, input_type, rng),
-                })
+                .map(|input_type| self.sample_value(&burner_address, input_type, rng))
                 .collect::<Result<Vec<_>>>()?;
             lap!(timer, "Sample the inputs");
 
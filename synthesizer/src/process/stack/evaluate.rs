@@ -14,6 +14,9 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 impl<N: Network> StackEvaluate<N> for Stack<N> {
     /// Evaluates a program closure on the given inputs.
     ///
@@ -35,6 +38,28 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
         }
 
+        // Initialize the closure locator.
+        let locator = Locator::new(*self.program.id(), *closure.name());
+        // If the closure is pure, retrieve the closure cache.
+        let closure_cache = match closure.is_pure() {
+            true => call_stack.closure_cache().cloned(),
+            false => None,
+        };
+        // Retrieve the instruction counter.
+        let instruction_counter = call_stack.instruction_counter().cloned();
+
+        // If the outputs were already computed on these inputs, count the instructions and return them.
+        if let Some(cache) = &closure_cache {
+            if let Some(outputs) = cache.get(&locator, inputs)? {
+                // Count the instructions, halting if the instruction limit is exceeded.
+                if let Some(instruction_counter) = &instruction_counter {
+                    closure.instructions().iter().try_for_each(|_| instruction_counter.increment())?;
+                }
+                finish!(timer, "Retrieve the cached outputs");
+                return Ok(outputs);
+            }
+        }
+
         // Initialize the registers.
         let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(closure.name())?.clone());
         // Set the transition caller.
@@ -52,9 +77,13 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
         // Evaluate the instructions.
         for instruction in closure.instructions() {
+            // Count the instruction, halting if the instruction limit is exceeded.
+            if let Some(instruction_counter) = &instruction_counter {
+                instruction_counter.increment()?;
+            }
             // If the evaluation fails, bail and return the error.
             if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+                return Err(error.context(format!("Failed to evaluate instruction ({instruction})")));
             }
         }
         lap!(timer, "Evaluate the instructions");
@@ -77,11 +106,16 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                 }
             })
-            .collect();
+            .collect::<Result<Vec<_>>>()?;
         lap!(timer, "Load the outputs");
 
+        // If the closure is pure, cache the outputs.
+        if let Some(cache) = &closure_cache {
+            cache.insert(&locator, inputs, outputs.clone())?;
+        }
+
         finish!(timer);
-        outputs
+        Ok(outputs)
     }
 
     /// Evaluates a program function on the given inputs.
@@ -94,7 +128,7 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
         // Retrieve the next request, based on the call stack mode.
         let (request, call_stack) = match &call_stack {
-            CallStack::Evaluate(authorization) => (authorization.next()?, call_stack),
+            CallStack::Evaluate(authorization, ..) => (authorization.next()?, call_stack),
             CallStack::Execute(authorization, ..) => (authorization.peek_next()?, call_stack.replicate()),
             _ => bail!("Illegal operation: call stack must be `Evaluate` or `Execute` in `evaluate_function`."),
         };
@@ -126,6 +160,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         }
         lap!(timer, "Perform input checks");
 
+        // Retrieve the instruction counter and evaluation mode.
+        let instruction_counter = call_stack.instruction_counter().cloned();
+        let evaluation_mode = call_stack.evaluation_mode();
+
         // Initialize the registers.
         let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(function.name())?.clone());
         // Set the transition caller.
@@ -145,11 +183,24 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
-        // Evaluate the instructions.
-        for instruction in function.instructions() {
-            // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+        // Evaluate the instructions in parallel, if enabled.
+        let is_evaluated = match evaluation_mode {
+            Some(EvaluationMode::Parallel) => {
+                self.evaluate_in_parallel(&function, &mut registers, instruction_counter.as_ref())?
+            }
+            _ => false,
+        };
+        // Otherwise, evaluate the instructions serially.
+        if !is_evaluated {
+            for instruction in function.instructions() {
+                // Count the instruction, halting if the instruction limit is exceeded.
+                if let Some(instruction_counter) = &instruction_counter {
+                    instruction_counter.increment()?;
+                }
+                // If the evaluation fails, bail and return the error.
+                if let Err(error) = instruction.evaluate(self, &mut registers) {
+                    return Err(error.context(format!("Failed to evaluate instruction ({instruction})")));
+                }
             }
         }
         lap!(timer, "Evaluate the instructions");
@@ -203,3 +254,112 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         )
     }
 }
+
+impl<N: Network> Stack<N> {
+    /// Evaluates the instructions of the function level by level, where the instructions in each level are
+    /// independent and evaluated concurrently, and stores their outputs in the given registers.
+    ///
+    /// Returns `false` without modifying the registers if parallel evaluation does not apply,
+    /// in which case the caller evaluates serially.
+    ///
+    /// # Errors
+    /// This method will halt with the error of the first failing instruction, in program order, of a level.
+    fn evaluate_in_parallel<A: circuit::Aleo<Network = N>>(
+        &self,
+        function: &Function<N>,
+        registers: &mut Registers<N, A>,
+        instruction_counter: Option<&InstructionCounter>,
+    ) -> Result<bool> {
+        let instructions = function.instructions();
+        // Ensure the instructions have no side effects beyond their destinations, and fit in the instruction limit.
+        if registers.is_tracing()
+            || instructions.iter().any(|instruction| matches!(instruction, Instruction::Call(..)))
+            || !instruction_counter.map_or(true, |counter| counter.has_capacity(instructions.len()))
+        {
+            return Ok(false);
+        }
+
+        // Retrieve the register types, caller, transition view key, and call stack.
+        let register_types = self.get_register_types(function.name())?;
+        let caller = registers.caller()?;
+        let tvk = registers.tvk()?;
+        let call_stack = registers.call_stack();
+
+        // Initialize the register values with the inputs.
+        let mut values = IndexMap::new();
+        for input in function.inputs() {
+            let register = input.register();
+            values.insert(register.locator(), registers.load(self, &Operand::Register(register.clone()))?);
+        }
+
+        // Evaluate each level of independent instructions concurrently.
+        for level in function.dependency_levels() {
+            let outputs = cfg_iter!(level)
+                .map(|index| {
+                    let instruction = &instructions[*index];
+                    self.evaluate_isolated::<A>(instruction, &values, &call_stack, register_types, caller, tvk)
+                        .map_err(|error| error.context(format!("Failed to evaluate instruction ({instruction})")))
+                })
+                .collect::<Vec<_>>();
+            // If any instruction fails, bail and return the error of the first one.
+            for output in outputs {
+                values.extend(output?);
+            }
+        }
+
+        // Store the outputs in the order of their registers.
+        values.sort_keys();
+        for (locator, value) in values.into_iter().skip(function.inputs().len()) {
+            registers.store(self, &Register::Locator(locator), value)?;
+        }
+        // Count the evaluated instructions.
+        if let Some(instruction_counter) = instruction_counter {
+            instructions.iter().try_for_each(|_| instruction_counter.increment())?;
+        }
+        Ok(true)
+    }
+    /// Evaluates the given instruction on registers that hold only the values it reads,
+    /// and returns the values assigned to its destinations.
+    fn evaluate_isolated<A: circuit::Aleo<Network = N>>(
+        &self,
+        instruction: &Instruction<N>,
+        values: &IndexMap<u64, Value<N>>,
+        call_stack: &CallStack<N>,
+        register_types: &RegisterTypes<N>,
+        caller: Address<N>,
+        tvk: Field<N>,
+    ) -> Result<Vec<(u64, Value<N>)>> {
+        // Initialize the registers.
+        let mut registers = Registers::<N, A>::sparse(call_stack.clone(), register_types.clone());
+        registers.set_caller(caller);
+        registers.set_tvk(tvk);
+
+        // Store the registers read by the instruction, in order.
+        let mut locators = instruction
+            .operands()
+            .iter()
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => Some(register.locator()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        locators.sort_unstable();
+        locators.dedup();
+        for locator in locators {
+            let value = values.get(&locator).ok_or_else(|| anyhow!("Register 'r{locator}' is not assigned"))?;
+            registers.skip_to(locator)?;
+            registers.store(self, &Register::Locator(locator), value.clone())?;
+        }
+
+        // Skip to the destinations, then evaluate the instruction and load its destinations.
+        if let Some(register) = instruction.destinations().first() {
+            registers.skip_to(register.locator())?;
+        }
+        instruction.evaluate(self, &mut registers)?;
+        instruction
+            .destinations()
+            .into_iter()
+            .map(|register| Ok((register.locator(), registers.load(self, &Operand::Register(register))?)))
+            .collect()
+    }
+}
@@ -69,7 +69,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
                 if let Err(error) = instruction.evaluate(self, &mut registers) {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
+                    return Err(error.context(format!("Failed to evaluate instruction ({instruction})")));
                 }
             }
             // Execute the instruction.
@@ -223,7 +223,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
                 if let Err(error) = instruction.evaluate(self, &mut registers) {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
+                    return Err(error.context(format!("Failed to evaluate instruction ({instruction})")));
                 }
             }
 
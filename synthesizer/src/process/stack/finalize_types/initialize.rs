@@ -311,10 +311,14 @@ impl<N: Network> FinalizeTypes<N> {
                 ensure!(Program::<N>::is_reserved_opcode(opcode), "'{opcode}' is not an opcode.");
                 // Ensure the instruction is not the cast operation.
                 ensure!(!matches!(instruction, Instruction::Cast(..)), "Instruction '{instruction}' is a 'cast'.");
-                // Ensure the instruction has one destination register.
+                // Ensure the instruction has one destination register, or two for `div_rem`.
+                let num_destinations = match instruction {
+                    Instruction::DivRem(..) => 2,
+                    _ => 1,
+                };
                 ensure!(
-                    instruction.destinations().len() == 1,
-                    "Instruction '{instruction}' has multiple destinations."
+                    instruction.destinations().len() == num_destinations,
+                    "Instruction '{instruction}' has an invalid number of destinations."
                 );
             }
             Opcode::Assert(opcode) => {
@@ -338,11 +342,17 @@ impl<N: Network> FinalizeTypes<N> {
             Opcode::Call => {
                 bail!("Instruction 'call' is not allowed in 'finalize'");
             }
-            Opcode::Cast => {
-                // Retrieve the cast operation.
-                let operation = match instruction {
-                    Instruction::Cast(operation) => operation,
-                    _ => bail!("Instruction '{instruction}' is not a cast operation."),
+            Opcode::Cast(opcode) => {
+                // Retrieve the casted register type.
+                let register_type = match (opcode, instruction) {
+                    ("cast", Instruction::Cast(operation)) => operation.register_type(),
+                    ("cast.lossy", Instruction::CastLossy(operation)) => operation.register_type(),
+                    // Note: The byte casts have a fixed number of operands and destinations, checked by their output types.
+                    ("cast.from_bytes", Instruction::FromBytesLe(..))
+                    | ("cast.to_bytes", Instruction::ToBytesLe(..)) => {
+                        return Ok(());
+                    }
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 };
 
                 // Ensure the instruction has one destination register.
@@ -352,9 +362,13 @@ impl<N: Network> FinalizeTypes<N> {
                 );
 
                 // Ensure the casted register type is defined.
-                match operation.register_type() {
+                match register_type {
                     RegisterType::Plaintext(PlaintextType::Literal(..)) => {
-                        bail!("Casting to literal is currently unsupported")
+                        // Ensure the instruction has one operand.
+                        ensure!(
+                            instruction.operands().len() == 1,
+                            "Instruction '{instruction}' must have exactly one operand."
+                        );
                     }
                     RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                         // Ensure the struct name exists in the program.
@@ -435,6 +449,8 @@ impl<N: Network> FinalizeTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.to_group",
+                    "hash.to_scalar",
                 ]
                 .contains(&opcode)
                 {
@@ -478,12 +494,20 @@ impl<N: Network> FinalizeTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.to_group" => ensure!(
+                        matches!(instruction, Instruction::HashToGroup(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.to_scalar" => ensure!(
+                        matches!(instruction, Instruction::HashToScalar(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
             Opcode::Is(opcode) => {
                 // Ensure the instruction belongs to the defined set.
-                if !["is.eq", "is.neq"].contains(&opcode) {
+                if !["is.eq", "is.neq", "is.owner"].contains(&opcode) {
                     bail!("Instruction '{instruction}' is not for opcode '{opcode}'.");
                 }
                 // Ensure the instruction is the correct one.
@@ -496,6 +520,10 @@ impl<N: Network> FinalizeTypes<N> {
                         matches!(instruction, Instruction::IsNeq(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "is.owner" => ensure!(
+                        matches!(instruction, Instruction::IsOwner(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
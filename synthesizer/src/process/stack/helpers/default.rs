@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Returns the default value for the given value type, where every literal is set to its zero value.
+    /// If the value type is a record, the given `owner` is used as the record owner.
+    pub fn default_value(&self, owner: &Address<N>, value_type: &ValueType<N>) -> Result<Value<N>> {
+        match value_type {
+            ValueType::Constant(plaintext_type)
+            | ValueType::Public(plaintext_type)
+            | ValueType::Private(plaintext_type) => Ok(Value::Plaintext(self.default_plaintext(plaintext_type)?)),
+            ValueType::Record(record_name) => Ok(Value::Record(self.default_record(owner, record_name)?)),
+            ValueType::ExternalRecord(locator) => {
+                bail!("Illegal operation: Cannot construct a default for external records (for '{locator}.record').")
+            }
+        }
+    }
+
+    /// Returns the default record for the given record name, with the given owner.
+    pub fn default_record(&self, owner: &Address<N>, record_name: &Identifier<N>) -> Result<Record<N, Plaintext<N>>> {
+        // Construct the default record.
+        let record = self.default_record_internal(owner, record_name, 0)?;
+        // Ensure the record matches the value type.
+        self.matches_record(&record, record_name)?;
+        // Return the record.
+        Ok(record)
+    }
+
+    /// Returns the default plaintext value for the given plaintext type.
+    pub fn default_plaintext(&self, plaintext_type: &PlaintextType<N>) -> Result<Plaintext<N>> {
+        // Construct the default plaintext value.
+        let plaintext = self.default_plaintext_internal(plaintext_type, 0)?;
+        // Ensure the plaintext value matches the plaintext type.
+        self.matches_plaintext(&plaintext, plaintext_type)?;
+        // Return the plaintext value.
+        Ok(plaintext)
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Returns the default record for the given record name.
+    fn default_record_internal(
+        &self,
+        owner: &Address<N>,
+        record_name: &Identifier<N>,
+        depth: usize,
+    ) -> Result<Record<N, Plaintext<N>>> {
+        // If the depth exceeds the maximum depth, then the plaintext type is invalid.
+        ensure!(depth <= N::MAX_DATA_DEPTH, "Plaintext exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
+
+        // Retrieve the record type from the program.
+        let record_type = self.program.get_record(record_name)?;
+
+        // Initialize the owner based on the visibility.
+        let owner = match record_type.owner().is_public() {
+            true => RecordOwner::Public(*owner),
+            false => RecordOwner::Private(Plaintext::Literal(Literal::Address(*owner), Default::default())),
+        };
+
+        // Initialize the record data according to the defined type.
+        let data = record_type
+            .entries()
+            .iter()
+            .map(|(entry_name, entry_type)| {
+                // Construct the default entry value.
+                let entry = self.default_entry_internal(entry_type, depth + 1)?;
+                // Return the entry.
+                Ok((*entry_name, entry))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        // Return the record, with a zero nonce.
+        Record::<N, Plaintext<N>>::from_plaintext(owner, data, Group::zero())
+    }
+
+    /// Returns the default entry for the given entry type.
+    fn default_entry_internal(&self, entry_type: &EntryType<N>, depth: usize) -> Result<Entry<N, Plaintext<N>>> {
+        // If the depth exceeds the maximum depth, then the entry type is invalid.
+        ensure!(depth <= N::MAX_DATA_DEPTH, "Entry exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
+
+        match entry_type {
+            EntryType::Constant(plaintext_type)
+            | EntryType::Public(plaintext_type)
+            | EntryType::Private(plaintext_type) => {
+                // Construct the default plaintext value.
+                let plaintext = self.default_plaintext_internal(plaintext_type, depth)?;
+                // Return the entry, with the declared visibility.
+                match entry_type {
+                    EntryType::Constant(..) => Ok(Entry::Constant(plaintext)),
+                    EntryType::Public(..) => Ok(Entry::Public(plaintext)),
+                    EntryType::Private(..) => Ok(Entry::Private(plaintext)),
+                }
+            }
+        }
+    }
+
+    /// Returns the default plaintext value for the given plaintext type.
+    fn default_plaintext_internal(&self, plaintext_type: &PlaintextType<N>, depth: usize) -> Result<Plaintext<N>> {
+        // If the depth exceeds the maximum depth, then the plaintext type is invalid.
+        ensure!(depth <= N::MAX_DATA_DEPTH, "Plaintext exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
+
+        let plaintext = match plaintext_type {
+            // Construct the default literal.
+            PlaintextType::Literal(literal_type) => {
+                Plaintext::Literal(Literal::default_for_type(*literal_type)?, Default::default())
+            }
+            // Construct the default struct.
+            PlaintextType::Struct(struct_name) => {
+                // Retrieve the struct.
+                let struct_ = self.program.get_struct(struct_name)?;
+                // Construct the default value for each member of the struct.
+                let members = struct_
+                    .members()
+                    .iter()
+                    .map(|(member_name, member_type)| {
+                        // Construct the default member value.
+                        let member = self.default_plaintext_internal(member_type, depth + 1)?;
+                        // Return the member.
+                        Ok((*member_name, member))
+                    })
+                    .collect::<Result<IndexMap<_, _>>>()?;
+
+                Plaintext::Struct(members, Default::default())
+            }
+        };
+        // Return the plaintext.
+        Ok(plaintext)
+    }
+}
@@ -250,3 +250,106 @@ impl<N: Network> Stack<N> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::test_helpers::sample_process;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const OWNER: &str = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+
+    fn check_value_type(value: &str, value_type: &str) -> Result<()> {
+        // Initialize a program with a nested record layout.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program layout.aleo;
+
+struct point:
+    x as u64;
+    y as u64;
+
+record token:
+    owner as address.private;
+    amount as u64.public;
+    location as point.private;
+
+function noop:
+    input r0 as u64.private;
+    output r0 as u64.private;",
+        )?;
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Check the value against the value type.
+        stack.matches_value_type(&Value::from_str(value)?, &ValueType::from_str(value_type)?)
+    }
+
+    #[test]
+    fn test_matches_value_type() -> Result<()> {
+        // Ensure a matching record passes.
+        check_value_type(
+            &format!(
+                "{{ owner: {OWNER}.private, amount: 1u64.public, location: {{ x: 2u64.private, y: 3u64.private }}, _nonce: 0group.public }}"
+            ),
+            "token.record",
+        )?;
+        // Ensure a matching struct passes.
+        check_value_type("{ x: 2u64, y: 3u64 }", "point.private")
+    }
+
+    #[test]
+    fn test_matches_value_type_fails() {
+        // Ensure a record with a wrong leaf type fails.
+        let error = check_value_type(
+            &format!(
+                "{{ owner: {OWNER}.private, amount: 1u64.public, location: {{ x: 2u64.private, y: 3u32.private }}, _nonce: 0group.public }}"
+            ),
+            "token.record",
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("expected u64, found 3u32"), "{error}");
+
+        // Ensure a record with a missing member fails.
+        let error = check_value_type(
+            &format!(
+                "{{ owner: {OWNER}.private, amount: 1u64.public, location: {{ x: 2u64.private }}, _nonce: 0group.public }}"
+            ),
+            "token.record",
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("'point' expected 2 members, found 1 members"), "{error}");
+
+        // Ensure a record with an extra member fails.
+        let error = check_value_type(
+            &format!(
+                "{{ owner: {OWNER}.private, amount: 1u64.public, location: {{ x: 2u64.private, y: 3u64.private, z: 4u64.private }}, _nonce: 0group.public }}"
+            ),
+            "token.record",
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("'point' expected 2 members, found 3 members"), "{error}");
+
+        // Ensure a record with a `public` entry declared `private` fails.
+        let error = check_value_type(
+            &format!(
+                "{{ owner: {OWNER}.private, amount: 1u64.private, location: {{ x: 2u64.private, y: 3u64.private }}, _nonce: 0group.public }}"
+            ),
+            "token.record",
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("Type mismatch in record entry 'token.amount'"), "{error}");
+
+        // Ensure a record with a swapped owner visibility fails.
+        let error = check_value_type(
+            &format!(
+                "{{ owner: {OWNER}.public, amount: 1u64.public, location: {{ x: 2u64.private, y: 3u64.private }}, _nonce: 0group.public }}"
+            ),
+            "token.record",
+        )
+        .unwrap_err();
+        assert_eq!("Visibility of record entry 'owner' does not match", error.to_string());
+    }
+}
@@ -14,6 +14,7 @@
 
 use super::*;
 
+mod default;
 mod initialize;
 mod matches;
 mod sample;
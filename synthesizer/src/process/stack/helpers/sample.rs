@@ -16,6 +16,7 @@ use super::*;
 
 impl<N: Network> Stack<N> {
     /// Returns a value for the given value type.
+    /// Note: Records are owned by the given burner address, and external records are sampled from their own stack.
     pub fn sample_value<R: Rng + CryptoRng>(
         &self,
         burner_address: &Address<N>,
@@ -30,7 +31,10 @@ impl<N: Network> Stack<N> {
                 Ok(Value::Record(self.sample_record(burner_address, record_name, rng)?))
             }
             ValueType::ExternalRecord(locator) => {
-                bail!("Illegal operation: Cannot sample external records (for '{locator}.record').")
+                // Retrieve the external stack.
+                let stack = self.get_external_stack(locator.program_id())?;
+                // Sample the record from the external stack.
+                Ok(Value::Record(stack.sample_record(burner_address, locator.resource(), rng)?))
             }
         }
     }
@@ -170,3 +174,107 @@ impl<N: Network> Stack<N> {
         Ok(plaintext)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a process with a program whose inputs span literals, nested structs, records, and external records.
+    fn sample_process() -> Result<(Process<CurrentNetwork>, ProgramID<CurrentNetwork>)> {
+        let external = Program::<CurrentNetwork>::from_str(
+            r"
+program external.aleo;
+
+record coin:
+    owner as address.public;
+    amount as u64.private;
+
+function noop:
+    input r0 as u64.private;",
+        )?;
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import external.aleo;
+
+program layout.aleo;
+
+struct point:
+    x as u64;
+    y as i8;
+
+struct segment:
+    start as point;
+    end as point;
+    label as field;
+
+record token:
+    owner as address.private;
+    amount as u64.public;
+    location as point.private;
+    flag as boolean.constant;
+
+function noop:
+    input r0 as u64.private;",
+        )?;
+        let mut process = Process::load()?;
+        process.add_program(&external)?;
+        process.add_program(&program)?;
+        Ok((process, *program.id()))
+    }
+
+    #[test]
+    fn test_sample_value_matches_type() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (process, program_id) = sample_process()?;
+        let stack = process.get_stack(program_id)?;
+        let burner_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+
+        for value_type in [
+            "u8.public",
+            "scalar.private",
+            "address.constant",
+            "point.private",
+            "segment.public",
+            "token.record",
+            "external.aleo/coin.record",
+        ] {
+            let value_type = ValueType::from_str(value_type)?;
+            for _ in 0..10 {
+                // Ensure the sampled value matches the value type.
+                let value = stack.sample_value(&burner_address, &value_type, rng)?;
+                match &value_type {
+                    // External records are checked against their own stack.
+                    ValueType::ExternalRecord(locator) => {
+                        let external_stack = stack.get_external_stack(locator.program_id())?;
+                        external_stack.matches_value_type(&value, &ValueType::Record(*locator.resource()))?
+                    }
+                    _ => stack.matches_value_type(&value, &value_type)?,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_value_differs() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (process, program_id) = sample_process()?;
+        let stack = process.get_stack(program_id)?;
+        let burner_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+
+        for value_type in ["field.private", "segment.public", "token.record"] {
+            let value_type = ValueType::from_str(value_type)?;
+            // Ensure two samples from different RNG states differ.
+            let first = stack.sample_value(&burner_address, &value_type, rng)?;
+            let second = stack.sample_value(&burner_address, &value_type, rng)?;
+            assert_ne!(first, second, "Sampled the same '{value_type}' twice");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// A counter of the instructions evaluated across a call, including nested calls, with an optional limit.
+#[derive(Clone, Default)]
+pub struct InstructionCounter {
+    /// The maximum number of instructions that may be evaluated, if any.
+    limit: Option<usize>,
+    /// The number of instructions evaluated.
+    num_instructions: Arc<RwLock<usize>>,
+}
+
+impl InstructionCounter {
+    /// Initializes a new instruction counter with the given limit, if any.
+    pub fn new(limit: Option<usize>) -> Self {
+        Self { limit, num_instructions: Default::default() }
+    }
+
+    /// Returns a new and independent replica of the instruction counter.
+    pub fn replicate(&self) -> Self {
+        Self { limit: self.limit, num_instructions: Arc::new(RwLock::new(*self.num_instructions.read())) }
+    }
+
+    /// Returns the maximum number of instructions that may be evaluated, if any.
+    pub const fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Returns the number of instructions evaluated.
+    pub fn num_instructions(&self) -> usize {
+        *self.num_instructions.read()
+    }
+
+    /// Returns `true` if the given number of instructions may be evaluated without exceeding the limit.
+    pub fn has_capacity(&self, num_instructions: usize) -> bool {
+        match self.limit {
+            Some(limit) => self.num_instructions().saturating_add(num_instructions) <= limit,
+            None => true,
+        }
+    }
+
+    /// Increments the number of instructions evaluated, halting if the limit is exceeded.
+    pub fn increment(&self) -> Result<()> {
+        let mut num_instructions = self.num_instructions.write();
+        *num_instructions += 1;
+        match self.limit {
+            Some(limit) if *num_instructions > limit => {
+                bail!("Evaluation halted: instruction limit exceeded ({limit})")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instruction_counter() {
+        let counter = InstructionCounter::new(Some(2));
+        assert!(counter.increment().is_ok());
+        assert!(counter.increment().is_ok());
+        assert!(counter.increment().is_err());
+        assert_eq!(3, counter.num_instructions());
+
+        // Ensure clones share the count, and replicas do not.
+        let replica = counter.replicate();
+        counter.clone().increment().unwrap_err();
+        assert_eq!(4, counter.num_instructions());
+        assert_eq!(3, replica.num_instructions());
+
+        // Ensure the capacity accounts for the instructions already evaluated.
+        let counter = InstructionCounter::new(Some(3));
+        counter.increment().unwrap();
+        assert!(counter.has_capacity(2));
+        assert!(!counter.has_capacity(3));
+
+        // Ensure the default counter is unlimited.
+        let counter = InstructionCounter::default();
+        (0..1000).for_each(|_| counter.increment().unwrap());
+        assert_eq!(None, counter.limit());
+    }
+}
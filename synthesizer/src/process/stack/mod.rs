@@ -15,6 +15,9 @@
 mod authorization;
 pub use authorization::*;
 
+mod closure_cache;
+pub use closure_cache::*;
+
 mod finalize_registers;
 pub use finalize_registers::*;
 
@@ -24,6 +27,9 @@ pub use finalize_types::*;
 mod inclusion;
 pub use inclusion::*;
 
+mod instruction_counter;
+pub use instruction_counter::*;
+
 mod query;
 pub use query::*;
 
@@ -95,19 +101,50 @@ pub struct CallMetrics<N: Network> {
     pub num_response_constraints: u64,
 }
 
+/// The strategy for evaluating the instructions of a function.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum EvaluationMode {
+    /// Evaluates the instructions one at a time, in program order.
+    #[default]
+    Serial,
+    /// Evaluates independent instructions concurrently, with the same outcome as serial evaluation.
+    /// Note: Functions containing a `call` are always evaluated serially.
+    Parallel,
+}
+
 #[derive(Clone)]
 pub enum CallStack<N: Network> {
     Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
-    Evaluate(Authorization<N>),
+    Evaluate(Authorization<N>, ClosureCache<N>, InstructionCounter, EvaluationMode),
     Execute(Authorization<N>, Arc<RwLock<Execution<N>>>, Arc<RwLock<Inclusion<N>>>, Arc<RwLock<Vec<CallMetrics<N>>>>),
 }
 
 impl<N: Network> CallStack<N> {
     /// Initializes a call stack as `Self::Evaluate`.
     pub fn evaluate(authorization: Authorization<N>) -> Result<Self> {
-        Ok(CallStack::Evaluate(authorization))
+        Self::evaluate_with_limit(authorization, None)
+    }
+
+    /// Initializes a call stack as `Self::Evaluate`, which halts after evaluating `limit` instructions, if given.
+    pub fn evaluate_with_limit(authorization: Authorization<N>, limit: Option<usize>) -> Result<Self> {
+        Ok(CallStack::Evaluate(
+            authorization,
+            ClosureCache::new(),
+            InstructionCounter::new(limit),
+            EvaluationMode::default(),
+        ))
+    }
+
+    /// Sets the evaluation mode, if the call stack is in evaluate mode.
+    pub fn with_evaluation_mode(self, mode: EvaluationMode) -> Self {
+        match self {
+            CallStack::Evaluate(authorization, closure_cache, instruction_counter, _) => {
+                CallStack::Evaluate(authorization, closure_cache, instruction_counter, mode)
+            }
+            call_stack => call_stack,
+        }
     }
 
     /// Initializes a call stack as `Self::Execute`.
@@ -119,6 +156,30 @@ impl<N: Network> CallStack<N> {
     ) -> Result<Self> {
         Ok(CallStack::Execute(authorization, execution, inclusion, metrics))
     }
+
+    /// Returns the closure cache, if the call stack is in evaluate mode.
+    pub fn closure_cache(&self) -> Option<&ClosureCache<N>> {
+        match self {
+            CallStack::Evaluate(_, closure_cache, ..) => Some(closure_cache),
+            _ => None,
+        }
+    }
+
+    /// Returns the instruction counter, if the call stack is in evaluate mode.
+    pub fn instruction_counter(&self) -> Option<&InstructionCounter> {
+        match self {
+            CallStack::Evaluate(_, _, instruction_counter, _) => Some(instruction_counter),
+            _ => None,
+        }
+    }
+
+    /// Returns the evaluation mode, if the call stack is in evaluate mode.
+    pub fn evaluation_mode(&self) -> Option<EvaluationMode> {
+        match self {
+            CallStack::Evaluate(.., mode) => Some(*mode),
+            _ => None,
+        }
+    }
 }
 
 impl<N: Network> CallStack<N> {
@@ -136,7 +197,12 @@ impl<N: Network> CallStack<N> {
                 *private_key,
                 Arc::new(RwLock::new(assignments.read().clone())),
             ),
-            CallStack::Evaluate(authorization) => CallStack::Evaluate(authorization.replicate()),
+            CallStack::Evaluate(authorization, closure_cache, instruction_counter, mode) => CallStack::Evaluate(
+                authorization.replicate(),
+                closure_cache.replicate(),
+                instruction_counter.replicate(),
+                *mode,
+            ),
             CallStack::Execute(authorization, execution, inclusion, metrics) => CallStack::Execute(
                 authorization.replicate(),
                 Arc::new(RwLock::new(execution.read().clone())),
@@ -152,7 +218,7 @@ impl<N: Network> CallStack<N> {
             CallStack::Authorize(requests, ..) => requests.push(request),
             CallStack::Synthesize(requests, ..) => requests.push(request),
             CallStack::CheckDeployment(requests, ..) => requests.push(request),
-            CallStack::Evaluate(authorization) => authorization.push(request),
+            CallStack::Evaluate(authorization, ..) => authorization.push(request),
             CallStack::Execute(authorization, ..) => authorization.push(request),
         }
         Ok(())
@@ -166,7 +232,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::CheckDeployment(requests, ..) => {
                 requests.pop().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.next(),
+            CallStack::Evaluate(authorization, ..) => authorization.next(),
             CallStack::Execute(authorization, ..) => authorization.next(),
         }
     }
@@ -179,7 +245,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::CheckDeployment(requests, ..) => {
                 requests.last().cloned().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.peek_next(),
+            CallStack::Evaluate(authorization, ..) => authorization.peek_next(),
             CallStack::Execute(authorization, ..) => authorization.peek_next(),
         }
     }
@@ -403,6 +469,35 @@ impl<N: Network> Stack<N> {
     }
 }
 
+impl<N: Network> Stack<N> {
+    /// Returns the estimated cost of the given closure or function, including the cost of its callees.
+    pub fn get_cost(&self, name: &Identifier<N>) -> Result<u64> {
+        // Retrieve the instructions of the closure or function.
+        let instructions = match self.program.contains_function(name) {
+            true => self.program.get_function(name)?.instructions().to_vec(),
+            false => self.program.get_closure(name)?.instructions().to_vec(),
+        };
+        // Sum the cost of each instruction, and of each callee.
+        let mut cost = 0u64;
+        for instruction in &instructions {
+            let callee_cost = match instruction {
+                Instruction::Call(call) => match call.operator() {
+                    CallOperator::Locator(locator) => {
+                        self.get_external_stack(locator.program_id())?.get_cost(locator.resource())?
+                    }
+                    CallOperator::Resource(resource) => self.get_cost(resource)?,
+                },
+                _ => 0,
+            };
+            cost = cost
+                .checked_add(instruction.cost())
+                .and_then(|cost| cost.checked_add(callee_cost))
+                .ok_or_else(|| anyhow!("The cost of '{name}' in '{}' overflowed", self.program.id()))?;
+        }
+        Ok(cost)
+    }
+}
+
 impl<N: Network> PartialEq for Stack<N> {
     fn eq(&self, other: &Self) -> bool {
         self.program == other.program
@@ -227,6 +227,15 @@ impl<N: Network> RegisterTypes<N> {
         operand: &Operand<N>,
         register_type: &RegisterType<N>,
     ) -> Result<()> {
+        // Ensure the output register is assigned by an input or an instruction.
+        if let Operand::Register(register) = operand {
+            ensure!(
+                self.contains(register),
+                "Output register '{register}' in '{}' is never assigned",
+                stack.program_id()
+            );
+        }
+
         match operand {
             // Inform the user the output operand is an input register, to ensure this is intended behavior.
             Operand::Register(register) if self.is_input(register) => {
@@ -323,10 +332,14 @@ impl<N: Network> RegisterTypes<N> {
                 ensure!(Program::<N>::is_reserved_opcode(opcode), "'{opcode}' is not an opcode.");
                 // Ensure the instruction is not the cast operation.
                 ensure!(!matches!(instruction, Instruction::Cast(..)), "Instruction '{instruction}' is a 'cast'.");
-                // Ensure the instruction has one destination register.
+                // Ensure the instruction has one destination register, or two for `div_rem`.
+                let num_destinations = match instruction {
+                    Instruction::DivRem(..) => 2,
+                    _ => 1,
+                };
                 ensure!(
-                    instruction.destinations().len() == 1,
-                    "Instruction '{instruction}' has multiple destinations."
+                    instruction.destinations().len() == num_destinations,
+                    "Instruction '{instruction}' has an invalid number of destinations."
                 );
             }
             Opcode::Assert(opcode) => {
@@ -397,14 +410,26 @@ impl<N: Network> RegisterTypes<N> {
                         if !stack.program().contains_closure(resource) {
                             bail!("'{resource}' is not defined in '{}'.", stack.program_id())
                         }
+                        // Ensure the closure does not call back into this closure (i.e. an indirect recursive call).
+                        if Self::is_reachable(stack.program(), resource, closure_or_function_name) {
+                            bail!(
+                                "Cannot invoke 'call' to '{resource}' (in '{closure_or_function_name}'): recursive call cycle."
+                            )
+                        }
                     }
                 }
             }
-            Opcode::Cast => {
-                // Retrieve the cast operation.
-                let operation = match instruction {
-                    Instruction::Cast(operation) => operation,
-                    _ => bail!("Instruction '{instruction}' is not a cast operation."),
+            Opcode::Cast(opcode) => {
+                // Retrieve the casted register type.
+                let register_type = match (opcode, instruction) {
+                    ("cast", Instruction::Cast(operation)) => operation.register_type(),
+                    ("cast.lossy", Instruction::CastLossy(operation)) => operation.register_type(),
+                    // Note: The byte casts have a fixed number of operands and destinations, checked by their output types.
+                    ("cast.from_bytes", Instruction::FromBytesLe(..))
+                    | ("cast.to_bytes", Instruction::ToBytesLe(..)) => {
+                        return Ok(());
+                    }
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 };
 
                 // Ensure the instruction has one destination register.
@@ -414,9 +439,13 @@ impl<N: Network> RegisterTypes<N> {
                 );
 
                 // Ensure the casted register type is defined.
-                match operation.register_type() {
+                match register_type {
                     RegisterType::Plaintext(PlaintextType::Literal(..)) => {
-                        bail!("Casting to literal is currently unsupported")
+                        // Ensure the instruction has one operand.
+                        ensure!(
+                            instruction.operands().len() == 1,
+                            "Instruction '{instruction}' must have exactly one operand."
+                        );
                     }
                     RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                         // Ensure the struct name exists in the program.
@@ -508,6 +537,8 @@ impl<N: Network> RegisterTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.to_group",
+                    "hash.to_scalar",
                 ]
                 .contains(&opcode)
                 {
@@ -551,12 +582,20 @@ impl<N: Network> RegisterTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.to_group" => ensure!(
+                        matches!(instruction, Instruction::HashToGroup(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.to_scalar" => ensure!(
+                        matches!(instruction, Instruction::HashToScalar(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
             Opcode::Is(opcode) => {
                 // Ensure the instruction belongs to the defined set.
-                if !["is.eq", "is.neq"].contains(&opcode) {
+                if !["is.eq", "is.neq", "is.owner"].contains(&opcode) {
                     bail!("Instruction '{instruction}' is not for opcode '{opcode}'.");
                 }
                 // Ensure the instruction is the correct one.
@@ -569,10 +608,42 @@ impl<N: Network> RegisterTypes<N> {
                         matches!(instruction, Instruction::IsNeq(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "is.owner" => ensure!(
+                        matches!(instruction, Instruction::IsOwner(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
         }
         Ok(())
     }
+
+    /// Returns `true` if the given target is reachable from the given closure, by following its internal calls.
+    fn is_reachable(program: &Program<N>, closure_name: &Identifier<N>, target: &Identifier<N>) -> bool {
+        // Initialize the set of visited closures, and the closures to visit.
+        let mut visited = IndexSet::new();
+        let mut queue = vec![*closure_name];
+        // Traverse the internal calls.
+        while let Some(name) = queue.pop() {
+            if &name == target {
+                return true;
+            }
+            // Skip closures that were already visited. Missing closures are rejected when their caller is checked.
+            if !visited.insert(name) {
+                continue;
+            }
+            if let Ok(closure) = program.get_closure(&name) {
+                // Add the callees of the closure.
+                for instruction in closure.instructions() {
+                    if let Instruction::Call(call) = instruction {
+                        if let CallOperator::Resource(resource) = call.operator() {
+                            queue.push(*resource);
+                        }
+                    }
+                }
+            }
+        }
+        false
+    }
 }
@@ -31,7 +31,7 @@ use console::{
     },
 };
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct RegisterTypes<N: Network> {
@@ -65,6 +65,15 @@ impl<N: Network> RegisterTypes<N> {
         self.inputs.contains_key(locator) || self.destinations.contains_key(locator)
     }
 
+    /// Returns the mapping of each input and destination register to its type.
+    pub fn registers(&self) -> IndexMap<Register<N>, RegisterType<N>> {
+        self.inputs
+            .iter()
+            .chain(self.destinations.iter())
+            .map(|(locator, register_type)| (Register::Locator(*locator), *register_type))
+            .collect()
+    }
+
     /// Returns `true` if the given register corresponds to an input register.
     pub fn is_input(&self, register: &Register<N>) -> bool {
         self.inputs.contains_key(&register.locator())
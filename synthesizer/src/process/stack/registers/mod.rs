@@ -17,8 +17,16 @@ mod caller;
 mod load;
 mod store;
 
+mod snapshot;
+pub use snapshot::*;
+
+mod trace;
+pub use trace::*;
+
 use crate::{
     CallStack,
+    Instruction,
+    Opcode,
     Operand,
     RegisterTypes,
     RegistersCall,
@@ -57,6 +65,12 @@ pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     tvk: Option<Field<N>>,
     /// The transition view key, as a circuit.
     tvk_circuit: Option<circuit::Field<A>>,
+    /// The trace of evaluated instructions, if tracing is enabled.
+    trace: Option<Vec<TraceEntry<N>>>,
+    /// Whether console registers may be skipped, leaving gaps between locators.
+    is_sparse: bool,
+    /// The number of console registers skipped, if the registers are sparse.
+    num_skipped: u64,
 }
 
 impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
@@ -72,9 +86,36 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             caller_circuit: None,
             tvk: None,
             tvk_circuit: None,
+            trace: None,
+            is_sparse: false,
+            num_skipped: 0,
         }
     }
 
+    /// Initializes a new set of registers, given the call stack, whose console registers may be skipped.
+    /// Note: This is used to evaluate an instruction given only the registers it reads.
+    #[inline]
+    pub(crate) fn sparse(call_stack: CallStack<N>, register_types: RegisterTypes<N>) -> Self {
+        Self { is_sparse: true, ..Self::new(call_stack, register_types) }
+    }
+
+    /// Skips the unassigned console registers preceding the given locator, so that it is the next locator.
+    ///
+    /// # Errors
+    /// This method will halt if the registers are not sparse.
+    /// This method will halt if the given locator precedes the next locator.
+    #[inline]
+    pub(crate) fn skip_to(&mut self, locator: u64) -> Result<()> {
+        // Ensure the registers are sparse.
+        ensure!(self.is_sparse, "Cannot skip registers that are not sparse");
+        // Ensure the locator does not precede the next locator.
+        let next_locator = self.console_registers.len() as u64 + self.num_skipped;
+        ensure!(next_locator <= locator, "Cannot skip backwards to register 'r{locator}'");
+        // Skip the registers up to the locator.
+        self.num_skipped += locator - next_locator;
+        Ok(())
+    }
+
     /// Ensure the console and circuit registers match.
     #[inline]
     pub fn ensure_console_and_circuit_registers_match(&self) -> Result<()> {
@@ -95,3 +136,98 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process::Stack, program::test_helpers::sample_registers, Process, Program};
+    use circuit::{AleoV0, Eject, Inject};
+    use console::{network::Testnet3, program::Identifier};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Samples a stack and a new set of registers for its `run` function.
+    fn sample() -> Result<(Stack<CurrentNetwork>, Registers<CurrentNetwork, CurrentAleo>)> {
+        let program = Program::from_str(
+            "program registers.aleo;
+
+            function run:
+                input r0 as u8.private;
+                input r1 as field.private;
+                input r2 as boolean.private;
+                add r0 r0 into r3;",
+        )?;
+        let stack = Stack::new(&Process::load()?, &program)?;
+        let registers = sample_registers(&stack, &Identifier::from_str("run")?, &[])?;
+        Ok((stack, registers))
+    }
+
+    /// Returns the register operands for the given locators.
+    fn operands(locators: &[u64]) -> Vec<Operand<CurrentNetwork>> {
+        locators.iter().map(|locator| Operand::Register(Register::Locator(*locator))).collect()
+    }
+
+    #[test]
+    fn test_load_and_store_many() -> Result<()> {
+        let (stack, mut registers) = sample()?;
+
+        let destinations = [Register::Locator(0), Register::Locator(1), Register::Locator(2)];
+        let values: Vec<Value<CurrentNetwork>> =
+            ["5u8", "3field", "true"].into_iter().map(Value::from_str).try_collect()?;
+
+        // Store the values in both the console and circuit registers.
+        registers.store_many(&stack, &destinations, values.clone())?;
+        let circuit_values = values.iter().map(|value| circuit::Value::new(circuit::Mode::Private, value.clone()));
+        registers.store_many_circuit(&stack, &destinations, circuit_values.collect())?;
+
+        // Ensure the values are loaded in the order of the operands.
+        let expected = vec![values[2].clone(), values[0].clone(), values[1].clone()];
+        assert_eq!(registers.load_many(&stack, &operands(&[2, 0, 1]))?, expected);
+        assert_eq!(registers.load_many_circuit(&stack, &operands(&[2, 0, 1]))?.eject_value(), expected);
+
+        // Ensure an empty batch loads nothing.
+        assert!(registers.load_many(&stack, &[])?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_and_store_many_fails() -> Result<()> {
+        let (stack, mut registers) = sample()?;
+
+        // Ensure a batch with a mismatched number of values fails.
+        let destinations = [Register::Locator(0), Register::Locator(1)];
+        assert!(registers.store_many(&stack, &destinations, vec![Value::from_str("5u8")?]).is_err());
+
+        // Ensure a batch storing into an undefined register fails.
+        let destinations = [Register::Locator(0), Register::Locator(9)];
+        let values = vec![Value::from_str("5u8")?, Value::from_str("3field")?];
+        assert!(registers.store_many(&stack, &destinations, values).is_err());
+
+        // Ensure a batch loading an unassigned register fails.
+        assert!(registers.load_many(&stack, &operands(&[0])).is_ok());
+        assert!(registers.load_many(&stack, &operands(&[0, 1])).is_err());
+        assert!(registers.load_many_circuit(&stack, &operands(&[0])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sparse_skip_to() -> Result<()> {
+        let (stack, mut registers) = sample()?;
+
+        // Ensure registers that are not sparse cannot skip.
+        assert!(registers.skip_to(1).is_err());
+
+        // Ensure sparse registers store at the next locator, and only skip forwards.
+        let register_types = stack.get_register_types(&Identifier::from_str("run")?)?.clone();
+        let mut sparse = Registers::<CurrentNetwork, CurrentAleo>::sparse(registers.call_stack(), register_types);
+        assert!(sparse.store(&stack, &Register::Locator(1), Value::from_str("3field")?).is_err());
+        sparse.skip_to(1)?;
+        sparse.store(&stack, &Register::Locator(1), Value::from_str("3field")?)?;
+        assert!(sparse.skip_to(0).is_err());
+        assert!(sparse.store(&stack, &Register::Locator(3), Value::from_str("6u8")?).is_err());
+        sparse.skip_to(3)?;
+        sparse.store(&stack, &Register::Locator(3), Value::from_str("6u8")?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A checkpoint of the assigned registers, used to roll back speculative evaluation.
+/// Note: As registers are assigned exactly once and in order, a snapshot only records
+/// the number of assigned registers, and restoring it discards every later assignment.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RegistersSnapshot {
+    /// The number of assigned console registers.
+    num_console_registers: usize,
+    /// The number of assigned circuit registers.
+    num_circuit_registers: usize,
+}
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Returns a snapshot of the currently-assigned registers.
+    #[inline]
+    pub fn snapshot(&self) -> RegistersSnapshot {
+        RegistersSnapshot {
+            num_console_registers: self.console_registers.len(),
+            num_circuit_registers: self.circuit_registers.len(),
+        }
+    }
+
+    /// Restores the registers to the given snapshot, undefining every register assigned after it.
+    /// Note: This does not roll back the instruction trace, nor any constraints already synthesized.
+    #[inline]
+    pub fn restore(&mut self, snapshot: RegistersSnapshot) -> Result<()> {
+        // Ensure the snapshot does not refer to registers that are no longer assigned.
+        ensure!(
+            snapshot.num_console_registers <= self.console_registers.len()
+                && snapshot.num_circuit_registers <= self.circuit_registers.len(),
+            "Cannot restore the registers to a snapshot that is ahead of the current registers"
+        );
+        // Discard the registers assigned after the snapshot.
+        self.console_registers.truncate(snapshot.num_console_registers);
+        self.circuit_registers.truncate(snapshot.num_circuit_registers);
+        Ok(())
+    }
+}
@@ -30,8 +30,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStore<N> for Registers<
     ) -> Result<()> {
         match register {
             Register::Locator(locator) => {
-                // Ensure the register assignments are monotonically increasing.
-                let expected_locator = self.console_registers.len() as u64;
+                // Ensure the register assignments are monotonically increasing, accounting for skipped registers.
+                let expected_locator = self.console_registers.len() as u64 + self.num_skipped;
                 ensure!(expected_locator == *locator, "Out-of-order write operation at '{register}'");
                 // Ensure the register does not already exist.
                 ensure!(
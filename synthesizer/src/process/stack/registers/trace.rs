@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A record of a single instruction evaluated on a set of registers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceEntry<N: Network> {
+    /// The opcode of the instruction.
+    pub opcode: Opcode,
+    /// The values of the instruction operands, in order.
+    pub operands: Vec<Value<N>>,
+    /// The values assigned to the instruction destinations, in order.
+    /// Note: This is empty if the instruction halted before assigning its destinations.
+    pub destinations: Vec<Value<N>>,
+}
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Returns the registers, set to record a trace of every instruction evaluated from here on.
+    #[inline]
+    pub fn with_tracing(self) -> Self {
+        Self { trace: Some(Vec::new()), ..self }
+    }
+
+    /// Returns `true` if the registers are recording an instruction trace.
+    #[inline]
+    pub const fn is_tracing(&self) -> bool {
+        self.trace.is_some()
+    }
+
+    /// Returns the instruction trace, if tracing is enabled.
+    #[inline]
+    pub fn trace(&self) -> Option<&[TraceEntry<N>]> {
+        self.trace.as_deref()
+    }
+
+    /// Records the opcode and operand values of the given instruction, prior to its evaluation.
+    /// Note: This method is a no-op if tracing is disabled.
+    #[inline]
+    pub(crate) fn trace_operands(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        instruction: &Instruction<N>,
+    ) -> Result<()> {
+        if self.trace.is_none() {
+            return Ok(());
+        }
+        // Load the operand values.
+        let operands =
+            instruction.operands().iter().map(|operand| self.load(stack, operand)).collect::<Result<Vec<_>>>()?;
+        // Record the entry.
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraceEntry { opcode: instruction.opcode(), operands, destinations: Vec::new() });
+        }
+        Ok(())
+    }
+
+    /// Records the destination values of the given instruction, after its evaluation.
+    /// Note: This method is a no-op if tracing is disabled.
+    #[inline]
+    pub(crate) fn trace_destinations(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        instruction: &Instruction<N>,
+    ) -> Result<()> {
+        if self.trace.is_none() {
+            return Ok(());
+        }
+        // Load the destination values.
+        let destinations = instruction
+            .destinations()
+            .into_iter()
+            .map(|register| self.load(stack, &Operand::Register(register)))
+            .collect::<Result<Vec<_>>>()?;
+        // Attach them to the entry recorded for this instruction.
+        match self.trace.as_mut().and_then(|trace| trace.last_mut()) {
+            Some(entry) => entry.destinations = destinations,
+            None => bail!("Missing the trace entry for '{instruction}'"),
+        }
+        Ok(())
+    }
+}
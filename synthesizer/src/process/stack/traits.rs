@@ -152,6 +152,19 @@ pub trait RegistersLoad<N: Network> {
     /// In the case of register members, this method should halt if the member is not found.
     fn load(&self, stack: &(impl StackMatches<N> + StackProgram<N>), operand: &Operand<N>) -> Result<Value<N>>;
 
+    /// Loads the values of the given operands, in order.
+    ///
+    /// # Errors
+    /// This method should halt if any operand fails to load.
+    #[inline]
+    fn load_many(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        operands: &[Operand<N>],
+    ) -> Result<Vec<Value<N>>> {
+        operands.iter().map(|operand| self.load(stack, operand)).collect()
+    }
+
     /// Loads the literal of a given operand.
     ///
     /// # Errors
@@ -202,6 +215,19 @@ pub trait RegistersLoadCircuit<N: Network, A: circuit::Aleo<Network = N>> {
         operand: &Operand<N>,
     ) -> Result<circuit::Value<A>>;
 
+    /// Loads the values of the given operands, in order.
+    ///
+    /// # Errors
+    /// This method should halt if any operand fails to load.
+    #[inline]
+    fn load_many_circuit(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        operands: &[Operand<N>],
+    ) -> Result<Vec<circuit::Value<A>>> {
+        operands.iter().map(|operand| self.load_circuit(stack, operand)).collect()
+    }
+
     /// Loads the literal of a given operand.
     ///
     /// # Errors
@@ -269,6 +295,27 @@ pub trait RegistersStore<N: Network> {
     ) -> Result<()> {
         self.store(stack, register, Value::Plaintext(Plaintext::from(literal)))
     }
+
+    /// Assigns the given values to the given registers, in order.
+    ///
+    /// # Errors
+    /// This method should halt if the number of registers and values differ.
+    /// This method should halt if any value fails to store.
+    #[inline]
+    fn store_many(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &[Register<N>],
+        values: Vec<Value<N>>,
+    ) -> Result<()> {
+        ensure!(
+            registers.len() == values.len(),
+            "Expected {} values to store, found {}",
+            registers.len(),
+            values.len()
+        );
+        registers.iter().zip(values).try_for_each(|(register, value)| self.store(stack, register, value))
+    }
 }
 
 pub trait RegistersStoreCircuit<N: Network, A: circuit::Aleo<Network = N>> {
@@ -300,4 +347,25 @@ pub trait RegistersStoreCircuit<N: Network, A: circuit::Aleo<Network = N>> {
     ) -> Result<()> {
         self.store_circuit(stack, register, circuit::Value::Plaintext(circuit::Plaintext::from(literal)))
     }
+
+    /// Assigns the given values to the given registers, in order.
+    ///
+    /// # Errors
+    /// This method should halt if the number of registers and values differ.
+    /// This method should halt if any value fails to store.
+    #[inline]
+    fn store_many_circuit(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &[Register<N>],
+        values: Vec<circuit::Value<A>>,
+    ) -> Result<()> {
+        ensure!(
+            registers.len() == values.len(),
+            "Expected {} values to store, found {}",
+            registers.len(),
+            values.len()
+        );
+        registers.iter().zip(values).try_for_each(|(register, value)| self.store_circuit(stack, register, value))
+    }
 }
@@ -13,12 +13,12 @@
 // limitations under the License.
 
 use super::*;
-use crate::store::helpers::memory::FinalizeMemory;
+use crate::{store::helpers::memory::FinalizeMemory, InstructionError, Opcode, Operand};
 use circuit::network::AleoV0;
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::Testnet3,
-    program::{Identifier, Literal, Value},
+    program::{Identifier, Literal, Value, ValueType},
     types::Field,
 };
 
@@ -470,6 +470,116 @@ fn test_process_output_operand() {
     );
 }
 
+#[test]
+fn test_process_unassigned_output_register() {
+    // Initialize a new program, where the output register is never assigned.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program unassigned.aleo;
+
+  function compute:
+    input r0 as u32.private;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r3 as u32.private;",
+    )
+    .unwrap();
+
+    // Ensure the program is rejected, naming the unassigned output register.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    let error = process.add_program(&program).unwrap_err();
+    assert!(error.to_string().contains("Output register 'r3'"), "Unexpected error: {error}");
+
+    // Initialize a new program, where the output register is assigned.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program assigned.aleo;
+
+  function compute:
+    input r0 as u32.private;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;",
+    )
+    .unwrap();
+
+    // Ensure the program is accepted.
+    assert!(process.add_program(&program).is_ok());
+}
+
+#[test]
+fn test_process_ternary_rejects_struct_operands() {
+    // Initialize a new program, where `ternary` selects between two structs.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program ternary_struct.aleo;
+
+  struct point:
+    x as u32;
+    y as u32;
+
+  function compute:
+    input r0 as boolean.private;
+    input r1 as point.private;
+    input r2 as point.private;
+    ternary r0 r1 r2 into r3;
+    output r3 as point.private;",
+    )
+    .unwrap();
+
+    // Ensure the program is rejected, naming the instruction and the struct type.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    let error = process.add_program(&program).unwrap_err();
+    assert!(error.to_string().contains("Instruction 'ternary' expects literal operands"), "Unexpected error: {error}");
+}
+
+#[test]
+fn test_process_assert_struct_operands() {
+    // Initialize a new program, which asserts on struct operands.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program assert_struct.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+function check_eq:
+    input r0 as point.private;
+    input r1 as point.private;
+    assert.eq r0 r1;
+
+function check_neq:
+    input r0 as point.private;
+    input r1 as point.private;
+    assert.neq r0 r1;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = super::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Evaluates the given function on the given points.
+    let mut evaluate = |function_name: &str, first: &str, second: &str| {
+        let inputs = [Value::<CurrentNetwork>::from_str(first).unwrap(), Value::from_str(second).unwrap()];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        process.evaluate::<CurrentAleo>(authorization)
+    };
+
+    let a = "{ x: 1field, y: 2field }";
+    let b = "{ x: 1field, y: 3field }";
+
+    // Ensure the structs are compared member by member.
+    assert!(evaluate("check_eq", a, a).is_ok());
+    assert!(evaluate("check_eq", a, b).is_err());
+    assert!(evaluate("check_neq", a, b).is_ok());
+    assert!(evaluate("check_neq", a, a).is_err());
+}
+
 #[test]
 fn test_process_execute_call_closure() {
     // Initialize a new program.
@@ -594,6 +704,328 @@ function compute:
     // assert_eq!(159387, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_evaluate_caches_pure_closures() {
+    // Initialize a new program, where `twice` is pure and `check_caller` reads the caller.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program closure_cache.aleo;
+
+  closure twice:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+  closure check_caller:
+    input r0 as address;
+    is.eq r0 self.caller into r1;
+    output r1 as boolean;
+
+  function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    input r2 as address.private;
+    call twice r0 into r3;
+    call twice r0 into r4;
+    call twice r1 into r5;
+    call check_caller r2 into r6;
+    call check_caller r2 into r7;
+    output r3 as field.private;
+    output r4 as field.private;
+    output r5 as field.private;
+    output r6 as boolean.private;
+    output r7 as boolean.private;",
+    )
+    .unwrap();
+
+    // Ensure only the pure closure is marked as pure.
+    assert!(program.get_closure(&Identifier::from_str("twice").unwrap()).unwrap().is_pure());
+    assert!(!program.get_closure(&Identifier::from_str("check_caller").unwrap()).unwrap().is_pure());
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = super::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            Identifier::from_str("compute").unwrap(),
+            [r0, r1, r2].iter(),
+            rng,
+        )
+        .unwrap();
+
+    // Evaluate the function, keeping a handle on the call stack to inspect the closure cache.
+    let call_stack = CallStack::evaluate(authorization).unwrap();
+    let response =
+        process.get_stack(program.id()).unwrap().evaluate_function::<CurrentAleo>(call_stack.clone()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(5, candidate.len());
+    assert_eq!(Value::from_str("6field").unwrap(), candidate[0]);
+    assert_eq!(Value::from_str("6field").unwrap(), candidate[1]);
+    assert_eq!(Value::from_str("10field").unwrap(), candidate[2]);
+    assert_eq!(Value::from_str("true").unwrap(), candidate[3]);
+    assert_eq!(Value::from_str("true").unwrap(), candidate[4]);
+
+    // Ensure `twice` was evaluated once per distinct input, and `check_caller` was never cached.
+    let cache = call_stack.closure_cache().unwrap();
+    assert_eq!(2, cache.len());
+    assert_eq!(1, cache.num_hits());
+}
+
+#[test]
+fn test_process_evaluate_instruction_limit() {
+    // Initialize a new program, where `compute` evaluates 2 instructions and `quadruple` evaluates 2 more.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program instruction_limit.aleo;
+
+  closure quadruple:
+    input r0 as field;
+    add r0 r0 into r1;
+    add r1 r1 into r2;
+    output r2 as field;
+
+  function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    call quadruple r1 into r2;
+    output r2 as field.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Construct the process, and ensure it is unlimited by default.
+    let process = super::test_helpers::sample_process(&program);
+    assert_eq!(None, process.instruction_limit());
+
+    // Authorize the function call.
+    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            Identifier::from_str("compute").unwrap(),
+            [r0].iter(),
+            rng,
+        )
+        .unwrap();
+
+    // Ensure the count includes the instructions in the called closure.
+    let call_stack = CallStack::evaluate_with_limit(authorization.replicate(), None).unwrap();
+    process.get_stack(program.id()).unwrap().evaluate_function::<CurrentAleo>(call_stack.clone()).unwrap();
+    assert_eq!(4, call_stack.instruction_counter().unwrap().num_instructions());
+
+    // Ensure the function completes at the limit.
+    let process = process.with_instruction_limit(4);
+    assert_eq!(Some(4), process.instruction_limit());
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(Value::from_str("24field").unwrap(), response.outputs()[0]);
+
+    // Ensure the function halts over the limit, which is only reached inside the called closure.
+    let process = process.with_instruction_limit(3);
+    let error = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap_err();
+    assert!(error.to_string().contains("instruction limit exceeded"), "Unexpected error: {error}");
+}
+
+#[test]
+fn test_process_evaluate_counts_cached_closures() {
+    // Initialize a new program, where `repeat` calls `twice` on the same input, and `distinct` on different inputs.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program cached_instruction_count.aleo;
+
+  closure twice:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+  function repeat:
+    input r0 as field.private;
+    input r1 as field.private;
+    call twice r0 into r2;
+    call twice r0 into r3;
+    output r3 as field.private;
+
+  function distinct:
+    input r0 as field.private;
+    input r1 as field.private;
+    call twice r0 into r2;
+    call twice r1 into r3;
+    output r3 as field.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Construct the process.
+    let process = super::test_helpers::sample_process(&program);
+
+    // Evaluates the given function, and returns the number of cache hits and the number of instructions.
+    let mut evaluate = |function_name: &str| {
+        let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str(function_name).unwrap(),
+                [r0, r1].iter(),
+                rng,
+            )
+            .unwrap();
+        let call_stack = CallStack::evaluate_with_limit(authorization, None).unwrap();
+        process.get_stack(program.id()).unwrap().evaluate_function::<CurrentAleo>(call_stack.clone()).unwrap();
+        (call_stack.closure_cache().unwrap().num_hits(), call_stack.instruction_counter().unwrap().num_instructions())
+    };
+
+    // Ensure the second call to `twice` is a cache hit in `repeat`, and a cache miss in `distinct`.
+    let (repeat_hits, repeat_count) = evaluate("repeat");
+    let (distinct_hits, distinct_count) = evaluate("distinct");
+    assert_eq!(1, repeat_hits);
+    assert_eq!(0, distinct_hits);
+    // Ensure the cache hit counts the instructions of the closure, as the cache miss does.
+    assert_eq!(4, distinct_count);
+    assert_eq!(distinct_count, repeat_count);
+}
+
+#[test]
+fn test_process_evaluate_in_parallel() {
+    // Initialize a new program, where `compute` has two independent branches, and `halts` has two independent failures.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program parallel_evaluation.aleo;
+
+  function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    mul r0 r0 into r2;
+    mul r1 r1 into r3;
+    add r2 r0 into r4;
+    add r3 r1 into r5;
+    hash.bhp256 r4 into r6;
+    hash.bhp256 r5 into r7;
+    add r6 r7 into r8;
+    output r8 as field.private;
+    output r4 as field.private;
+
+  function halts:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    assert.eq r0 r1;
+    assert.neq r0 r0;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Construct the process, and ensure it evaluates serially by default.
+    let process = super::test_helpers::sample_process(&program);
+    assert_eq!(EvaluationMode::Serial, process.evaluation_mode());
+
+    // Authorize the function calls.
+    let mut authorize = |function_name: &str, inputs: [&str; 2]| {
+        let inputs = inputs.map(|input| Value::<CurrentNetwork>::from_str(input).unwrap());
+        process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str(function_name).unwrap(),
+                inputs.iter(),
+                rng,
+            )
+            .unwrap()
+    };
+    let compute = authorize("compute", ["3field", "5field"]);
+    let halts = authorize("halts", ["0u8", "5u8"]);
+
+    // Evaluate serially.
+    let expected = process.evaluate::<CurrentAleo>(compute.replicate()).unwrap();
+    let expected_error = process.evaluate::<CurrentAleo>(halts.replicate()).unwrap_err();
+    assert!(format!("{expected_error:#}").contains("assert.eq"), "Unexpected error: {expected_error:#}");
+
+    // Ensure the independent instructions are grouped together.
+    let function = program.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+    assert_eq!(vec![vec![0, 1], vec![2, 3], vec![4, 5], vec![6]], function.dependency_levels());
+
+    // Ensure parallel evaluation produces the same outputs, and counts every instruction.
+    let process = process.with_evaluation_mode(EvaluationMode::Parallel);
+    let candidate = process.evaluate::<CurrentAleo>(compute.replicate()).unwrap();
+    assert_eq!(expected.outputs(), candidate.outputs());
+    let call_stack = CallStack::evaluate(compute.replicate()).unwrap().with_evaluation_mode(EvaluationMode::Parallel);
+    process.get_stack(program.id()).unwrap().evaluate_function::<CurrentAleo>(call_stack.clone()).unwrap();
+    assert_eq!(7, call_stack.instruction_counter().unwrap().num_instructions());
+
+    // Ensure parallel evaluation fails on the first failing instruction in program order, as serial evaluation does.
+    let candidate_error = process.evaluate::<CurrentAleo>(halts.replicate()).unwrap_err();
+    assert_eq!(format!("{expected_error:#}"), format!("{candidate_error:#}"));
+}
+
+#[test]
+fn test_process_function_cost() {
+    // Initialize a new program, where `compute` calls the `hash_twice` closure.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program function_cost.aleo;
+
+  closure hash_twice:
+    input r0 as field;
+    hash.psd2 r0 into r1;
+    hash.psd2 r1 into r2;
+    output r2 as field;
+
+  function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    call hash_twice r1 into r2;
+    commit.bhp256 r0 1scalar into r3;
+    output r2 as field.private;
+    output r3 as field.private;",
+    )
+    .unwrap();
+
+    let closure = program.get_closure(&Identifier::from_str("hash_twice").unwrap()).unwrap();
+    let function = program.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+
+    // Ensure the local costs are the sum of the instruction costs.
+    assert_eq!(2 * closure.instructions()[0].cost(), closure.cost());
+    assert_eq!(function.instructions().iter().map(Instruction::cost).sum::<u64>(), function.cost());
+    // Ensure the hashes and the commitment cost more than the addition.
+    assert!(closure.cost() > function.instructions()[0].cost());
+    assert!(function.instructions()[2].cost() > function.instructions()[0].cost());
+
+    // Construct the process.
+    let process = super::test_helpers::sample_process(&program);
+
+    // Ensure the process cost includes the cost of the called closure.
+    let cost = process.get_function_cost(program.id(), "compute").unwrap();
+    assert_eq!(function.cost() + closure.cost(), cost);
+
+    // Ensure the process rejects closures and missing functions.
+    assert!(process.get_function_cost(program.id(), "hash_twice").is_err());
+    assert!(process.get_function_cost(program.id(), "missing").is_err());
+}
+
 #[test]
 fn test_process_execute_call_external_function() {
     // Initialize a new program.
@@ -1433,3 +1865,522 @@ finalize compute:
         .unwrap();
     assert_eq!(candidate, Value::from_str("{ count: 3u8, data: 6u8 }").unwrap());
 }
+
+#[test]
+fn test_process_dry_run() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program dry_run.aleo;
+
+  struct point:
+    x as u32;
+    y as u32;
+
+  function noop:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::process::test_helpers::sample_process(&program);
+
+    // Initialize a well-typed function.
+    let function = Function::<CurrentNetwork>::from_str(
+        r"
+function compute:
+    input r0 as point.private;
+    input r1 as field.public;
+    add r0.x r0.y into r2;
+    mul r1 r1 into r3;
+    is.eq r2 r0.x into r4;
+    cast r2 r2 into r5 as point;
+    output r5 as point.private;",
+    )
+    .unwrap();
+
+    // Ensure the inferred register types are correct.
+    let register_types = process.dry_run(program.id(), &function).unwrap();
+    let expected =
+        [("r0", "point"), ("r1", "field"), ("r2", "u32"), ("r3", "field"), ("r4", "boolean"), ("r5", "point")];
+    assert_eq!(register_types.len(), expected.len());
+    for (register, register_type) in expected {
+        let register = Register::from_str(register).unwrap();
+        assert_eq!(register_types.get(&register).unwrap().to_string(), register_type);
+    }
+
+    // Initialize a function that adds a field to a u8.
+    let function = Function::<CurrentNetwork>::from_str(
+        r"
+function mismatch:
+    input r0 as field.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+    )
+    .unwrap();
+
+    // Ensure the mismatched operands are rejected, naming the function and the instruction.
+    let error = process.dry_run(program.id(), &function).unwrap_err().to_string();
+    assert!(error.contains("Function 'mismatch' failed to type-check"), "Unexpected error: {error}");
+    assert!(error.contains("'add'"), "Unexpected error: {error}");
+}
+
+#[test]
+fn test_process_default_value() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program default_value.aleo;
+
+  struct mixed:
+    a as u8;
+    b as i64;
+    c as u128;
+    d as field;
+    e as group;
+
+  struct nested:
+    inner as mixed;
+    flag as boolean;
+
+  struct with_address:
+    user as address;
+
+  record token:
+    owner as address.private;
+    amount as u64.private;
+    id as field.public;
+    data as nested.constant;
+
+  function noop:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::process::test_helpers::sample_process(&program);
+    let stack = process.get_stack(program.id()).unwrap();
+
+    // Sample the owner.
+    let rng = &mut TestRng::default();
+    let owner = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+    // Ensure the default struct has every leaf set to zero.
+    let value = stack.default_value(&owner, &ValueType::from_str("nested.public").unwrap()).unwrap();
+    let expected = Value::<CurrentNetwork>::from_str(
+        "{ inner: { a: 0u8, b: 0i64, c: 0u128, d: 0field, e: 0group }, flag: false }",
+    )
+    .unwrap();
+    assert_eq!(value, expected);
+
+    // Ensure the default record carries the declared visibility for each entry.
+    let value = stack.default_value(&owner, &ValueType::from_str("token.record").unwrap()).unwrap();
+    let expected = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {owner}.private, amount: 0u64.private, id: 0field.public, data: {{ inner: {{ a: 0u8.constant, b: 0i64.constant, c: 0u128.constant, d: 0field.constant, e: 0group.constant }}, flag: false.constant }}, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+    assert_eq!(value, expected);
+
+    // Ensure types without a canonical default are rejected.
+    assert!(stack.default_value(&owner, &ValueType::from_str("address.private").unwrap()).is_err());
+    assert!(stack.default_value(&owner, &ValueType::from_str("with_address.private").unwrap()).is_err());
+}
+
+#[test]
+fn test_process_instruction_trace() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program trace.aleo;
+
+  function compute:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    mul r2 r0 into r3;
+    assert.eq r3 r1;
+    output r3 as u8.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::process::test_helpers::sample_process(&program);
+    let stack = process.get_stack(program.id()).unwrap();
+    let function = program.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+
+    // Declare the input values.
+    let r0 = Literal::<CurrentNetwork>::from_str("2u8").unwrap();
+    let r1 = Literal::<CurrentNetwork>::from_str("3u8").unwrap();
+    let inputs = [(&r0, None), (&r1, None)];
+
+    // Ensure registers without tracing do not record anything.
+    let mut registers = crate::program::test_helpers::sample_registers(stack, function.name(), &inputs).unwrap();
+    assert!(!registers.is_tracing());
+    for instruction in function.instructions().iter().take(2) {
+        instruction.evaluate(stack, &mut registers).unwrap();
+    }
+    assert!(registers.trace().is_none());
+
+    // Evaluate the instructions with tracing enabled, until the assertion halts.
+    let mut registers =
+        crate::program::test_helpers::sample_registers(stack, function.name(), &inputs).unwrap().with_tracing();
+    assert!(registers.is_tracing());
+    let results = function
+        .instructions()
+        .iter()
+        .map(|instruction| instruction.evaluate(stack, &mut registers))
+        .collect::<Vec<_>>();
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+    assert!(results[2].is_err());
+
+    // Ensure the trace records each instruction in order.
+    let value = |string: &str| Value::<CurrentNetwork>::from_str(string).unwrap();
+    let trace = registers.trace().unwrap();
+    assert_eq!(trace.len(), 3);
+    assert_eq!(trace[0], TraceEntry {
+        opcode: Opcode::Literal("add"),
+        operands: vec![value("2u8"), value("3u8")],
+        destinations: vec![value("5u8")],
+    });
+    assert_eq!(trace[1], TraceEntry {
+        opcode: Opcode::Literal("mul"),
+        operands: vec![value("5u8"), value("2u8")],
+        destinations: vec![value("10u8")],
+    });
+    // Ensure the halting instruction recorded its inputs, without any destinations.
+    assert_eq!(trace[2], TraceEntry {
+        opcode: Opcode::Assert("assert.eq"),
+        operands: vec![value("10u8"), value("3u8")],
+        destinations: vec![],
+    });
+}
+
+#[test]
+fn test_process_registers_snapshot_and_restore() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program snapshot.aleo;
+
+  function compute:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    mul r2 r0 into r3;
+    output r3 as u8.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::process::test_helpers::sample_process(&program);
+    let stack = process.get_stack(program.id()).unwrap();
+    let function = program.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+    let instructions = function.instructions();
+
+    // Initialize the registers.
+    let mut registers = crate::program::test_helpers::sample_registers(stack, function.name(), &[]).unwrap();
+    let value = |string: &str| Value::<CurrentNetwork>::from_str(string).unwrap();
+    let load = |registers: &Registers<CurrentNetwork, CurrentAleo>, locator: u64| {
+        registers.load(stack, &Operand::Register(Register::Locator(locator)))
+    };
+
+    // Store the first input, and take a snapshot.
+    registers.store(stack, &Register::Locator(0), value("2u8")).unwrap();
+    let first = registers.snapshot();
+
+    // Store the second input, evaluate the addition, and take another snapshot.
+    registers.store(stack, &Register::Locator(1), value("3u8")).unwrap();
+    instructions[0].evaluate(stack, &mut registers).unwrap();
+    let second = registers.snapshot();
+    assert_ne!(first, second);
+
+    // Evaluate the multiplication, and roll it back.
+    instructions[1].evaluate(stack, &mut registers).unwrap();
+    assert_eq!(load(&registers, 3).unwrap(), value("10u8"));
+    registers.restore(second).unwrap();
+    assert_eq!(registers.snapshot(), second);
+
+    // Ensure the earlier values are intact, and the later register is undefined again.
+    assert_eq!(load(&registers, 0).unwrap(), value("2u8"));
+    assert_eq!(load(&registers, 1).unwrap(), value("3u8"));
+    assert_eq!(load(&registers, 2).unwrap(), value("5u8"));
+    assert!(load(&registers, 3).is_err());
+
+    // Ensure the rolled-back instruction can be evaluated again.
+    instructions[1].evaluate(stack, &mut registers).unwrap();
+    assert_eq!(load(&registers, 3).unwrap(), value("10u8"));
+
+    // Roll back to the first snapshot, and ensure only the first input remains.
+    registers.restore(first).unwrap();
+    assert_eq!(load(&registers, 0).unwrap(), value("2u8"));
+    for locator in 1..4 {
+        assert!(load(&registers, locator).is_err());
+    }
+
+    // Ensure a new value can be assigned to a register that was undefined by the restore.
+    registers.store(stack, &Register::Locator(1), value("7u8")).unwrap();
+    assert_eq!(load(&registers, 1).unwrap(), value("7u8"));
+
+    // Ensure a snapshot that is ahead of the registers cannot be restored.
+    assert!(registers.restore(second).is_err());
+}
+
+#[test]
+fn test_process_optimized_function_is_equivalent() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program original.aleo;
+
+  function compute:
+    input r0 as u8.private;
+    input r1 as field.public;
+    add 1u8 2u8 into r2;
+    mul r0 r2 into r3;
+    mul 2field 3field into r4;
+    add r1 r4 into r5;
+    sub r2 1u8 into r6;
+    output r3 as u8.private;
+    output r5 as field.private;
+    output r6 as u8.private;",
+    )
+    .unwrap();
+
+    // Optimize the function, and construct a program with it.
+    let function = program.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+    let optimized = function.optimize(true).unwrap();
+    assert_eq!(optimized.instructions().len(), 2);
+
+    // Ensure the function is unchanged, down to its bytes, if the pass is disabled.
+    let unoptimized = function.optimize(false).unwrap();
+    assert_eq!(function.to_bytes_le().unwrap(), unoptimized.to_bytes_le().unwrap());
+    assert_eq!(function.to_string(), unoptimized.to_string());
+    let optimized = Program::<CurrentNetwork>::from_str(&format!("program optimized.aleo;\n\n{optimized}")).unwrap();
+
+    // Construct the process.
+    let mut process = super::test_helpers::sample_process(&program);
+    process.add_program(&optimized).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Evaluates the function in the given program on the given inputs, and returns the outputs.
+    let mut evaluate = |program: &Program<CurrentNetwork>, inputs: &[&str]| {
+        let inputs = inputs.iter().map(|input| Value::<CurrentNetwork>::from_str(input).unwrap()).collect::<Vec<_>>();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str("compute").unwrap(),
+                inputs.iter(),
+                rng,
+            )
+            .unwrap();
+        process.evaluate::<CurrentAleo>(authorization).map(|response| response.outputs().to_vec())
+    };
+
+    // Ensure both functions produce the same outputs.
+    for inputs in [["0u8", "0field"], ["7u8", "5field"], ["85u8", "6field"]] {
+        let expected = evaluate(&program, &inputs).unwrap();
+        assert_eq!(expected, evaluate(&optimized, &inputs).unwrap());
+    }
+    // Ensure both functions halt on the same inputs.
+    assert!(evaluate(&program, &["86u8", "1field"]).is_err());
+    assert!(evaluate(&optimized, &["86u8", "1field"]).is_err());
+}
+
+#[test]
+fn test_process_program_registry() {
+    // Initialize two programs.
+    let program_0 = Program::<CurrentNetwork>::from_str(
+        r"program registry_0.aleo;
+
+  function noop:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+    )
+    .unwrap();
+    let program_1 = Program::<CurrentNetwork>::from_str(
+        r"program registry_1.aleo;
+
+  function noop:
+    input r0 as u16.private;
+    output r0 as u16.private;",
+    )
+    .unwrap();
+
+    // Register both programs.
+    let mut process = Process::load().unwrap();
+    assert!(!process.contains_program(program_0.id()));
+    process.add_program(&program_0).unwrap();
+    process.add_program(&program_1).unwrap();
+
+    // Ensure each program resolves to itself by its ID.
+    assert!(process.contains_program(program_0.id()));
+    assert!(process.contains_program(program_1.id()));
+    assert_eq!(process.get_program(program_0.id()).unwrap(), &program_0);
+    assert_eq!(process.get_program("registry_1.aleo").unwrap(), &program_1);
+    assert_eq!(process.get_stack(program_1.id()).unwrap().program_id(), program_1.id());
+
+    // Ensure an unknown or malformed ID does not resolve.
+    assert!(process.get_program("registry_2.aleo").is_err());
+    assert!(process.get_program("registry_2").is_err());
+
+    // Ensure a duplicate registration is rejected, and leaves the existing program in place.
+    assert!(process.add_program(&program_0).is_err());
+    assert_eq!(process.get_program(program_0.id()).unwrap(), &program_0);
+}
+
+#[test]
+fn test_process_rejects_invalid_calls() {
+    // Returns the result of adding the given program to a process.
+    let add_program = |program: &str| {
+        let program = Program::<CurrentNetwork>::from_str(program).unwrap();
+        Process::load().unwrap().add_program(&program)
+    };
+
+    // Ensure a chain of closure calls is accepted.
+    let result = add_program(
+        r"program call_chain.aleo;
+
+  closure first:
+    input r0 as u8;
+    call second r0 into r1;
+    output r1 as u8;
+
+  closure second:
+    input r0 as u8;
+    add r0 r0 into r1;
+    output r1 as u8;
+
+  function compute:
+    input r0 as u8.private;
+    call first r0 into r1;
+    output r1 as u8.private;",
+    );
+    assert!(result.is_ok());
+
+    // Ensure a call with the wrong number of arguments is rejected.
+    let result = add_program(
+        r"program call_arity.aleo;
+
+  closure double:
+    input r0 as u8;
+    add r0 r0 into r1;
+    output r1 as u8;
+
+  function compute:
+    input r0 as u8.private;
+    call double r0 r0 into r1;
+    output r1 as u8.private;",
+    );
+    assert!(result.is_err());
+
+    // Ensure a direct recursive call is rejected.
+    let result = add_program(
+        r"program call_self.aleo;
+
+  closure recurse:
+    input r0 as u8;
+    call recurse r0 into r1;
+    output r1 as u8;
+
+  function compute:
+    input r0 as u8.private;
+    call recurse r0 into r1;
+    output r1 as u8.private;",
+    );
+    assert!(result.unwrap_err().to_string().contains("self-recursive call"));
+
+    // Ensure an indirect recursive call is rejected.
+    let result = add_program(
+        r"program call_cycle.aleo;
+
+  closure ping:
+    input r0 as u8;
+    call pong r0 into r1;
+    output r1 as u8;
+
+  closure pong:
+    input r0 as u8;
+    call ping r0 into r1;
+    output r1 as u8;
+
+  function compute:
+    input r0 as u8.private;
+    call ping r0 into r1;
+    output r1 as u8.private;",
+    );
+    assert!(result.unwrap_err().to_string().contains("recursive call cycle"));
+}
+
+#[test]
+fn test_process_evaluate_instruction_errors() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program instruction_errors.aleo;
+
+  function overflow:
+    input r0 as u8.private;
+    add r0 r0 into r1;
+    output r1 as u8.private;
+
+  function divide_by_zero:
+    input r0 as u8.private;
+    div r0 0u8 into r1;
+    output r1 as u8.private;
+
+  function too_large:
+    input r0 as u128.private;
+    hash.ped64 r0 into r1;
+    output r1 as field.private;
+
+  function not_equal:
+    input r0 as u8.private;
+    assert.eq r0 0u8;
+    output r0 as u8.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = super::test_helpers::sample_process(&program);
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Evaluates the given function on the given input, and returns the error.
+    let mut evaluate = |function_name: &str, input: &str| {
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str(function_name).unwrap(),
+                [Value::<CurrentNetwork>::from_str(input).unwrap()].iter(),
+                rng,
+            )
+            .unwrap();
+        process.evaluate::<CurrentAleo>(authorization).unwrap_err()
+    };
+
+    // Ensure an overflow is surfaced as such, with the original message.
+    let error = evaluate("overflow", "200u8");
+    assert!(matches!(error.downcast_ref::<InstructionError>(), Some(InstructionError::Overflow(..))));
+    assert!(format!("{error:#}").contains("Integer addition failed on: 200u8 and 200u8"));
+    // Ensure the message includes the exceeded bound.
+    assert!(format!("{error:#}").contains("(200u8 + 200u8 exceeds u8::MAX)"));
+
+    // Ensure a division by zero is surfaced as such.
+    let error = evaluate("divide_by_zero", "7u8");
+    assert!(matches!(error.downcast_ref::<InstructionError>(), Some(InstructionError::DivisionByZero(..))));
+
+    // Ensure an oversized Pedersen input is surfaced as such.
+    let error = evaluate("too_large", "1u128");
+    assert!(matches!(error.downcast_ref::<InstructionError>(), Some(InstructionError::InputTooLarge(..))));
+
+    // Ensure a failed assertion is surfaced as a halt.
+    let error = evaluate("not_equal", "1u8");
+    assert!(matches!(error.downcast_ref::<InstructionError>(), Some(InstructionError::Halt(..))));
+}
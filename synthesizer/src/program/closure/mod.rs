@@ -21,7 +21,7 @@ use output::*;
 mod bytes;
 mod parse;
 
-use crate::Instruction;
+use crate::{Instruction, Operand};
 use console::{
     network::prelude::*,
     program::{Identifier, Register, RegisterType},
@@ -63,10 +63,24 @@ impl<N: Network> Closure<N> {
         &self.instructions
     }
 
+    /// Returns the estimated cost of the closure instructions, excluding the cost of any callee.
+    pub fn cost(&self) -> u64 {
+        self.instructions.iter().map(Instruction::cost).sum()
+    }
+
     /// Returns the closure outputs.
     pub const fn outputs(&self) -> &IndexSet<Output<N>> {
         &self.outputs
     }
+
+    /// Returns `true` if the closure outputs depend only on its inputs.
+    /// A closure is impure if it performs a call or reads the caller.
+    pub fn is_pure(&self) -> bool {
+        let is_call = |instruction: &Instruction<N>| matches!(instruction, Instruction::Call(..));
+        let reads_caller = |instruction: &Instruction<N>| instruction.operands().contains(&Operand::Caller);
+        !self.instructions.iter().any(|instruction| is_call(instruction) || reads_caller(instruction))
+            && !self.outputs.iter().any(|output| matches!(output.operand(), Operand::Caller))
+    }
 }
 
 impl<N: Network> Closure<N> {
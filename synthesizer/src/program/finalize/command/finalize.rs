@@ -53,6 +53,12 @@ impl<N: Network, const VARIANT: u8> FinalizeOperation<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![]
     }
+
+    /// Returns the command with each operand rewritten by the given function.
+    #[inline]
+    pub(crate) fn map_operands(&self, operand: impl Fn(&Operand<N>) -> Result<Operand<N>>) -> Result<Self> {
+        Ok(Self { operands: self.operands.iter().map(operand).collect::<Result<_>>()? })
+    }
 }
 
 impl<N: Network, const VARIANT: u8> FinalizeOperation<N, VARIANT> {
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Operand;
+
+use indexmap::IndexMap;
+
+impl<N: Network> Function<N> {
+    /// Returns the indices of the instructions that each instruction directly depends on, in program order.
+    /// An instruction depends on the instructions that assign the registers it reads, and a `call`
+    /// also depends on the preceding `call`, as calls consume requests from the call stack in order.
+    pub fn dependencies(&self) -> Vec<Vec<usize>> {
+        // The mapping of register locators to the index of the instruction that assigns them.
+        let mut assignments = IndexMap::<u64, usize>::new();
+        // The index of the preceding `call` instruction, if any.
+        let mut previous_call = None;
+
+        let mut dependencies = Vec::with_capacity(self.instructions.len());
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            let mut indices = instruction
+                .operands()
+                .iter()
+                .filter_map(|operand| match operand {
+                    Operand::Register(register) => assignments.get(&register.locator()).copied(),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if let Instruction::Call(..) = instruction {
+                indices.extend(previous_call.replace(index));
+            }
+            indices.sort_unstable();
+            indices.dedup();
+            dependencies.push(indices);
+
+            // Record the destinations of the instruction.
+            for destination in instruction.destinations() {
+                assignments.insert(destination.locator(), index);
+            }
+        }
+        dependencies
+    }
+
+    /// Returns the indices of the instructions grouped into levels, such that every instruction
+    /// only depends on instructions in earlier levels. The instructions within a level are
+    /// independent of one another, and are listed in program order.
+    pub fn dependency_levels(&self) -> Vec<Vec<usize>> {
+        let mut depths = Vec::<usize>::with_capacity(self.instructions.len());
+        let mut levels = Vec::<Vec<usize>>::new();
+        for (index, dependencies) in self.dependencies().into_iter().enumerate() {
+            // The depth of an instruction is one more than the deepest instruction it depends on.
+            let depth = dependencies.iter().map(|dependency| depths[*dependency] + 1).max().unwrap_or(0);
+            depths.push(depth);
+            match levels.get_mut(depth) {
+                Some(level) => level.push(index),
+                None => levels.push(vec![index]),
+            }
+        }
+        levels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Program;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_dependency_levels() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program dependencies.aleo;
+
+  closure double:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+  function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r0 into r2;
+    mul r1 r1 into r3;
+    add r2 r3 into r4;
+    call double r0 into r5;
+    call double r1 into r6;
+    sub r4 1field into r7;
+    output r7 as field.private;",
+        )?;
+        let function = program.get_function(&Identifier::from_str("compute")?)?;
+
+        assert_eq!(function.dependencies(), vec![vec![], vec![], vec![0, 1], vec![], vec![3], vec![2]]);
+        assert_eq!(function.dependency_levels(), vec![vec![0, 1, 3], vec![2, 4], vec![5]]);
+        Ok(())
+    }
+}
@@ -19,6 +19,8 @@ mod output;
 use output::*;
 
 mod bytes;
+mod dependencies;
+mod optimize;
 mod parse;
 
 use crate::{
@@ -73,6 +75,11 @@ impl<N: Network> Function<N> {
         &self.instructions
     }
 
+    /// Returns the estimated cost of the function instructions, excluding the cost of any callee.
+    pub fn cost(&self) -> u64 {
+        self.instructions.iter().map(Instruction::cost).sum()
+    }
+
     /// Returns the function outputs.
     pub const fn outputs(&self) -> &IndexSet<Output<N>> {
         &self.outputs
@@ -0,0 +1,260 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Operand;
+use console::program::Literal;
+
+use indexmap::IndexMap;
+
+impl<N: Network> Function<N> {
+    /// Returns an equivalent function, in which every literal operation on literal operands is precomputed.
+    /// The result of a folded instruction is substituted as a literal into every operand that reads it,
+    /// after which the folded instruction is removed and the remaining registers are renumbered in order.
+    /// Note: This pass is never applied implicitly, as it changes the instructions (and cost) of the function.
+    /// Callers that depend on the exact instructions of the function may opt out with `is_enabled` set to `false`,
+    /// in which case the function is returned unchanged.
+    pub fn optimize(&self, is_enabled: bool) -> Result<Self> {
+        // If the pass is disabled, return the function unchanged.
+        if !is_enabled {
+            return Ok(self.clone());
+        }
+
+        // Fold the instructions in order, recording the literal assigned to each folded register.
+        let mut constants = IndexMap::<u64, Literal<N>>::new();
+        let mut instructions = Vec::with_capacity(self.instructions.len());
+        for instruction in &self.instructions {
+            // Substitute the folded registers with their literals.
+            let instruction = instruction
+                .map_registers(|operand| Ok(substitute(&constants, operand)), |register| Ok(register.clone()))?;
+            // Fold the instruction, if its operands are all literals.
+            let is_folded = match (instruction.fold(), instruction.destinations().as_slice()) {
+                (Some(literal), [Register::Locator(locator)]) => {
+                    constants.insert(*locator, literal);
+                    true
+                }
+                _ => false,
+            };
+            instructions.push((instruction, is_folded));
+        }
+
+        // Substitute the folded registers in the outputs.
+        let substituted = self
+            .outputs
+            .iter()
+            .map(|output| output.map_operand(|operand| Ok(substitute(&constants, operand))))
+            .collect::<Result<Vec<_>>>()?;
+        // Note: As output statements must be distinct, a folded output that would duplicate another keeps its register.
+        let mut live = IndexSet::new();
+        let mut outputs = IndexSet::with_capacity(self.outputs.len());
+        for (output, candidate) in self.outputs.iter().zip_eq(&substituted) {
+            let is_duplicate = substituted.iter().filter(|other| *other == candidate).count() > 1;
+            match (output != candidate && is_duplicate, output.operand()) {
+                (true, Operand::Register(register)) => {
+                    live.insert(register.locator());
+                    outputs.insert(output.clone());
+                }
+                _ => {
+                    outputs.insert(candidate.clone());
+                }
+            }
+        }
+
+        // Substitute the folded registers in the finalize command.
+        let finalize = match &self.finalize {
+            Some((command, finalize)) => {
+                Some((command.map_operands(|operand| Ok(substitute(&constants, operand)))?, finalize.clone()))
+            }
+            None => None,
+        };
+
+        // Renumber the registers in order, removing the folded instructions that are no longer read.
+        let mut locators: IndexMap<u64, u64> =
+            self.inputs.iter().map(|input| (input.register().locator(), input.register().locator())).collect();
+        let mut optimized = Vec::with_capacity(instructions.len());
+        for (instruction, is_folded) in instructions {
+            // Remove the folded instruction, if its register is not live.
+            if is_folded && instruction.destinations().iter().all(|register| !live.contains(&register.locator())) {
+                continue;
+            }
+            // Renumber the operands.
+            let instruction = instruction
+                .map_registers(|operand| renumber_operand(&locators, operand), |register| Ok(register.clone()))?;
+            // Assign the next locators to the destinations.
+            for register in instruction.destinations() {
+                let next = locators.len() as u64;
+                locators.insert(register.locator(), next);
+            }
+            // Renumber the destinations.
+            optimized.push(
+                instruction.map_registers(|operand| Ok(operand.clone()), |register| renumber(&locators, register))?,
+            );
+        }
+
+        // Renumber the outputs and the finalize command.
+        let outputs = outputs
+            .iter()
+            .map(|output| output.map_operand(|operand| renumber_operand(&locators, operand)))
+            .collect::<Result<_>>()?;
+        let finalize = match finalize {
+            Some((command, finalize)) => {
+                Some((command.map_operands(|operand| renumber_operand(&locators, operand))?, finalize))
+            }
+            None => None,
+        };
+
+        Ok(Self { name: self.name, inputs: self.inputs.clone(), instructions: optimized, outputs, finalize })
+    }
+}
+
+/// Returns the operand, with a folded register substituted by its literal.
+fn substitute<N: Network>(constants: &IndexMap<u64, Literal<N>>, operand: &Operand<N>) -> Operand<N> {
+    match operand {
+        Operand::Register(Register::Locator(locator)) => match constants.get(locator) {
+            Some(literal) => Operand::Literal(literal.clone()),
+            None => operand.clone(),
+        },
+        _ => operand.clone(),
+    }
+}
+
+/// Returns the register, with its locator renumbered by the given mapping.
+fn renumber<N: Network>(locators: &IndexMap<u64, u64>, register: &Register<N>) -> Result<Register<N>> {
+    match (locators.get(&register.locator()), register) {
+        (Some(locator), Register::Locator(..)) => Ok(Register::Locator(*locator)),
+        (Some(locator), Register::Member(_, path)) => Ok(Register::Member(*locator, path.clone())),
+        (None, _) => bail!("Register '{register}' is read before it is assigned"),
+    }
+}
+
+/// Returns the operand, with its register renumbered by the given mapping.
+fn renumber_operand<N: Network>(locators: &IndexMap<u64, u64>, operand: &Operand<N>) -> Result<Operand<N>> {
+    match operand {
+        Operand::Register(register) => Ok(Operand::Register(renumber(locators, register)?)),
+        _ => Ok(operand.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_optimize(function: &str, expected: &str) {
+        let function = Function::<CurrentNetwork>::from_str(function).unwrap();
+        let expected = Function::<CurrentNetwork>::from_str(expected).unwrap();
+        let candidate = function.optimize().unwrap();
+        assert_eq!(expected, candidate, "\nExpected:\n{expected}\n\nFound:\n{candidate}\n");
+        // Ensure the optimization is idempotent.
+        assert_eq!(candidate, candidate.optimize().unwrap());
+    }
+
+    #[test]
+    fn test_optimize_folds_constant_chain() {
+        check_optimize(
+            r"
+function compute:
+    add 1u8 2u8 into r0;
+    mul r0 3u8 into r1;
+    sub r1 4u8 into r2;
+    output r2 as u8.private;",
+            r"
+function compute:
+    output 5u8 as u8.private;",
+        );
+    }
+
+    #[test]
+    fn test_optimize_keeps_input_dependent_instructions() {
+        let function = r"
+function compute:
+    input r0 as u8.private;
+    input r1 as field.public;
+    add r0 1u8 into r2;
+    mul r1 r1 into r3;
+    hash.bhp256 2field into r4;
+    is.eq r2 3u8 into r5;
+    output r2 as u8.private;
+    output r3 as field.private;
+    output r4 as field.private;
+    output r5 as boolean.private;";
+        check_optimize(function, function);
+    }
+
+    #[test]
+    fn test_optimize_removes_dead_registers() {
+        check_optimize(
+            r"
+function compute:
+    input r0 as u8.private;
+    add 1u8 2u8 into r1;
+    add r0 r1 into r2;
+    mul 2u8 r1 into r3;
+    sub r2 r3 into r4;
+    assert.neq r4 r1;
+    output r4 as u8.private;",
+            r"
+function compute:
+    input r0 as u8.private;
+    add r0 3u8 into r1;
+    sub r1 6u8 into r2;
+    assert.neq r2 3u8;
+    output r2 as u8.private;",
+        );
+    }
+
+    #[test]
+    fn test_optimize_keeps_halting_instructions() {
+        // Ensure an overflowing operation is not folded, so that it still halts.
+        check_optimize(
+            r"
+function compute:
+    add 200u8 100u8 into r0;
+    div 1u8 0u8 into r1;
+    output r0 as u8.private;
+    output r1 as u8.private;",
+            r"
+function compute:
+    add 200u8 100u8 into r0;
+    div 1u8 0u8 into r1;
+    output r0 as u8.private;
+    output r1 as u8.private;",
+        );
+    }
+
+    #[test]
+    fn test_optimize_keeps_distinct_outputs() {
+        // Ensure a folded output that would duplicate another output keeps its register.
+        check_optimize(
+            r"
+function compute:
+    input r0 as u8.private;
+    add 1u8 2u8 into r1;
+    mul r0 r1 into r2;
+    output r2 as u8.private;
+    output r1 as u8.private;
+    output 3u8 as u8.private;",
+            r"
+function compute:
+    input r0 as u8.private;
+    add 1u8 2u8 into r1;
+    mul r0 3u8 into r2;
+    output r2 as u8.private;
+    output r1 as u8.private;
+    output 3u8 as u8.private;",
+        );
+    }
+}
@@ -41,6 +41,12 @@ impl<N: Network> Output<N> {
     pub const fn value_type(&self) -> &ValueType<N> {
         &self.value_type
     }
+
+    /// Returns the output statement with its operand rewritten by the given function.
+    #[inline]
+    pub(super) fn map_operand(&self, operand: impl Fn(&Operand<N>) -> Result<Operand<N>>) -> Result<Self> {
+        Ok(Self { operand: operand(&self.operand)?, value_type: self.value_type })
+    }
 }
 
 impl<N: Network> TypeName for Output<N> {
@@ -85,12 +85,11 @@ impl<N: Network> FromStr for Function<N> {
     /// Returns a function from a string literal.
     fn from_str(string: &str) -> Result<Self> {
         match Self::parse(string) {
-            Ok((remainder, object)) => {
-                // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
-                // Return the object.
-                Ok(object)
-            }
+            // Ensure the remainder is empty.
+            Ok((remainder, object)) => match remainder.is_empty() {
+                true => Ok(object),
+                false => Err(Instruction::<N>::parse_error(ParseError::new(string, remainder))),
+            },
             Err(error) => bail!("Failed to parse string. {error}"),
         }
     }
@@ -93,4 +93,219 @@ mod tests {
         assert_eq!(expected_bytes, candidate.to_bytes_le()?);
         Ok(())
     }
+
+    #[test]
+    fn test_ternary_bytes() -> Result<()> {
+        let expected = Instruction::<CurrentNetwork>::from_str("ternary r0 r1 r2 into r3;")?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_zero_bytes() -> Result<()> {
+        let expected = Instruction::<CurrentNetwork>::from_str("is.zero r0 into r1;")?;
+        assert!(matches!(expected, Instruction::IsZero(..)));
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_ones_and_zeros_bytes() -> Result<()> {
+        let expected = Instruction::<CurrentNetwork>::from_str("popcount r0 into r1;")?;
+        assert!(matches!(expected, Instruction::CountOnes(..)));
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?);
+
+        let expected = Instruction::<CurrentNetwork>::from_str("popcount.zero r0 into r1;")?;
+        assert!(matches!(expected, Instruction::CountZeros(..)));
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_leading_and_trailing_zeros_bytes() -> Result<()> {
+        let expected = Instruction::<CurrentNetwork>::from_str("clz r0 into r1;")?;
+        assert!(matches!(expected, Instruction::LeadingZeros(..)));
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?);
+
+        let expected = Instruction::<CurrentNetwork>::from_str("ctz r0 into r1;")?;
+        assert!(matches!(expected, Instruction::TrailingZeros(..)));
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_double_bytes() -> Result<()> {
+        let expected = Instruction::<CurrentNetwork>::from_str("double r0 into r1;")?;
+        assert!(matches!(expected, Instruction::Double(..)));
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_owner_bytes() -> Result<()> {
+        let expected = Instruction::<CurrentNetwork>::from_str("is.owner r0 r1 into r2;")?;
+        assert!(matches!(expected, Instruction::IsOwner(..)));
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mod_pow_bytes() -> Result<()> {
+        let expected = Instruction::<CurrentNetwork>::from_str("modpow r0 r1 into r2;")?;
+        assert!(matches!(expected, Instruction::ModPow(..)));
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        // Ensure the `mod` opcode is not confused with `modpow`.
+        assert!(matches!(Instruction::<CurrentNetwork>::from_str("mod r0 r1 into r2;")?, Instruction::Modulo(..)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_bytes_bytes() -> Result<()> {
+        let bytes = (1..=32).map(|index| format!("r{index}")).collect::<Vec<_>>().join(" ");
+        let to_bytes = Instruction::<CurrentNetwork>::from_str(&format!("cast.to_bytes r0 into {bytes};"))?;
+        assert!(matches!(to_bytes, Instruction::ToBytesLe(..)));
+        let from_bytes = Instruction::<CurrentNetwork>::from_str(&format!("cast.from_bytes {bytes} into r33;"))?;
+        assert!(matches!(from_bytes, Instruction::FromBytesLe(..)));
+
+        for expected in [to_bytes, from_bytes] {
+            let expected_bytes = expected.to_bytes_le()?;
+            let candidate = Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+            assert_eq!(expected, candidate);
+            assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_logic_bytes() -> Result<()> {
+        for instruction in [
+            "and r0 r1 into r2;",
+            "or r0 r1 into r2;",
+            "xor r0 r1 into r2;",
+            "nand r0 r1 into r2;",
+            "nor r0 r1 into r2;",
+            "not r0 into r1;",
+        ] {
+            let expected = Instruction::<CurrentNetwork>::from_str(instruction)?;
+            assert_eq!(instruction, expected.to_string());
+            let expected_bytes = expected.to_bytes_le()?;
+
+            let candidate = Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+            assert_eq!(expected, candidate);
+            assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_square_root_bytes() -> Result<()> {
+        let expected = Instruction::<CurrentNetwork>::from_str("sqrt r0 into r1;")?;
+        assert!(matches!(expected, Instruction::SquareRoot(..)));
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?);
+
+        let expected = Instruction::<CurrentNetwork>::from_str("sqrt.canonical r0 into r1;")?;
+        assert!(matches!(expected, Instruction::SquareRootCanonical(..)));
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_opcode_indices() -> Result<()> {
+        // Note: The opcode index is serialized, so the index of an existing opcode must never change.
+        let expected = [
+            "abs",
+            "abs.w",
+            "add",
+            "add.w",
+            "and",
+            "assert.eq",
+            "assert.neq",
+            "call",
+            "cast",
+            "commit.bhp256",
+            "commit.bhp512",
+            "commit.bhp768",
+            "commit.bhp1024",
+            "commit.ped64",
+            "commit.ped128",
+            "div",
+            "div.w",
+            "double",
+            "gt",
+            "gte",
+            "hash.bhp256",
+            "hash.bhp512",
+            "hash.bhp768",
+            "hash.bhp1024",
+            "hash.ped64",
+            "hash.ped128",
+            "hash.psd2",
+            "hash.psd4",
+            "hash.psd8",
+            "inv",
+            "is.eq",
+            "is.neq",
+            "lt",
+            "lte",
+            "mod",
+            "mul",
+            "mul.w",
+            "nand",
+            "neg",
+            "nor",
+            "not",
+            "or",
+            "pow",
+            "pow.w",
+            "rem",
+            "rem.w",
+            "shl",
+            "shl.w",
+            "shr",
+            "shr.w",
+            "square",
+            "sqrt",
+            "sub",
+            "sub.w",
+            "ternary",
+            "xor",
+        ];
+        for (index, opcode) in expected.iter().enumerate() {
+            assert_eq!(*opcode, *Instruction::<CurrentNetwork>::OPCODES[index], "Opcode index {index} changed");
+        }
+
+        // Ensure the serialized instruction begins with the opcode index.
+        let instruction = Instruction::<CurrentNetwork>::from_str("add r0 r1 into r2;")?;
+        assert_eq!(2u16.to_le_bytes(), instruction.to_bytes_le()?[..2]);
+        let instruction = Instruction::<CurrentNetwork>::from_str("xor r0 r1 into r2;")?;
+        assert_eq!(55u16.to_le_bytes(), instruction.to_bytes_le()?[..2]);
+        Ok(())
+    }
 }
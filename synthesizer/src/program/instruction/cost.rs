@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Instruction;
+use console::network::Network;
+
+impl<N: Network> Instruction<N> {
+    /// Returns the estimated cost of the instruction, excluding the cost of any callee.
+    /// The costs are relative weights, which roughly track the number of constraints for each instruction.
+    pub fn cost(&self) -> u64 {
+        match self {
+            // Operations that amount to a single addition or comparison.
+            Self::Abs(..)
+            | Self::AbsWrapped(..)
+            | Self::Add(..)
+            | Self::AddWrapped(..)
+            | Self::And(..)
+            | Self::AssertEq(..)
+            | Self::AssertNeq(..)
+            | Self::Call(..)
+            | Self::CountLeadingSignBits(..)
+            | Self::CountOnes(..)
+            | Self::CountZeros(..)
+            | Self::Double(..)
+            | Self::GetBit(..)
+            | Self::GreaterThan(..)
+            | Self::GreaterThanOrEqual(..)
+            | Self::IsEq(..)
+            | Self::IsNeq(..)
+            | Self::IsOwner(..)
+            | Self::IsZero(..)
+            | Self::LeadingZeros(..)
+            | Self::LessThan(..)
+            | Self::LessThanOrEqual(..)
+            | Self::Nand(..)
+            | Self::Neg(..)
+            | Self::NegWrapped(..)
+            | Self::Nor(..)
+            | Self::Not(..)
+            | Self::Or(..)
+            | Self::Shl(..)
+            | Self::ShlWrapped(..)
+            | Self::Shr(..)
+            | Self::ShrWrapped(..)
+            | Self::Sub(..)
+            | Self::SubWrapped(..)
+            | Self::SwapBytes(..)
+            | Self::Ternary(..)
+            | Self::TrailingZeros(..)
+            | Self::Xor(..) => 1,
+            // Integer and field operations beyond a single addition.
+            Self::Cast(..) | Self::CastLossy(..) | Self::Mul(..) | Self::MulWrapped(..) | Self::Square(..) => 2,
+            Self::Div(..)
+            | Self::DivRem(..)
+            | Self::DivWrapped(..)
+            | Self::Inv(..)
+            | Self::Modulo(..)
+            | Self::Rem(..)
+            | Self::RemWrapped(..)
+            | Self::SquareRoot(..)
+            | Self::SquareRootCanonical(..) => 8,
+            Self::ModPow(..) | Self::Pow(..) | Self::PowWrapped(..) => 16,
+            Self::Gcd(..) => 64,
+            // Field byte encodings, which decompose the field into bits.
+            Self::FromBytesLe(..) | Self::ToBytesLe(..) => 256,
+            // Pedersen commitments and hashes.
+            Self::CommitPED64(..) | Self::HashPED64(..) => 64,
+            Self::CommitPED128(..) | Self::HashPED128(..) => 128,
+            // Poseidon hashes.
+            Self::HashPSD2(..) => 96,
+            Self::HashPSD4(..) => 160,
+            Self::HashPSD8(..) => 288,
+            Self::HashToGroup(..) => 192,
+            Self::HashToScalar(..) => 128,
+            // BHP commitments and hashes.
+            Self::CommitBHP256(..) | Self::HashBHP256(..) => 256,
+            Self::CommitBHP512(..) | Self::HashBHP512(..) => 512,
+            Self::CommitBHP768(..) | Self::HashBHP768(..) => 768,
+            Self::CommitBHP1024(..) | Self::HashBHP1024(..) => 1024,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::{prelude::FromStr, Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    fn cost(instruction: &str) -> u64 {
+        Instruction::<CurrentNetwork>::from_str(instruction).unwrap().cost()
+    }
+
+    #[test]
+    fn test_cost_ordering() {
+        // Ensure the operations that are heavier in the circuit cost more.
+        assert!(cost("add r0 r1 into r2;") < cost("mul r0 r1 into r2;"));
+        assert!(cost("mul r0 r1 into r2;") < cost("div r0 r1 into r2;"));
+        assert!(cost("add r0 r1 into r2;") < cost("hash.psd2 r0 into r1;"));
+        assert!(cost("hash.psd2 r0 into r1;") < cost("hash.psd4 r0 into r1;"));
+        assert!(cost("hash.psd4 r0 into r1;") < cost("hash.psd8 r0 into r1;"));
+        assert!(cost("add r0 r1 into r2;") < cost("commit.ped64 r0 r1 into r2;"));
+        assert!(cost("commit.ped64 r0 r1 into r2;") < cost("commit.ped128 r0 r1 into r2;"));
+        assert!(cost("hash.bhp256 r0 into r1;") < cost("hash.bhp1024 r0 into r1;"));
+    }
+
+    #[test]
+    fn test_cost_is_nonzero() {
+        for instruction in [
+            "add r0 r1 into r2;",
+            "assert.eq r0 r1;",
+            "is.owner r0 r1 into r2;",
+            "ternary r0 r1 r2 into r3;",
+            "hash.to_group r0 into r1;",
+            "hash.to_scalar r0 into r1;",
+        ] {
+            assert!(cost(instruction) > 0, "'{instruction}' must have a nonzero cost");
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+/// The reason an instruction halted during evaluation.
+///
+/// The error is constructed where an instruction is evaluated, and carries the message of the halt.
+/// Callers of `Process::evaluate` may recover the kind of halt with `error.downcast_ref::<InstructionError>()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InstructionError {
+    /// The input exceeds the size supported by the instruction.
+    InputTooLarge(String),
+    /// An arithmetic operation overflowed or underflowed, or an index or shift exceeded the bit width of the type.
+    Overflow(String),
+    /// An operation divided by zero.
+    DivisionByZero(String),
+    /// An operand has a type that is not supported by the instruction.
+    TypeMismatch(String),
+    /// The instruction halted for any other reason.
+    Halt(String),
+}
+
+impl InstructionError {
+    /// Returns the message of the halt.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::InputTooLarge(message)
+            | Self::Overflow(message)
+            | Self::DivisionByZero(message)
+            | Self::TypeMismatch(message)
+            | Self::Halt(message) => message,
+        }
+    }
+}
+
+impl Display for InstructionError {
+    /// Prints the message of the halt.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for InstructionError {}
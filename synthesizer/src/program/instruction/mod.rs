@@ -22,6 +22,11 @@ mod operation;
 pub use operation::*;
 
 mod bytes;
+mod cost;
+
+mod error;
+pub use error::*;
+
 mod parse;
 
 use crate::{FinalizeRegisters, Registers, StackEvaluate, StackExecute, StackMatches, StackProgram};
@@ -29,6 +34,7 @@ use console::{
     network::{
         prelude::{
             alt,
+            anyhow,
             bail,
             ensure,
             error,
@@ -42,6 +48,7 @@ use console::{
             FromBytes,
             FromStr,
             IoResult,
+            ParseError,
             Parser,
             ParserResult,
             Read,
@@ -52,7 +59,7 @@ use console::{
         },
         Network,
     },
-    program::{Register, RegisterType},
+    program::{Literal, Register, RegisterType},
 };
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -131,11 +138,11 @@ pub enum Instruction<N: Network> {
     Mul(Mul<N>),
     /// Multiplies `first` with `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     MulWrapped(MulWrapped<N>),
-    /// Returns `false` if `first` and `second` are true, storing the outcome in `destination`.
+    /// Performs a bitwise `nand` on `first` and `second`, storing the outcome in `destination`.
     Nand(Nand<N>),
     /// Negates `first`, storing the outcome in `destination`.
     Neg(Neg<N>),
-    /// Returns `true` if neither `first` nor `second` is `true`, storing the outcome in `destination`.
+    /// Performs a bitwise `nor` on `first` and `second`, storing the outcome in `destination`.
     Nor(Nor<N>),
     /// Flips each bit in the representation of `first`, storing the outcome in `destination`.
     Not(Not<N>),
@@ -169,6 +176,45 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    // Note: New instructions are appended below, in the order they were introduced.
+    /// Casts the operand into the declared integer type, truncating the integer if it does not fit.
+    CastLossy(CastLossy<N>),
+    /// Counts the leading bits of `first` equal to its sign bit, excluding the sign bit, storing the outcome in `destination`.
+    CountLeadingSignBits(CountLeadingSignBits<N>),
+    /// Retrieves the bit of `first` at the index `second`, storing the outcome in `destination`.
+    GetBit(GetBit<N>),
+    /// Computes the greatest common divisor of `first` and `second`, storing the outcome in `destination`.
+    Gcd(Gcd<N>),
+    /// Divides `first` by `second`, storing the quotient and the remainder in the two destinations.
+    DivRem(DivRem<N>),
+    /// Reverses the byte order of `first`, storing the outcome in `destination`.
+    SwapBytes(SwapBytes<N>),
+    /// Hashes the input to a group element, using Poseidon2 and Elligator-2.
+    HashToGroup(HashToGroup<N>),
+    /// Hashes the input to a scalar, using Poseidon2.
+    HashToScalar(HashToScalar<N>),
+    /// Computes whether `first` is zero as a boolean, storing the outcome in `destination`.
+    IsZero(IsZero<N>),
+    /// Negates `first`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
+    NegWrapped(NegWrapped<N>),
+    /// Raises the field element `first` to the power of `second`, storing the outcome in `destination`.
+    ModPow(ModPow<N>),
+    /// Counts the number of bits set to one in `first`, storing the outcome in `destination`.
+    CountOnes(CountOnes<N>),
+    /// Counts the number of bits set to zero in `first`, storing the outcome in `destination`.
+    CountZeros(CountZeros<N>),
+    /// Counts the number of leading zero bits in `first`, storing the outcome in `destination`.
+    LeadingZeros(LeadingZeros<N>),
+    /// Counts the number of trailing zero bits in `first`, storing the outcome in `destination`.
+    TrailingZeros(TrailingZeros<N>),
+    /// Casts the little-endian bytes of a field, one per operand, into a field, storing the outcome in `destination`.
+    FromBytesLe(FromBytesLe<N>),
+    /// Casts `first` into the little-endian bytes of a field, storing one byte per destination.
+    ToBytesLe(ToBytesLe<N>),
+    /// Computes whether the record in `first` is owned by the address in `second` as a boolean, storing the outcome in `destination`.
+    IsOwner(IsOwner<N>),
+    /// Computes the canonical (lexicographically smaller) square root of `first`, storing the outcome in `destination`.
+    SquareRootCanonical(SquareRootCanonical<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -256,6 +302,26 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            // Note: New instructions are appended below, as the opcode index of each instruction is serialized.
+            CastLossy,
+            CountLeadingSignBits,
+            GetBit,
+            Gcd,
+            DivRem,
+            SwapBytes,
+            HashToGroup,
+            HashToScalar,
+            IsZero,
+            NegWrapped,
+            ModPow,
+            CountOnes,
+            CountZeros,
+            LeadingZeros,
+            TrailingZeros,
+            FromBytesLe,
+            ToBytesLe,
+            IsOwner,
+            SquareRootCanonical,
         }}
     };
     // A variant **without** curly braces:
@@ -350,6 +416,22 @@ impl<N: Network> Instruction<N> {
         instruction!(self, |instruction| instruction.destinations())
     }
 
+    /// Returns the instruction with each operand and destination register rewritten by the given functions.
+    #[inline]
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        instruction!(self, |instruction| instruction.map_registers(&operand, &destination).map(Self::from))
+    }
+
+    /// Returns the result of the instruction if it is a literal operation on literal operands, or `None` otherwise.
+    #[inline]
+    pub fn fold(&self) -> Option<Literal<N>> {
+        instruction!(self, |instruction| instruction.fold())
+    }
+
     /// Evaluates the instruction.
     #[inline]
     pub fn evaluate<A: circuit::Aleo<Network = N>>(
@@ -357,7 +439,19 @@ impl<N: Network> Instruction<N> {
         stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
         registers: &mut Registers<N, A>,
     ) -> Result<()> {
-        instruction!(self, |instruction| instruction.evaluate(stack, registers))
+        // If tracing is enabled, record the operands first, so that a halting instruction is still traced.
+        registers.trace_operands(stack, self)?;
+        // Evaluate the instruction.
+        if let Err(error) = instruction!(self, |instruction| instruction.evaluate(stack, registers)) {
+            match error.downcast_ref::<InstructionError>() {
+                // If the error is already classified (i.e. by the operation or a nested call), return it as is.
+                Some(..) => return Err(error),
+                // Otherwise, the instruction halted for another reason.
+                None => return Err(InstructionError::Halt(format!("{error:#}")).into()),
+            }
+        }
+        // If tracing is enabled, record the destinations.
+        registers.trace_destinations(stack, self)
     }
 
     /// Executes the instruction.
@@ -416,7 +510,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            56,
+            74,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -22,7 +22,7 @@ pub enum Opcode {
     /// The opcode is for a call operation (i.e. `call`).
     Call,
     /// The opcode is for a cast operation (i.e. `cast`).
-    Cast,
+    Cast(&'static str),
     /// The opcode is for a finalize command (i.e. `increment`).
     Command(&'static str),
     /// The opcode is for a commit operation (i.e. `commit.psd4`).
@@ -45,7 +45,7 @@ impl Deref for Opcode {
         match self {
             Opcode::Assert(opcode) => opcode,
             Opcode::Call => &"call",
-            Opcode::Cast => &"cast",
+            Opcode::Cast(opcode) => opcode,
             Opcode::Command(opcode) => opcode,
             Opcode::Commit(opcode) => opcode,
             Opcode::Finalize(opcode) => opcode,
@@ -70,7 +70,7 @@ impl Display for Opcode {
         match self {
             Self::Assert(opcode) => write!(f, "{opcode}"),
             Self::Call => write!(f, "{}", self.deref()),
-            Self::Cast => write!(f, "{}", self.deref()),
+            Self::Cast(opcode) => write!(f, "{opcode}"),
             Self::Command(opcode) => write!(f, "{opcode}"),
             Self::Commit(opcode) => write!(f, "{opcode}"),
             Self::Finalize(opcode) => write!(f, "{opcode}"),
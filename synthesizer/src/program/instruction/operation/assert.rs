@@ -15,7 +15,7 @@
 use crate::{Opcode, Operand, RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram};
 use console::{
     network::prelude::*,
-    program::{Register, RegisterType},
+    program::{Literal, Register, RegisterType},
 };
 
 /// Asserts two operands are equal to each other.
@@ -60,6 +60,22 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![]
     }
+
+    /// Returns the instruction with each operand and destination register rewritten by the given functions.
+    #[inline]
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        _destination: impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self { operands: self.operands.iter().map(operand).collect::<Result<_>>()? })
+    }
+
+    /// Returns `None`, as this instruction is not folded into a literal.
+    #[inline]
+    pub fn fold(&self) -> Option<Literal<N>> {
+        None
+    }
 }
 
 impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
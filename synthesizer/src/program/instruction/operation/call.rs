@@ -31,7 +31,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, Locator, Register, RegisterType, Request, ValueType},
+    program::{Identifier, Literal, Locator, Register, RegisterType, Request, ValueType},
 };
 
 /// The operator references a function name or closure name.
@@ -156,6 +156,26 @@ impl<N: Network> Call<N> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         self.destinations.clone()
     }
+
+    /// Returns the instruction with each operand and destination register rewritten by the given functions.
+    #[inline]
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operator: self.operator.clone(),
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destinations: self.destinations.iter().map(destination).collect::<Result<_>>()?,
+        })
+    }
+
+    /// Returns `None`, as this instruction is not folded into a literal.
+    #[inline]
+    pub fn fold(&self) -> Option<Literal<N>> {
+        None
+    }
 }
 
 impl<N: Network> Call<N> {
@@ -183,7 +203,7 @@ impl<N: Network> Call<N> {
         registers: &mut Registers<N, A>,
     ) -> Result<()> {
         // Load the operands values.
-        let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+        let inputs = registers.load_many(stack, &self.operands)?;
 
         // Retrieve the substack and resource.
         let (substack, resource) = match &self.operator {
@@ -235,12 +255,7 @@ impl<N: Network> Call<N> {
         };
 
         // Assign the outputs to the destination registers.
-        for (output, register) in outputs.into_iter().zip_eq(&self.destinations) {
-            // Assign the output to the register.
-            registers.store(stack, register, output)?;
-        }
-
-        Ok(())
+        registers.store_many(stack, &self.destinations, outputs)
     }
 
     /// Executes the instruction.
@@ -256,8 +271,7 @@ impl<N: Network> Call<N> {
              ),
     ) -> Result<()> {
         // Load the operands values.
-        let inputs: Vec<_> =
-            self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+        let inputs = registers.load_many_circuit(stack, &self.operands)?;
 
         // Retrieve the substack and resource.
         let (substack, resource) = match &self.operator {
@@ -453,12 +467,7 @@ impl<N: Network> Call<N> {
         };
 
         // Assign the outputs to the destination registers.
-        for (output, register) in outputs.into_iter().zip_eq(&self.destinations) {
-            // Assign the output to the register.
-            registers.store_circuit(stack, register, output)?;
-        }
-
-        Ok(())
+        registers.store_many_circuit(stack, &self.destinations, outputs)
     }
 
     /// Finalizes the instruction.
@@ -46,9 +46,19 @@ use console::{
 
 use indexmap::IndexMap;
 
+/// Casts the operands into the declared type, halting if an integer does not fit in the declared type.
+pub type Cast<N> = CastOperation<N, { CastVariant::Cast as u8 }>;
+/// Casts the operand into the declared integer type, truncating the integer if it does not fit.
+pub type CastLossy<N> = CastOperation<N, { CastVariant::CastLossy as u8 }>;
+
+enum CastVariant {
+    Cast,
+    CastLossy,
+}
+
 /// Casts the operands into the declared type.
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct Cast<N: Network> {
+pub struct CastOperation<N: Network, const VARIANT: u8> {
     /// The operands.
     operands: Vec<Operand<N>>,
     /// The destination register.
@@ -57,11 +67,15 @@ pub struct Cast<N: Network> {
     register_type: RegisterType<N>,
 }
 
-impl<N: Network> Cast<N> {
+impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
-        Opcode::Cast
+        match VARIANT {
+            0 => Opcode::Cast("cast"),
+            1 => Opcode::Cast("cast.lossy"),
+            _ => panic!("Invalid 'cast' instruction opcode"),
+        }
     }
 
     /// Returns the operands in the operation.
@@ -76,6 +90,26 @@ impl<N: Network> Cast<N> {
         vec![self.destination.clone()]
     }
 
+    /// Returns the instruction with each operand and destination register rewritten by the given functions.
+    #[inline]
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destination: destination(&self.destination)?,
+            register_type: self.register_type.clone(),
+        })
+    }
+
+    /// Returns `None`, as this instruction is not folded into a literal.
+    #[inline]
+    pub fn fold(&self) -> Option<Literal<N>> {
+        None
+    }
+
     /// Returns the casted register type.
     #[inline]
     pub const fn register_type(&self) -> &RegisterType<N> {
@@ -83,7 +117,7 @@ impl<N: Network> Cast<N> {
     }
 }
 
-impl<N: Network> Cast<N> {
+impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
     /// Evaluates the instruction.
     #[inline]
     pub fn evaluate(
@@ -95,7 +129,9 @@ impl<N: Network> Cast<N> {
         let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                self.cast_to_literal(stack, registers, literal_type, inputs)
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 self.cast_to_struct(stack, registers, struct_name, inputs)
             }
@@ -188,7 +224,25 @@ impl<N: Network> Cast<N> {
             self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
 
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                if inputs.len() != 1 {
+                    bail!("Casting to a literal requires exactly 1 operand, but {} were provided", inputs.len())
+                }
+                // Retrieve the literal from the operand.
+                let literal = match &inputs[0] {
+                    circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => literal,
+                    _ => bail!("Casting to a literal requires the operand to be a literal"),
+                };
+                // Cast the literal.
+                let output = match VARIANT {
+                    0 => literal.cast(literal_type)?,
+                    1 => literal.cast_lossy(literal_type)?,
+                    _ => bail!("Invalid 'cast' variant: {VARIANT}"),
+                };
+                // Store the output.
+                registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output.into()))
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_)) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_STRUCT_ENTRIES {
@@ -332,7 +386,9 @@ impl<N: Network> Cast<N> {
         let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                self.cast_to_literal(stack, registers, literal_type, inputs)
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 self.cast_to_struct(stack, registers, struct_name, inputs)
             }
@@ -361,9 +417,33 @@ impl<N: Network> Cast<N> {
             self.operands.len(),
         );
 
+        // Ensure the lossy variant only casts into a literal.
+        if VARIANT == CastVariant::CastLossy as u8 {
+            ensure!(
+                matches!(self.register_type, RegisterType::Plaintext(PlaintextType::Literal(..))),
+                "Instruction '{}' can only cast into a literal type",
+                Self::opcode()
+            );
+        }
+
         // Ensure the output type is defined in the program.
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one input type.
+                ensure!(
+                    input_types.len() == 1,
+                    "Casting to a literal requires exactly 1 operand, but {} were provided",
+                    input_types.len()
+                );
+                // Ensure the input type is an integer, and the output type is an integer.
+                match input_types[0] {
+                    RegisterType::Plaintext(PlaintextType::Literal(input_type)) => ensure!(
+                        input_type.is_integer() && literal_type.is_integer(),
+                        "Casting from '{input_type}' into '{literal_type}' is unsupported"
+                    ),
+                    input_type => bail!("Casting from '{input_type}' into '{literal_type}' is unsupported"),
+                }
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Retrieve the struct and ensure it is defined in the program.
                 let struct_ = stack.program().get_struct(&struct_name)?;
@@ -462,7 +542,34 @@ impl<N: Network> Cast<N> {
     }
 }
 
-impl<N: Network> Cast<N> {
+impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
+    /// A helper method to handle casting to a literal.
+    fn cast_to_literal(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersStore<N>,
+        literal_type: LiteralType,
+        inputs: Vec<Value<N>>,
+    ) -> Result<()> {
+        // Ensure there is exactly one operand.
+        if inputs.len() != 1 {
+            bail!("Casting to a literal requires exactly 1 operand, but {} were provided", inputs.len())
+        }
+        // Retrieve the literal from the operand.
+        let literal = match &inputs[0] {
+            Value::Plaintext(Plaintext::Literal(literal, ..)) => literal,
+            _ => bail!("Casting to a literal requires the operand to be a literal"),
+        };
+        // Cast the literal.
+        let output = match VARIANT {
+            0 => literal.cast(literal_type)?,
+            1 => literal.cast_lossy(literal_type)?,
+            _ => bail!("Invalid 'cast' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+
     /// A helper method to handle casting to a struct.
     fn cast_to_struct(
         &self,
@@ -514,7 +621,7 @@ impl<N: Network> Cast<N> {
     }
 }
 
-impl<N: Network> Parser for Cast<N> {
+impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
     /// Parses a string into an operation.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
@@ -546,6 +653,14 @@ impl<N: Network> Parser for Cast<N> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the register type from the string.
         let (string, register_type) = RegisterType::parse(string)?;
+        // Ensure the lossy variant only casts into a literal.
+        if VARIANT == CastVariant::CastLossy as u8
+            && !matches!(register_type, RegisterType::Plaintext(PlaintextType::Literal(..)))
+        {
+            return map_res(fail, |_: ParserResult<Self>| {
+                Err(error("Failed to parse 'cast.lossy' opcode: expected a literal type"))
+            })(string);
+        }
         // Check that the number of operands does not exceed the maximum number of data entries.
         let max_operands = match register_type {
             RegisterType::Plaintext(_) => N::MAX_STRUCT_ENTRIES,
@@ -562,7 +677,7 @@ impl<N: Network> Parser for Cast<N> {
     }
 }
 
-impl<N: Network> FromStr for Cast<N> {
+impl<N: Network, const VARIANT: u8> FromStr for CastOperation<N, VARIANT> {
     type Err = Error;
 
     /// Parses a string into an operation.
@@ -580,14 +695,14 @@ impl<N: Network> FromStr for Cast<N> {
     }
 }
 
-impl<N: Network> Debug for Cast<N> {
+impl<N: Network, const VARIANT: u8> Debug for CastOperation<N, VARIANT> {
     /// Prints the operation as a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         Display::fmt(self, f)
     }
 }
 
-impl<N: Network> Display for Cast<N> {
+impl<N: Network, const VARIANT: u8> Display for CastOperation<N, VARIANT> {
     /// Prints the operation to a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         // Ensure the number of operands is within the bounds.
@@ -606,7 +721,7 @@ impl<N: Network> Display for Cast<N> {
     }
 }
 
-impl<N: Network> FromBytes for Cast<N> {
+impl<N: Network, const VARIANT: u8> FromBytes for CastOperation<N, VARIANT> {
     /// Reads the operation from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the number of operands.
@@ -632,6 +747,13 @@ impl<N: Network> FromBytes for Cast<N> {
         // Read the casted register type.
         let register_type = RegisterType::read_le(&mut reader)?;
 
+        // Ensure the lossy variant only casts into a literal.
+        if VARIANT == CastVariant::CastLossy as u8
+            && !matches!(register_type, RegisterType::Plaintext(PlaintextType::Literal(..)))
+        {
+            return Err(error("The 'cast.lossy' opcode must cast into a literal type"));
+        }
+
         // Ensure the number of operands is within the bounds for the register type.
         let max_operands = match register_type {
             RegisterType::Plaintext(_) => N::MAX_STRUCT_ENTRIES,
@@ -646,7 +768,7 @@ impl<N: Network> FromBytes for Cast<N> {
     }
 }
 
-impl<N: Network> ToBytes for Cast<N> {
+impl<N: Network, const VARIANT: u8> ToBytes for CastOperation<N, VARIANT> {
     /// Writes the operation to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Ensure the number of operands is within the bounds.
@@ -672,9 +794,207 @@ impl<N: Network> ToBytes for Cast<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        process::Stack,
+        program::test_helpers::{sample_finalize_registers, sample_registers},
+    };
+    use circuit::AleoV0;
     use console::{network::Testnet3, program::Identifier};
+    use snarkvm_synthesizer_snark::{ProvingKey, VerifyingKey};
+
+    use std::collections::HashMap;
 
     type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+    fn sample_stack(
+        opcode: Opcode,
+        input_type: LiteralType,
+        output_type: LiteralType,
+        mode: circuit::Mode,
+        cache: &mut HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>,
+    ) -> Result<Stack<CurrentNetwork>> {
+        use crate::{Process, Program};
+
+        // Initialize the program.
+        let program = Program::from_str(&format!(
+            "program testing.aleo;
+            function run:
+                input r0 as {input_type}.{mode};
+                {opcode} r0 into r1 as {output_type};
+                finalize r0;
+
+            finalize run:
+                input r0 as {input_type}.public;
+                {opcode} r0 into r1 as {output_type};
+        "
+        ))?;
+
+        // Initialize the stack.
+        Stack::new(&Process::load_with_cache(cache)?, &program)
+    }
+
+    fn check_cast<const VARIANT: u8>(
+        literal: &Literal<CurrentNetwork>,
+        output_type: LiteralType,
+        expected: Option<Literal<CurrentNetwork>>,
+        mode: &circuit::Mode,
+        cache: &mut HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>,
+    ) {
+        use circuit::Eject;
+
+        let opcode = CastOperation::<CurrentNetwork, VARIANT>::opcode();
+        println!("Checking '{opcode}' for '{literal}.{mode}' into '{output_type}'");
+
+        // Initialize the stack.
+        let stack = sample_stack(opcode, literal.to_type(), output_type, *mode, cache).unwrap();
+        // Initialize the operation.
+        let operation = CastOperation::<CurrentNetwork, VARIANT> {
+            operands: vec![Operand::Register(Register::Locator(0))],
+            destination: Register::Locator(1),
+            register_type: RegisterType::Plaintext(PlaintextType::Literal(output_type)),
+        };
+        // Initialize the function name.
+        let function_name = Identifier::from_str("run").unwrap();
+        // Initialize a destination operand.
+        let destination_operand = Operand::Register(Register::Locator(1));
+
+        // Check the console evaluation.
+        let mut registers = sample_registers(&stack, &function_name, &[(literal, None)]).unwrap();
+        let result = operation.evaluate(&stack, &mut registers);
+        match &expected {
+            Some(expected) => {
+                result.unwrap();
+                let output = registers.load_literal(&stack, &destination_operand).unwrap();
+                assert_eq!(*expected, output, "Instruction '{operation}' failed (console): {literal}");
+            }
+            None => assert!(result.is_err(), "Instruction '{operation}' should have failed (console): {literal}"),
+        }
+
+        // Check the circuit execution.
+        let mut registers = sample_registers(&stack, &function_name, &[(literal, Some(*mode))]).unwrap();
+        match &expected {
+            Some(expected) => {
+                operation.execute::<CurrentAleo>(&stack, &mut registers).unwrap();
+                let output = registers.load_literal_circuit(&stack, &destination_operand).unwrap();
+                assert_eq!(
+                    *expected,
+                    output.eject_value(),
+                    "Instruction '{operation}' failed (circuit): {literal}.{mode}"
+                );
+                assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+            }
+            None => match mode.is_constant() {
+                // Ensure a constant cast halts.
+                true => {
+                    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                        operation.execute::<CurrentAleo>(&stack, &mut registers)
+                    }));
+                    assert!(
+                        result.is_err(),
+                        "Instruction '{operation}' should have halted (circuit): {literal}.{mode}"
+                    );
+                }
+                // Ensure a non-constant cast is unsatisfied.
+                false => {
+                    operation.execute::<CurrentAleo>(&stack, &mut registers).unwrap();
+                    assert!(
+                        !<CurrentAleo as circuit::Environment>::is_satisfied(),
+                        "Instruction '{operation}' should not be satisfied (circuit): {literal}.{mode}"
+                    );
+                }
+            },
+        }
+        <CurrentAleo as circuit::Environment>::reset();
+
+        // Check the finalize.
+        let mut registers = sample_finalize_registers(&stack, &function_name, &[literal]).unwrap();
+        let result = operation.finalize(&stack, &mut registers);
+        match &expected {
+            Some(expected) => {
+                result.unwrap();
+                let output = registers.load_literal(&stack, &destination_operand).unwrap();
+                assert_eq!(*expected, output, "Instruction '{operation}' failed (finalize): {literal}");
+            }
+            None => assert!(result.is_err(), "Instruction '{operation}' should have failed (finalize): {literal}"),
+        }
+    }
+
+    fn check_casts(literal: &str, output_type: LiteralType, expected: Option<&str>, expected_lossy: &str) {
+        // Initialize the cache.
+        let mut cache = Default::default();
+
+        let literal = Literal::from_str(literal).unwrap();
+        let expected = expected.map(|expected| Literal::from_str(expected).unwrap());
+        let expected_lossy = Literal::from_str(expected_lossy).unwrap();
+
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            check_cast::<{ CastVariant::Cast as u8 }>(&literal, output_type, expected.clone(), &mode, &mut cache);
+            check_cast::<{ CastVariant::CastLossy as u8 }>(
+                &literal,
+                output_type,
+                Some(expected_lossy.clone()),
+                &mode,
+                &mut cache,
+            );
+        }
+    }
+
+    #[test]
+    fn test_cast_widening() {
+        check_casts("200u8", LiteralType::U64, Some("200u64"), "200u64");
+        check_casts("-5i8", LiteralType::I128, Some("-5i128"), "-5i128");
+        check_casts("70000u32", LiteralType::I64, Some("70000i64"), "70000i64");
+    }
+
+    #[test]
+    fn test_cast_narrowing() {
+        check_casts("255u64", LiteralType::U8, Some("255u8"), "255u8");
+        check_casts("-128i32", LiteralType::I8, Some("-128i8"), "-128i8");
+    }
+
+    #[test]
+    fn test_cast_signedness() {
+        check_casts("127u8", LiteralType::I8, Some("127i8"), "127i8");
+        check_casts("5i16", LiteralType::U16, Some("5u16"), "5u16");
+    }
+
+    #[test]
+    fn test_cast_out_of_range_halts() {
+        check_casts("256u64", LiteralType::U8, None, "0u8");
+        check_casts("-129i32", LiteralType::I8, None, "127i8");
+        check_casts("-1i8", LiteralType::U8, None, "255u8");
+        check_casts("255u8", LiteralType::I8, None, "-1i8");
+    }
+
+    #[test]
+    fn test_parse_cast_into_literal() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 into r1 as u32").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands, vec![Operand::Register(Register::Locator(0))], "The operands are incorrect");
+        assert_eq!(cast.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(
+            cast.register_type,
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
+            "The value type is incorrect"
+        );
+
+        let (string, cast) = CastLossy::<CurrentNetwork>::parse("cast.lossy r0 into r1 as i8").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(
+            cast.register_type,
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::I8)),
+            "The value type is incorrect"
+        );
+        assert_eq!(cast.to_string(), "cast.lossy r0 into r1 as i8");
+
+        // Ensure the lossy variant only casts into a literal.
+        assert!(CastLossy::<CurrentNetwork>::parse("cast.lossy r0 into r1 as foo").is_err());
+        assert!(CastLossy::<CurrentNetwork>::parse("cast.lossy r0 into r1 as token.record").is_err());
+        // Ensure the checked variant does not parse the lossy opcode.
+        assert!(Cast::<CurrentNetwork>::parse("cast.lossy r0 into r1 as i8").is_err());
+    }
 
     #[test]
     fn test_parse() {
@@ -0,0 +1,758 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    Opcode,
+    Operand,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+    StackMatches,
+    StackProgram,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+    types::{Field, U8},
+};
+
+/// The number of bytes in the encoding of a field element.
+pub const NUM_FIELD_BYTES: usize = 32;
+
+/// Casts a field into its canonical little-endian encoding, storing one `u8` per destination register.
+///
+/// Unlike the other casts, this instruction has `NUM_FIELD_BYTES` destination registers,
+/// i.e. `cast.to_bytes r0 into r1 r2 ... r32;` stores the least significant byte in `r1`.
+/// The bits of the field are zero-padded up to `8 * NUM_FIELD_BYTES` bits.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ToBytesLe<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination registers, from the least to the most significant byte.
+    destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> ToBytesLe<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Cast("cast.to_bytes")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly one input.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination registers, from the least to the most significant byte.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        self.destinations.clone()
+    }
+
+    /// Returns the instruction with each operand and destination register rewritten by the given functions.
+    #[inline]
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destinations: self.destinations.iter().map(destination).collect::<Result<_>>()?,
+        })
+    }
+
+    /// Returns `None`, as this instruction is not folded into a literal.
+    #[inline]
+    pub fn fold(&self) -> Option<Literal<N>> {
+        None
+    }
+}
+
+impl<N: Network> ToBytesLe<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+        // Ensure the number of destinations is correct.
+        ensure!(
+            self.destinations.len() == NUM_FIELD_BYTES,
+            "Instruction '{}' has an invalid number of destinations",
+            Self::opcode()
+        );
+
+        // Load the field.
+        let field = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::Field(field) => field,
+            literal => bail!("Instruction '{}' expects a field operand, found '{}'", Self::opcode(), literal.to_type()),
+        };
+
+        // Encode the field as zero-padded little-endian bits.
+        let mut bits_le = field.to_bits_le();
+        bits_le.resize(8 * NUM_FIELD_BYTES, false);
+        // Store each byte.
+        for (destination, byte) in self.destinations.iter().zip(bits_le.chunks(8)) {
+            registers.store_literal(stack, destination, Literal::U8(U8::from_bits_le(byte)?))?;
+        }
+        Ok(())
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{FromBits, Inject, ToBits};
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+        // Ensure the number of destinations is correct.
+        ensure!(
+            self.destinations.len() == NUM_FIELD_BYTES,
+            "Instruction '{}' has an invalid number of destinations",
+            Self::opcode()
+        );
+
+        // Load the field.
+        let field = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            circuit::Literal::Field(field) => field,
+            literal => bail!("Instruction '{}' expects a field operand, found '{}'", Self::opcode(), literal.to_type()),
+        };
+
+        // Encode the field as zero-padded little-endian bits.
+        let mut bits_le = field.to_bits_le();
+        bits_le.resize(8 * NUM_FIELD_BYTES, circuit::Boolean::constant(false));
+        // Store each byte.
+        for (destination, byte) in self.destinations.iter().zip(bits_le.chunks(8)) {
+            registers.store_literal_circuit(
+                stack,
+                destination,
+                circuit::Literal::U8(circuit::U8::from_bits_le(byte)),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output types from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+        // Ensure the operand is a field.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)) {
+            bail!("Instruction '{}' expects a field operand, found '{}'", Self::opcode(), input_types[0])
+        }
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U8)); NUM_FIELD_BYTES])
+    }
+}
+
+impl<N: Network> Parser for ToBytesLe<N> {
+    /// Parses a string into an operation.
+    /// The operation is of the form `cast.to_bytes {operand} into {destination_0} ... {destination_31}`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (mut string, _) = tag("into")(string)?;
+        // Parse the destination registers from the string.
+        let mut destinations = Vec::with_capacity(NUM_FIELD_BYTES);
+        for _ in 0..NUM_FIELD_BYTES {
+            // Parse the whitespace from the string.
+            let (remainder, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination register from the string.
+            let (remainder, destination) = Register::parse(remainder)?;
+            destinations.push(destination);
+            string = remainder;
+        }
+
+        Ok((string, Self { operands: vec![operand], destinations }))
+    }
+}
+
+impl<N: Network> FromStr for ToBytesLe<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ToBytesLe<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ToBytesLe<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            eprintln!("The number of operands must be 1, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} into", Self::opcode(), self.operands[0])?;
+        self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))
+    }
+}
+
+impl<N: Network> FromBytes for ToBytesLe<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operands = vec![Operand::read_le(&mut reader)?];
+        // Read the destination registers.
+        let destinations =
+            (0..NUM_FIELD_BYTES).map(|_| Register::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the operation.
+        Ok(Self { operands, destinations })
+    }
+}
+
+impl<N: Network> ToBytes for ToBytesLe<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Ensure the number of destinations is correct.
+        if self.destinations.len() != NUM_FIELD_BYTES {
+            return Err(error(format!(
+                "The number of destinations must be {NUM_FIELD_BYTES}, found {}",
+                self.destinations.len()
+            )));
+        }
+        // Write the operand.
+        self.operands[0].write_le(&mut writer)?;
+        // Write the destination registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+/// Casts the canonical little-endian encoding of a field, given as one `u8` per operand, into a field.
+///
+/// This is the inverse of `cast.to_bytes`, i.e. `cast.from_bytes r0 r1 ... r31 into r32;`
+/// reads the least significant byte from `r0`. It halts if the bytes encode a value outside of the field.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct FromBytesLe<N: Network> {
+    /// The operands, from the least to the most significant byte.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> FromBytesLe<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Cast("cast.from_bytes")
+    }
+
+    /// Returns the operands in the operation, from the least to the most significant byte.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there is one operand per byte.
+        debug_assert!(
+            self.operands.len() == NUM_FIELD_BYTES,
+            "Instruction '{}' must have {NUM_FIELD_BYTES} operands",
+            Self::opcode()
+        );
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the instruction with each operand and destination register rewritten by the given functions.
+    #[inline]
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destination: destination(&self.destination)?,
+        })
+    }
+
+    /// Returns `None`, as this instruction is not folded into a literal.
+    #[inline]
+    pub fn fold(&self) -> Option<Literal<N>> {
+        None
+    }
+}
+
+impl<N: Network> FromBytesLe<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != NUM_FIELD_BYTES {
+            bail!(
+                "Instruction '{}' expects {NUM_FIELD_BYTES} operands, found {} operands",
+                Self::opcode(),
+                self.operands.len()
+            )
+        }
+
+        // Load the bytes as little-endian bits.
+        let mut bits_le = Vec::with_capacity(8 * NUM_FIELD_BYTES);
+        for operand in &self.operands {
+            match registers.load_literal(stack, operand)? {
+                Literal::U8(byte) => bits_le.extend(byte.to_bits_le()),
+                literal => bail!("Instruction '{}' expects u8 operands, found '{}'", Self::opcode(), literal.to_type()),
+            }
+        }
+        // Recover the field, which fails if the bits encode a value outside of the field.
+        let field = Field::<N>::from_bits_le(&bits_le)?;
+        // Store the field.
+        registers.store_literal(stack, &self.destination, Literal::Field(field))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{FromBits, ToBits};
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != NUM_FIELD_BYTES {
+            bail!(
+                "Instruction '{}' expects {NUM_FIELD_BYTES} operands, found {} operands",
+                Self::opcode(),
+                self.operands.len()
+            )
+        }
+
+        // Load the bytes as little-endian bits.
+        let mut bits_le = Vec::with_capacity(8 * NUM_FIELD_BYTES);
+        for operand in &self.operands {
+            match registers.load_literal_circuit(stack, operand)? {
+                circuit::Literal::U8(byte) => bits_le.extend(byte.to_bits_le()),
+                literal => bail!("Instruction '{}' expects u8 operands, found '{}'", Self::opcode(), literal.to_type()),
+            }
+        }
+        // Recover the field, which is unsatisfiable if the bits encode a value outside of the field.
+        let field = circuit::Field::<A>::from_bits_le(&bits_le);
+        // Store the field.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Field(field))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != NUM_FIELD_BYTES {
+            bail!(
+                "Instruction '{}' expects {NUM_FIELD_BYTES} inputs, found {} inputs",
+                Self::opcode(),
+                input_types.len()
+            )
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != NUM_FIELD_BYTES {
+            bail!(
+                "Instruction '{}' expects {NUM_FIELD_BYTES} operands, found {} operands",
+                Self::opcode(),
+                self.operands.len()
+            )
+        }
+        // Ensure the operands are bytes.
+        for input_type in input_types {
+            if *input_type != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U8)) {
+                bail!("Instruction '{}' expects u8 operands, found '{input_type}'", Self::opcode())
+            }
+        }
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
+    }
+}
+
+impl<N: Network> Parser for FromBytesLe<N> {
+    /// Parses a string into an operation.
+    /// The operation is of the form `cast.from_bytes {operand_0} ... {operand_31} into {destination}`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (mut string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let mut operands = Vec::with_capacity(NUM_FIELD_BYTES);
+        for _ in 0..NUM_FIELD_BYTES {
+            // Parse the whitespace from the string.
+            let (remainder, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            let (remainder, operand) = Operand::parse(remainder)?;
+            operands.push(operand);
+            string = remainder;
+        }
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network> FromStr for FromBytesLe<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for FromBytesLe<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for FromBytesLe<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure there is one operand per byte.
+        if self.operands.len() != NUM_FIELD_BYTES {
+            eprintln!("The number of operands must be {NUM_FIELD_BYTES}, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for FromBytesLe<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operands.
+        let operands = (0..NUM_FIELD_BYTES).map(|_| Operand::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for FromBytesLe<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure there is one operand per byte.
+        if self.operands.len() != NUM_FIELD_BYTES {
+            return Err(error(format!(
+                "The number of operands must be {NUM_FIELD_BYTES}, found {}",
+                self.operands.len()
+            )));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        process::Stack,
+        program::test_helpers::{sample_finalize_registers, sample_registers},
+    };
+    use circuit::{AleoV0, Eject};
+    use console::{network::Testnet3, program::Identifier};
+    use snarkvm_synthesizer_snark::{ProvingKey, VerifyingKey};
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    type Cache = HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>;
+
+    const MODES: [circuit::Mode; 3] = [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private];
+
+    /// Returns the registers for each byte of a field, starting from `r{start}`.
+    fn byte_registers(start: usize) -> String {
+        (start..start + NUM_FIELD_BYTES).map(|index| format!("r{index}")).collect::<Vec<_>>().join(" ")
+    }
+
+    /// Samples a stack whose `run` function casts `r0` into bytes and back, in both the function and finalize.
+    fn sample_round_trip_stack(mode: circuit::Mode, cache: &mut Cache) -> Result<Stack<CurrentNetwork>> {
+        use crate::{Process, Program};
+
+        // Initialize the program.
+        let bytes = byte_registers(1);
+        let program = Program::from_str(&format!(
+            "program testing.aleo;
+            function run:
+                input r0 as field.{mode};
+                cast.to_bytes r0 into {bytes};
+                cast.from_bytes {bytes} into r33;
+                finalize r0;
+
+            finalize run:
+                input r0 as field.public;
+                cast.to_bytes r0 into {bytes};
+                cast.from_bytes {bytes} into r33;
+        "
+        ))?;
+
+        // Initialize the stack.
+        Stack::new(&Process::load_with_cache(cache)?, &program)
+    }
+
+    /// Samples a stack whose `run` function casts its byte inputs into a field.
+    fn sample_from_bytes_stack(mode: circuit::Mode, cache: &mut Cache) -> Result<Stack<CurrentNetwork>> {
+        use crate::{Process, Program};
+
+        // Initialize the program.
+        let inputs = (0..NUM_FIELD_BYTES).map(|index| format!("input r{index} as u8.{mode};")).collect::<String>();
+        let program = Program::from_str(&format!(
+            "program testing.aleo;
+            function run:
+                {inputs}
+                cast.from_bytes {} into r32;
+        ",
+            byte_registers(0)
+        ))?;
+
+        // Initialize the stack.
+        Stack::new(&Process::load_with_cache(cache)?, &program)
+    }
+
+    /// Checks the field casts into the expected bytes and back, when evaluated, executed, and finalized.
+    fn check_round_trip(field: Field<CurrentNetwork>, expected: &[u8], cache: &mut Cache) -> Result<()> {
+        let bytes = byte_registers(1);
+        let to_bytes = ToBytesLe::<CurrentNetwork>::from_str(&format!("cast.to_bytes r0 into {bytes}"))?;
+        let from_bytes = FromBytesLe::<CurrentNetwork>::from_str(&format!("cast.from_bytes {bytes} into r33"))?;
+
+        let function_name = Identifier::from_str("run")?;
+        let input = Literal::Field(field);
+        let byte_operand = |index: usize| Operand::Register(Register::Locator(index as u64 + 1));
+        let field_operand = Operand::Register(Register::Locator(33));
+
+        for mode in MODES {
+            let stack = sample_round_trip_stack(mode, cache)?;
+
+            // Check the evaluation.
+            let mut registers = sample_registers(&stack, &function_name, &[(&input, None)])?;
+            to_bytes.evaluate(&stack, &mut registers)?;
+            from_bytes.evaluate(&stack, &mut registers)?;
+            for (index, byte) in expected.iter().enumerate() {
+                assert_eq!(Literal::U8(U8::new(*byte)), registers.load_literal(&stack, &byte_operand(index))?);
+            }
+            assert_eq!(input, registers.load_literal(&stack, &field_operand)?);
+
+            // Check the execution.
+            let mut registers = sample_registers(&stack, &function_name, &[(&input, Some(mode))])?;
+            to_bytes.execute::<CurrentAleo>(&stack, &mut registers)?;
+            from_bytes.execute::<CurrentAleo>(&stack, &mut registers)?;
+            for (index, byte) in expected.iter().enumerate() {
+                let candidate = registers.load_literal_circuit(&stack, &byte_operand(index))?.eject_value();
+                assert_eq!(Literal::U8(U8::new(*byte)), candidate);
+            }
+            assert_eq!(input, registers.load_literal_circuit(&stack, &field_operand)?.eject_value());
+            assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "{field}.{mode}");
+            <CurrentAleo as circuit::Environment>::reset();
+
+            // Check the finalize.
+            let mut registers = sample_finalize_registers(&stack, &function_name, &[&input])?;
+            to_bytes.finalize(&stack, &mut registers)?;
+            from_bytes.finalize(&stack, &mut registers)?;
+            assert_eq!(input, registers.load_literal(&stack, &field_operand)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let bytes = byte_registers(1);
+
+        let to_bytes = ToBytesLe::<CurrentNetwork>::from_str(&format!("cast.to_bytes r0 into {bytes}"))?;
+        assert_eq!(to_bytes.operands(), [Operand::Register(Register::Locator(0))]);
+        assert_eq!(to_bytes.destinations().len(), NUM_FIELD_BYTES);
+        assert_eq!(format!("cast.to_bytes r0 into {bytes}"), to_bytes.to_string());
+        assert_eq!(to_bytes, ToBytesLe::read_le(&to_bytes.to_bytes_le()?[..])?);
+
+        let from_bytes = FromBytesLe::<CurrentNetwork>::from_str(&format!("cast.from_bytes {bytes} into r33"))?;
+        assert_eq!(from_bytes.operands().len(), NUM_FIELD_BYTES);
+        assert_eq!(from_bytes.destinations(), [Register::Locator(33)]);
+        assert_eq!(format!("cast.from_bytes {bytes} into r33"), from_bytes.to_string());
+        assert_eq!(from_bytes, FromBytesLe::read_le(&from_bytes.to_bytes_le()?[..])?);
+
+        // Ensure the wrong number of bytes is rejected.
+        let too_few = (1..NUM_FIELD_BYTES).map(|index| format!("r{index}")).collect::<Vec<_>>().join(" ");
+        assert!(ToBytesLe::<CurrentNetwork>::from_str(&format!("cast.to_bytes r0 into {too_few}")).is_err());
+        assert!(FromBytesLe::<CurrentNetwork>::from_str(&format!("cast.from_bytes {too_few} into r33")).is_err());
+        assert!(ToBytesLe::<CurrentNetwork>::from_str(&format!("cast.to_bytes r0 into {bytes} r33")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_bytes_round_trip() -> Result<()> {
+        let mut rng = TestRng::default();
+        let mut cache = Default::default();
+
+        // Check the encoding of fixed values.
+        let mut expected = [0u8; NUM_FIELD_BYTES];
+        check_round_trip(Field::from_str("0field")?, &expected, &mut cache)?;
+        expected[0] = 1;
+        check_round_trip(Field::from_str("1field")?, &expected, &mut cache)?;
+        expected[0] = 0;
+        expected[1] = 1;
+        check_round_trip(Field::from_str("256field")?, &expected, &mut cache)?;
+
+        // Check the encoding matches the canonical little-endian encoding, including for the largest field element.
+        for field in [-Field::one(), Field::rand(&mut rng), Field::rand(&mut rng)] {
+            check_round_trip(field, &field.to_bytes_le()?, &mut cache)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_from_bytes_halts_outside_the_field() -> Result<()> {
+        let mut cache = Default::default();
+        let operation =
+            FromBytesLe::<CurrentNetwork>::from_str(&format!("cast.from_bytes {} into r32", byte_registers(0)))?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Prepare the encoding of the modulus, which is `-1 + 1`, and the largest encoding.
+        let mut modulus = (-Field::<CurrentNetwork>::one()).to_bytes_le()?;
+        modulus[0] += 1;
+        let largest = vec![u8::MAX; NUM_FIELD_BYTES];
+
+        for bytes in [modulus, largest] {
+            let literals = bytes.iter().map(|byte| Literal::U8(U8::new(*byte))).collect::<Vec<_>>();
+            for mode in MODES {
+                let stack = sample_from_bytes_stack(mode, &mut cache)?;
+
+                // Ensure the evaluation fails.
+                let values = literals.iter().map(|literal| (literal, None)).collect::<Vec<_>>();
+                let mut registers = sample_registers(&stack, &function_name, &values)?;
+                assert!(operation.evaluate(&stack, &mut registers).is_err());
+
+                // Ensure the execution halts, or is unsatisfied.
+                let values = literals.iter().map(|literal| (literal, Some(mode))).collect::<Vec<_>>();
+                let mut registers = sample_registers(&stack, &function_name, &values)?;
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    operation.execute::<CurrentAleo>(&stack, &mut registers)
+                }));
+                assert!(
+                    !matches!(result, Ok(Ok(()))) || !<CurrentAleo as circuit::Environment>::is_satisfied(),
+                    "The bytes of a value outside the field must halt ({mode})"
+                );
+                <CurrentAleo as circuit::Environment>::reset();
+            }
+        }
+        Ok(())
+    }
+}
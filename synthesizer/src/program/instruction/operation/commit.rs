@@ -88,6 +88,25 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the instruction with each operand and destination register rewritten by the given functions.
+    #[inline]
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destination: destination(&self.destination)?,
+        })
+    }
+
+    /// Returns `None`, as this instruction is not folded into a literal.
+    #[inline]
+    pub fn fold(&self) -> Option<Literal<N>> {
+        None
+    }
 }
 
 impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
@@ -193,7 +212,25 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
             bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
         }
 
-        // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
+        // If the operation is Pedersen and the input is a literal, ensure it is within the number of bits.
+        if let RegisterType::Plaintext(PlaintextType::Literal(literal_type)) = input_types[0] {
+            // Compute the maximum number of input bits.
+            let max_bits = match VARIANT {
+                4 => Some(64),
+                5 => Some(128),
+                _ => None,
+            };
+            if let (Some(max_bits), Some(size_in_bits)) = (max_bits, literal_type.size_in_bits::<N>()) {
+                // Note: A literal is committed with its 2 variant bits, 8 type bits, and 16 size bits.
+                let num_bits = 26 + size_in_bits as usize;
+                if num_bits > max_bits {
+                    bail!(
+                        "Instruction '{}' expects at most {max_bits} input bits, found '{literal_type}' with {num_bits} bits",
+                        Self::opcode()
+                    )
+                }
+            }
+        }
 
         match VARIANT {
             0 | 1 | 2 | 3 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))]),
@@ -573,6 +610,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_commit_ped_rejects_oversized_literals() {
+        // Prepare the key cache.
+        let mut cache = Default::default();
+
+        let mode = circuit::Mode::Private;
+        let mut check =
+            |opcode, literal_type| sample_stack(opcode, literal_type, LiteralType::Scalar, mode, mode, &mut cache);
+
+        // Ensure the literals within the bit bound are accepted.
+        let ped64 = CommitPED64::<CurrentNetwork>::opcode();
+        let ped128 = CommitPED128::<CurrentNetwork>::opcode();
+        assert!(check(ped64, LiteralType::U32).is_ok());
+        assert!(check(ped128, LiteralType::I64).is_ok());
+
+        // Ensure the literals beyond the bit bound are rejected.
+        assert!(check(ped64, LiteralType::I64).is_err());
+        assert!(check(ped64, LiteralType::Field).is_err());
+        assert!(check(ped128, LiteralType::U128).is_err());
+        assert!(check(ped128, LiteralType::Address).is_err());
+    }
+
     #[test]
     fn test_parse() {
         let (string, commit) = CommitBHP512::<CurrentNetwork>::parse("commit.bhp512 r0 r1 into r2").unwrap();
@@ -0,0 +1,475 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    DivOperation,
+    MulWrappedOperation,
+    Opcode,
+    Operand,
+    Operation,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+    RemOperation,
+    StackMatches,
+    StackProgram,
+    SubWrappedOperation,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+};
+
+/// Divides `first` by `second`, storing the quotient in the first destination and the remainder in the second.
+///
+/// Unlike the other literal operations, this instruction has two destination registers,
+/// i.e. `div_rem r0 r1 into r2 r3;` stores the quotient in `r2` and the remainder in `r3`.
+/// The quotient is truncated towards zero, so the remainder has the sign of the dividend,
+/// and `first == second * quotient + remainder` always holds.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct DivRem<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register for the quotient.
+    quotient: Register<N>,
+    /// The destination register for the remainder.
+    remainder: Register<N>,
+}
+
+impl<N: Network> DivRem<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Literal("div_rem")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly two inputs.
+        debug_assert!(self.operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination registers, as `[quotient, remainder]`.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.quotient.clone(), self.remainder.clone()]
+    }
+
+    /// Returns the instruction with each operand and destination register rewritten by the given functions.
+    #[inline]
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            quotient: destination(&self.quotient)?,
+            remainder: destination(&self.remainder)?,
+        })
+    }
+
+    /// Returns `None`, as this instruction is not folded into a literal.
+    #[inline]
+    pub fn fold(&self) -> Option<Literal<N>> {
+        None
+    }
+}
+
+impl<N: Network> DivRem<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Load the operands literals.
+        let first = registers.load_literal(stack, &self.operands[0])?;
+        let second = registers.load_literal(stack, &self.operands[1])?;
+        // Ensure the operands are integers of the same type.
+        let input_types = [first.to_type(), second.to_type()].map(|input| RegisterType::Plaintext(input.into()));
+        self.output_types(stack, &input_types)?;
+
+        // Compute the quotient, which halts on division by zero.
+        let quotient = DivOperation::<N>::evaluate(&[first.clone(), second.clone()])?;
+        // Compute the remainder as `first - second * quotient`, which cannot overflow.
+        let product = MulWrappedOperation::<N>::evaluate(&[second, quotient.clone()])?;
+        let remainder = SubWrappedOperation::<N>::evaluate(&[first, product])?;
+
+        // Store the outputs.
+        registers.store_literal(stack, &self.quotient, quotient)?;
+        registers.store_literal(stack, &self.remainder, remainder)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Load the operands literals.
+        let first = registers.load_literal_circuit(stack, &self.operands[0])?;
+        let second = registers.load_literal_circuit(stack, &self.operands[1])?;
+        // Ensure the operands are integers of the same type.
+        let input_types = [first.to_type(), second.to_type()].map(|input| RegisterType::Plaintext(input.into()));
+        self.output_types(stack, &input_types)?;
+
+        // Compute the quotient, which is unsatisfiable on division by zero.
+        let quotient = DivOperation::<N>::execute::<A>(&[first.clone(), second.clone()])?;
+        // Compute the remainder as `first - second * quotient`, which cannot overflow.
+        let product = MulWrappedOperation::<N>::execute::<A>(&[second, quotient.clone()])?;
+        let remainder = SubWrappedOperation::<N>::execute::<A>(&[first, product])?;
+
+        // Store the outputs.
+        registers.store_literal_circuit(stack, &self.quotient, quotient)?;
+        registers.store_literal_circuit(stack, &self.remainder, remainder)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output types from the given program and input types, as `[quotient, remainder]`.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 2 {
+            bail!("Instruction '{}' expects 2 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Convert all input types into `LiteralType`s. If any are not a `LiteralType`, return an error.
+        let input_types = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => Ok(*literal_type),
+                _ => bail!("Instruction '{}' expects literal operands, found '{input_type}'", Self::opcode()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let input_types: [LiteralType; 2] =
+            input_types.try_into().map_err(|_| anyhow!("Failed to prepare operand types"))?;
+
+        // Note: `rem` is only defined on integers, so this rejects field operands that `div` accepts.
+        let quotient = DivOperation::<N>::output_type(&input_types)?;
+        let remainder = RemOperation::<N>::output_type(&input_types)?;
+
+        Ok(vec![
+            RegisterType::Plaintext(PlaintextType::Literal(quotient)),
+            RegisterType::Plaintext(PlaintextType::Literal(remainder)),
+        ])
+    }
+}
+
+impl<N: Network> Parser for DivRem<N> {
+    /// Parses a string into an operation.
+    /// The operation is of the form `div_rem {first} {second} into {quotient} {remainder}`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the quotient register from the string.
+        let (string, quotient) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the remainder register from the string.
+        let (string, remainder) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second], quotient, remainder }))
+    }
+}
+
+impl<N: Network> FromStr for DivRem<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for DivRem<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for DivRem<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            eprintln!("The number of operands must be 2, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {} {}", self.quotient, self.remainder)
+    }
+}
+
+impl<N: Network> FromBytes for DivRem<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(2);
+        // Read the operands.
+        for _ in 0..2 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the quotient and remainder registers.
+        let quotient = Register::read_le(&mut reader)?;
+        let remainder = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, quotient, remainder })
+    }
+}
+
+impl<N: Network> ToBytes for DivRem<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the quotient and remainder registers.
+        self.quotient.write_le(&mut writer)?;
+        self.remainder.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        process::Stack,
+        program::test_helpers::{sample_finalize_registers, sample_registers},
+        InstructionError,
+    };
+    use circuit::{AleoV0, Eject};
+    use console::{network::Testnet3, program::Identifier};
+    use snarkvm_synthesizer_snark::{ProvingKey, VerifyingKey};
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    type Cache = HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>;
+
+    const MODES: [circuit::Mode; 3] = [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private];
+
+    /// Samples a stack whose `run` function computes `div_rem r0 r1 into r2 r3`, in both the function and finalize.
+    fn sample_stack(
+        literal_type: LiteralType,
+        mode_a: circuit::Mode,
+        mode_b: circuit::Mode,
+        cache: &mut Cache,
+    ) -> Result<Stack<CurrentNetwork>> {
+        use crate::{Process, Program};
+
+        // Initialize the program.
+        let program = Program::from_str(&format!(
+            "program testing.aleo;
+            function run:
+                input r0 as {literal_type}.{mode_a};
+                input r1 as {literal_type}.{mode_b};
+                div_rem r0 r1 into r2 r3;
+                finalize r0 r1;
+
+            finalize run:
+                input r0 as {literal_type}.public;
+                input r1 as {literal_type}.public;
+                div_rem r0 r1 into r2 r3;
+        "
+        ))?;
+
+        // Initialize the stack.
+        Stack::new(&Process::load_with_cache(cache)?, &program)
+    }
+
+    /// Checks `div_rem` evaluates, executes, and finalizes to the expected quotient and remainder.
+    fn check_div_rem(first: &str, second: &str, quotient: &str, remainder: &str, cache: &mut Cache) -> Result<()> {
+        let (first, second) = (Literal::<CurrentNetwork>::from_str(first)?, Literal::from_str(second)?);
+        let (quotient, remainder) = (Literal::<CurrentNetwork>::from_str(quotient)?, Literal::from_str(remainder)?);
+
+        let operation = DivRem::<CurrentNetwork>::from_str("div_rem r0 r1 into r2 r3")?;
+        let function_name = Identifier::from_str("run")?;
+        let (r2, r3) = (Operand::Register(Register::Locator(2)), Operand::Register(Register::Locator(3)));
+
+        for mode_a in MODES {
+            for mode_b in MODES {
+                let stack = sample_stack(first.to_type(), mode_a, mode_b, cache)?;
+
+                // Check the evaluation.
+                let mut registers = sample_registers(&stack, &function_name, &[(&first, None), (&second, None)])?;
+                operation.evaluate(&stack, &mut registers)?;
+                assert_eq!(quotient, registers.load_literal(&stack, &r2)?, "{operation} on {first} {second}");
+                assert_eq!(remainder, registers.load_literal(&stack, &r3)?, "{operation} on {first} {second}");
+
+                // Check the execution.
+                let values = [(&first, Some(mode_a)), (&second, Some(mode_b))];
+                let mut registers = sample_registers(&stack, &function_name, &values)?;
+                operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+                assert_eq!(quotient, registers.load_literal_circuit(&stack, &r2)?.eject_value());
+                assert_eq!(remainder, registers.load_literal_circuit(&stack, &r3)?.eject_value());
+                assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "{first}.{mode_a} {second}.{mode_b}");
+                <CurrentAleo as circuit::Environment>::reset();
+
+                // Check the finalize.
+                let mut registers = sample_finalize_registers(&stack, &function_name, &[&first, &second])?;
+                operation.finalize(&stack, &mut registers)?;
+                assert_eq!(quotient, registers.load_literal(&stack, &r2)?);
+                assert_eq!(remainder, registers.load_literal(&stack, &r3)?);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let (string, div_rem) = DivRem::<CurrentNetwork>::parse("div_rem r0 r1 into r2 r3")?;
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(
+            div_rem.operands(),
+            [Operand::Register(Register::Locator(0)), Operand::Register(Register::Locator(1))]
+        );
+        assert_eq!(div_rem.destinations(), [Register::Locator(2), Register::Locator(3)]);
+        assert_eq!("div_rem r0 r1 into r2 r3", div_rem.to_string());
+
+        // Ensure the bytes round trip.
+        let bytes = div_rem.to_bytes_le()?;
+        assert_eq!(div_rem, DivRem::read_le(&bytes[..])?);
+
+        // Ensure a single destination is rejected.
+        assert!(DivRem::<CurrentNetwork>::from_str("div_rem r0 r1 into r2").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_div_rem() -> Result<()> {
+        let mut cache = Default::default();
+
+        // The remainder takes the sign of the dividend.
+        for (first, second, quotient, remainder) in [
+            ("7i8", "2i8", "3i8", "1i8"),
+            ("-7i16", "2i16", "-3i16", "-1i16"),
+            ("7i32", "-2i32", "-3i32", "1i32"),
+            ("-7i64", "-2i64", "3i64", "-1i64"),
+            (
+                "-170141183460469231731687303715884105728i128",
+                "3i128",
+                "-56713727820156410577229101238628035242i128",
+                "-2i128",
+            ),
+            ("255u8", "16u8", "15u8", "15u8"),
+            ("1000u16", "10u16", "100u16", "0u16"),
+            ("5u32", "9u32", "0u32", "5u32"),
+            ("18446744073709551615u64", "7u64", "2635249153387078802u64", "1u64"),
+            ("0u128", "5u128", "0u128", "0u128"),
+        ] {
+            check_div_rem(first, second, quotient, remainder, &mut cache)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_div_rem_by_zero_halts() -> Result<()> {
+        let mut cache = Default::default();
+        let stack = sample_stack(LiteralType::U32, circuit::Mode::Private, circuit::Mode::Private, &mut cache)?;
+        let operation = DivRem::<CurrentNetwork>::from_str("div_rem r0 r1 into r2 r3")?;
+        let function_name = Identifier::from_str("run")?;
+        let (first, second) = (Literal::from_str("7u32")?, Literal::from_str("0u32")?);
+
+        // Ensure the evaluation halts.
+        let mut registers = sample_registers(&stack, &function_name, &[(&first, None), (&second, None)])?;
+        let error = operation.evaluate(&stack, &mut registers).unwrap_err();
+        assert!(
+            matches!(error.downcast_ref::<InstructionError>(), Some(InstructionError::DivisionByZero(..))),
+            "Division by zero must halt"
+        );
+
+        // Ensure the execution is unsatisfied.
+        let values = [(&first, Some(circuit::Mode::Private)), (&second, Some(circuit::Mode::Private))];
+        let mut registers = sample_registers(&stack, &function_name, &values)?;
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        assert!(!<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_div_rem_rejects_field_operands() -> Result<()> {
+        let mut cache = Default::default();
+        let result = sample_stack(LiteralType::Field, circuit::Mode::Private, circuit::Mode::Private, &mut cache);
+        assert!(result.is_err(), "'div_rem' must not accept field operands");
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    InstructionError,
     Opcode,
     Operand,
     RegistersLoad,
@@ -48,6 +49,13 @@ pub type HashPSD4<N> = HashInstruction<N, { Hasher::PSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashPSD8<N> = HashInstruction<N, { Hasher::PSD8 as u8 }>;
 
+/// HashToGroup maps the input to a group element, by hashing it to a field element with Poseidon2
+/// and mapping the field element onto the curve with Elligator-2.
+pub type HashToGroup<N> = HashInstruction<N, { Hasher::ToGroup as u8 }>;
+/// HashToScalar maps the input to a scalar, by hashing it to a field element with Poseidon2
+/// and truncating the field element to the bit length of the scalar field.
+pub type HashToScalar<N> = HashInstruction<N, { Hasher::ToScalar as u8 }>;
+
 enum Hasher {
     BHP256,
     BHP512,
@@ -58,6 +66,8 @@ enum Hasher {
     PSD2,
     PSD4,
     PSD8,
+    ToGroup,
+    ToScalar,
 }
 
 /// Hashes the operand into the declared type.
@@ -83,6 +93,8 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             6 => Opcode::Hash("hash.psd2"),
             7 => Opcode::Hash("hash.psd4"),
             8 => Opcode::Hash("hash.psd8"),
+            9 => Opcode::Hash("hash.to_group"),
+            10 => Opcode::Hash("hash.to_scalar"),
             _ => panic!("Invalid 'hash' instruction opcode"),
         }
     }
@@ -101,6 +113,36 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the instruction with each operand and destination register rewritten by the given functions.
+    #[inline]
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destination: destination(&self.destination)?,
+        })
+    }
+
+    /// Returns `None`, as this instruction is not folded into a literal.
+    #[inline]
+    pub fn fold(&self) -> Option<Literal<N>> {
+        None
+    }
+}
+
+/// Ensures the input to a Poseidon hash does not exceed `N::MAX_POSEIDON_INPUT_SIZE_IN_FIELDS` field elements.
+fn ensure_poseidon_input_size<N: Network>(num_fields: usize) -> Result<()> {
+    match num_fields <= N::MAX_POSEIDON_INPUT_SIZE_IN_FIELDS {
+        true => Ok(()),
+        false => {
+            let message = format!("Poseidon input exceeds {} field elements.", N::MAX_POSEIDON_INPUT_SIZE_IN_FIELDS);
+            Err(InstructionError::InputTooLarge(message).into())
+        }
+    }
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
@@ -119,19 +161,35 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         let input = registers.load(stack, &self.operands[0])?;
         // Hash the input.
         let output = match VARIANT {
-            0 => N::hash_bhp256(&input.to_bits_le())?,
-            1 => N::hash_bhp512(&input.to_bits_le())?,
-            2 => N::hash_bhp768(&input.to_bits_le())?,
-            3 => N::hash_bhp1024(&input.to_bits_le())?,
-            4 => N::hash_ped64(&input.to_bits_le())?,
-            5 => N::hash_ped128(&input.to_bits_le())?,
-            6 => N::hash_psd2(&input.to_fields()?)?,
-            7 => N::hash_psd4(&input.to_fields()?)?,
-            8 => N::hash_psd8(&input.to_fields()?)?,
+            0 => Literal::Field(N::hash_bhp256(&input.to_bits_le())?),
+            1 => Literal::Field(N::hash_bhp512(&input.to_bits_le())?),
+            2 => Literal::Field(N::hash_bhp768(&input.to_bits_le())?),
+            3 => Literal::Field(N::hash_bhp1024(&input.to_bits_le())?),
+            4 | 5 => {
+                let bits = input.to_bits_le();
+                // Note: A Pedersen hash only fails if the input exceeds the size of its parameters.
+                let output = match VARIANT {
+                    4 => N::hash_ped64(&bits),
+                    _ => N::hash_ped128(&bits),
+                };
+                Literal::Field(output.map_err(|error| InstructionError::InputTooLarge(error.to_string()))?)
+            }
+            6..=10 => {
+                // Ensure the input does not exceed the maximum number of field elements.
+                let fields = input.to_fields()?;
+                ensure_poseidon_input_size::<N>(fields.len())?;
+                match VARIANT {
+                    6 => Literal::Field(N::hash_psd2(&fields)?),
+                    7 => Literal::Field(N::hash_psd4(&fields)?),
+                    8 => Literal::Field(N::hash_psd8(&fields)?),
+                    9 => Literal::Group(N::hash_to_group_psd2(&fields)?),
+                    _ => Literal::Scalar(N::hash_to_scalar_psd2(&fields)?),
+                }
+            }
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
         // Store the output.
-        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Field(output))))
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
     }
 
     /// Executes the instruction.
@@ -151,20 +209,28 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         let input = registers.load_circuit(stack, &self.operands[0])?;
         // Hash the input.
         let output = match VARIANT {
-            0 => A::hash_bhp256(&input.to_bits_le()),
-            1 => A::hash_bhp512(&input.to_bits_le()),
-            2 => A::hash_bhp768(&input.to_bits_le()),
-            3 => A::hash_bhp1024(&input.to_bits_le()),
-            4 => A::hash_ped64(&input.to_bits_le()),
-            5 => A::hash_ped128(&input.to_bits_le()),
-            6 => A::hash_psd2(&input.to_fields()),
-            7 => A::hash_psd4(&input.to_fields()),
-            8 => A::hash_psd8(&input.to_fields()),
+            0 => circuit::Literal::Field(A::hash_bhp256(&input.to_bits_le())),
+            1 => circuit::Literal::Field(A::hash_bhp512(&input.to_bits_le())),
+            2 => circuit::Literal::Field(A::hash_bhp768(&input.to_bits_le())),
+            3 => circuit::Literal::Field(A::hash_bhp1024(&input.to_bits_le())),
+            4 => circuit::Literal::Field(A::hash_ped64(&input.to_bits_le())),
+            5 => circuit::Literal::Field(A::hash_ped128(&input.to_bits_le())),
+            6..=10 => {
+                // Ensure the input does not exceed the maximum number of field elements.
+                let fields = input.to_fields();
+                ensure_poseidon_input_size::<N>(fields.len())?;
+                match VARIANT {
+                    6 => circuit::Literal::Field(A::hash_psd2(&fields)),
+                    7 => circuit::Literal::Field(A::hash_psd4(&fields)),
+                    8 => circuit::Literal::Field(A::hash_psd8(&fields)),
+                    9 => circuit::Literal::Group(A::hash_to_group_psd2(&fields)),
+                    _ => circuit::Literal::Scalar(A::hash_to_scalar_psd2(&fields)),
+                }
+            }
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
         // Convert the output to a stack value.
-        let output =
-            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(output), Default::default()));
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
         // Store the output.
         registers.store_circuit(stack, &self.destination, output)
     }
@@ -201,6 +267,8 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 => {
                 Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
             }
+            9 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Group))]),
+            10 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Scalar))]),
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
@@ -300,7 +368,7 @@ mod tests {
     use super::*;
     use crate::{
         process::Stack,
-        program::test_helpers::{sample_finalize_registers, sample_registers},
+        program::test_helpers::{sample_finalize_registers, sample_registers, sample_value_registers},
     };
     use circuit::{AleoV0, Eject};
     use console::{network::Testnet3, program::Identifier};
@@ -463,6 +531,8 @@ mod tests {
     test_hash!(hash_psd2, HashPSD2);
     test_hash!(hash_psd4, HashPSD4);
     test_hash!(hash_psd8, HashPSD8);
+    test_hash!(hash_to_group, HashToGroup);
+    test_hash!(hash_to_scalar, HashToScalar);
 
     // Note this test must be explicitly written, instead of using the macro, because HashPED64 fails on certain input types.
     #[test]
@@ -536,6 +606,217 @@ mod tests {
         }
     }
 
+    /// Checks that the given Pedersen hash halts on an input that exceeds its number of bits.
+    fn check_hash_ped_halts<const VARIANT: u8>(
+        literal: &Literal<CurrentNetwork>,
+        num_bits: usize,
+        cache: &mut HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>,
+    ) {
+        // Initialize the opcode.
+        let opcode = HashInstruction::<CurrentNetwork, VARIANT>::opcode();
+        // Initialize the stack.
+        let (stack, operands, destination) =
+            sample_stack(opcode, literal.to_type(), circuit::Mode::Private, cache).unwrap();
+        // Initialize the operation.
+        let operation = HashInstruction::<CurrentNetwork, VARIANT> { operands, destination };
+        // Initialize the function name.
+        let function_name = Identifier::from_str("run").unwrap();
+
+        // Ensure the evaluation fails.
+        let mut registers = sample_registers(&stack, &function_name, &[(literal, None)]).unwrap();
+        assert!(operation.evaluate(&stack, &mut registers).is_err(), "'{opcode}' should fail on '{literal}'");
+
+        // Ensure the execution halts with the expected message.
+        let mut registers =
+            sample_registers(&stack, &function_name, &[(literal, Some(circuit::Mode::Private))]).unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            operation.execute::<CurrentAleo>(&stack, &mut registers)
+        }));
+        let message = result.expect_err("The execution should halt").downcast::<String>().unwrap();
+        assert_eq!(*message, format!("The Pedersen hash input cannot exceed {num_bits} bits."));
+
+        // Reset the circuit.
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+
+    #[test]
+    fn test_hash_ped_halts_on_oversized_inputs() {
+        // Prepare the rng.
+        let mut rng = TestRng::default();
+        // Prepare the key cache.
+        let mut cache = Default::default();
+
+        let field = Literal::Field(console::types::Field::rand(&mut rng));
+        check_hash_ped_halts::<4>(&Literal::U64(console::types::U64::rand(&mut rng)), 64, &mut cache);
+        check_hash_ped_halts::<4>(&field, 64, &mut cache);
+        check_hash_ped_halts::<5>(&Literal::U128(console::types::U128::rand(&mut rng)), 128, &mut cache);
+        check_hash_ped_halts::<5>(&field, 128, &mut cache);
+    }
+
+    /// Samples a stack whose `run` function hashes a struct of `num_inner` structs of 32 `u128`s, and a matching input.
+    fn sample_composite(
+        opcode: Opcode,
+        num_inner: usize,
+        mode: circuit::Mode,
+    ) -> Result<(Stack<CurrentNetwork>, Value<CurrentNetwork>)> {
+        use crate::{Process, Program};
+
+        // Initialize the program.
+        let inner_members = (0..32).map(|i| format!("a{i} as u128;")).join(" ");
+        let outer_members = (0..num_inner).map(|i| format!("b{i} as inner;")).join(" ");
+        let program = Program::from_str(&format!(
+            "program testing.aleo;
+            struct inner: {inner_members}
+            struct outer: {outer_members}
+            function run:
+                input r0 as outer.{mode};
+                {opcode} r0 into r1;"
+        ))?;
+        let stack = Stack::new(&Process::load()?, &program)?;
+
+        // Initialize the input.
+        let inner = (0..32).map(|i| format!("a{i}: {}u128", u128::MAX - i)).join(", ");
+        let outer = (0..num_inner).map(|i| format!("b{i}: {{ {inner} }}")).join(", ");
+        Ok((stack, Value::from_str(&format!("{{ {outer} }}"))?))
+    }
+
+    #[test]
+    fn test_hash_psd_composite_input_limit() -> Result<()> {
+        let opcode = HashPSD8::<CurrentNetwork>::opcode();
+        let operation = HashPSD8::<CurrentNetwork>::from_str("hash.psd8 r0 into r1")?;
+        let limit = CurrentNetwork::MAX_POSEIDON_INPUT_SIZE_IN_FIELDS;
+        let expected_error = format!("Poseidon input exceeds {limit} field elements.");
+        let run = Identifier::from_str("run")?;
+
+        // Ensure the limit is inclusive.
+        assert!(ensure_poseidon_input_size::<CurrentNetwork>(limit).is_ok());
+        assert!(ensure_poseidon_input_size::<CurrentNetwork>(limit + 1).is_err());
+
+        // Ensure an over-length composite halts.
+        let (stack, value) = sample_composite(opcode, 12, circuit::Mode::Constant)?;
+        assert!(value.to_fields()?.len() > limit);
+        let mut registers = sample_value_registers(&stack, &run, &[(value.clone(), None)])?;
+        assert_eq!(operation.evaluate(&stack, &mut registers).unwrap_err().to_string(), expected_error);
+        let mut registers = sample_value_registers(&stack, &run, &[(value.clone(), Some(circuit::Mode::Constant))])?;
+        assert_eq!(operation.execute::<CurrentAleo>(&stack, &mut registers).unwrap_err().to_string(), expected_error);
+        <CurrentAleo as circuit::Environment>::reset();
+
+        // Ensure a composite within the limit still hashes, in every mode.
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            let (stack, value) = sample_composite(opcode, 4, mode)?;
+            let fields = value.to_fields()?;
+            assert!(fields.len() <= limit);
+            let expected = Value::Plaintext(Plaintext::from(Literal::Field(CurrentNetwork::hash_psd8(&fields)?)));
+
+            // Check the evaluation.
+            let mut registers = sample_value_registers(&stack, &run, &[(value.clone(), None)])?;
+            operation.evaluate(&stack, &mut registers)?;
+            assert_eq!(registers.load(&stack, &Operand::Register(Register::Locator(1)))?, expected);
+
+            // Check the execution.
+            let mut registers = sample_value_registers(&stack, &run, &[(value.clone(), Some(mode))])?;
+            operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+            let candidate = registers.load_circuit(&stack, &Operand::Register(Register::Locator(1)))?;
+            assert_eq!(candidate.eject_value(), expected);
+            assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_group_is_deterministic() {
+        // Initialize the operation.
+        let opcode = HashToGroup::<CurrentNetwork>::opcode();
+        let mut cache = Default::default();
+        let (stack, operands, destination) =
+            sample_stack(opcode, LiteralType::Field, circuit::Mode::Private, &mut cache).unwrap();
+        let operation = HashToGroup::<CurrentNetwork> { operands, destination: destination.clone() };
+        let function_name = Identifier::from_str("run").unwrap();
+
+        // Evaluates the operation on the given literal.
+        let evaluate = |literal: &Literal<CurrentNetwork>| {
+            let mut registers = sample_registers(&stack, &function_name, &[(literal, None)]).unwrap();
+            operation.evaluate(&stack, &mut registers).unwrap();
+            registers.load(&stack, &Operand::Register(destination.clone())).unwrap()
+        };
+
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            let first = Literal::Field(console::types::Field::rand(&mut rng));
+            let second = Literal::Field(console::types::Field::rand(&mut rng));
+
+            // Ensure the output matches hashing the input fields to a group element.
+            let input = Value::Plaintext(Plaintext::from(first.clone()));
+            let expected = CurrentNetwork::hash_to_group_psd2(&input.to_fields().unwrap()).unwrap();
+            assert_eq!(Value::Plaintext(Plaintext::from(Literal::Group(expected))), evaluate(&first));
+
+            // Ensure the output is deterministic, and distinct inputs map to distinct points.
+            assert_eq!(evaluate(&first), evaluate(&first));
+            assert_ne!(evaluate(&first), evaluate(&second));
+        }
+    }
+
+    #[test]
+    fn test_hash_to_scalar_is_canonical() {
+        // Initialize the operation.
+        let opcode = HashToScalar::<CurrentNetwork>::opcode();
+        let mut cache = Default::default();
+        let (stack, operands, destination) =
+            sample_stack(opcode, LiteralType::U64, circuit::Mode::Private, &mut cache).unwrap();
+        let operation = HashToScalar::<CurrentNetwork> { operands, destination: destination.clone() };
+        let function_name = Identifier::from_str("run").unwrap();
+
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            let literal = Literal::U64(console::types::U64::rand(&mut rng));
+            let mut registers = sample_registers(&stack, &function_name, &[(&literal, None)]).unwrap();
+            operation.evaluate(&stack, &mut registers).unwrap();
+            let candidate = registers.load(&stack, &Operand::Register(destination.clone())).unwrap();
+
+            // Ensure the output matches hashing the input fields to a scalar.
+            let input = Value::Plaintext(Plaintext::from(literal));
+            let expected = CurrentNetwork::hash_to_scalar_psd2(&input.to_fields().unwrap()).unwrap();
+            assert_eq!(Value::Plaintext(Plaintext::from(Literal::Scalar(expected))), candidate);
+            // Ensure the scalar is nonzero.
+            assert!(!expected.is_zero());
+        }
+    }
+
+    #[test]
+    fn test_hash_psd2_uses_rate_two() {
+        let mut cache = Default::default();
+        let function_name = Identifier::from_str("run").unwrap();
+
+        // Evaluates the given opcode on the given literal.
+        let mut evaluate = |opcode: Opcode, literal: &Literal<CurrentNetwork>| {
+            let (stack, operands, destination) =
+                sample_stack(opcode, literal.to_type(), circuit::Mode::Private, &mut cache).unwrap();
+            let mut registers = sample_registers(&stack, &function_name, &[(literal, None)]).unwrap();
+            match opcode {
+                Opcode::Hash("hash.psd2") => HashPSD2::<CurrentNetwork> { operands, destination: destination.clone() }
+                    .evaluate(&stack, &mut registers)
+                    .unwrap(),
+                _ => HashPSD4::<CurrentNetwork> { operands, destination: destination.clone() }
+                    .evaluate(&stack, &mut registers)
+                    .unwrap(),
+            }
+            registers.load(&stack, &Operand::Register(destination)).unwrap()
+        };
+
+        let mut rng = TestRng::default();
+        for literal in crate::sample_literals!(CurrentNetwork, &mut rng).iter() {
+            // Ensure the output matches the rate-2 Poseidon hash of the input fields.
+            let input = Value::Plaintext(Plaintext::from(literal.clone()));
+            let expected = CurrentNetwork::hash_psd2(&input.to_fields().unwrap()).unwrap();
+            let candidate = evaluate(HashPSD2::<CurrentNetwork>::opcode(), literal);
+            assert_eq!(Value::Plaintext(Plaintext::from(Literal::Field(expected))), candidate);
+
+            // Ensure the output differs from the rate-4 Poseidon hash.
+            assert_ne!(candidate, evaluate(HashPSD4::<CurrentNetwork>::opcode(), literal));
+        }
+    }
+
     #[test]
     fn test_parse() {
         let (string, hash) = HashBHP512::<CurrentNetwork>::parse("hash.bhp512 r0 into r1").unwrap();
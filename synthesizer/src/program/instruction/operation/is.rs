@@ -72,9 +72,40 @@ impl<N: Network, const VARIANT: u8> IsInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the instruction with each operand and destination register rewritten by the given functions.
+    #[inline]
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destination: destination(&self.destination)?,
+        })
+    }
+
+    /// Returns `None`, as this instruction is not folded into a literal.
+    #[inline]
+    pub fn fold(&self) -> Option<Literal<N>> {
+        None
+    }
 }
 
 impl<N: Network, const VARIANT: u8> IsInstruction<N, VARIANT> {
+    /// Ensures the given inputs are of the same type, as an equality check across types is always a program error.
+    fn ensure_same_type(&self, input_a: &Value<N>, input_b: &Value<N>) -> Result<()> {
+        ensure!(
+            is_same_type(input_a, input_b),
+            "Instruction '{}' expects inputs of the same type. Found mismatched types for '{}' and '{}'",
+            Self::opcode(),
+            self.operands[0],
+            self.operands[1]
+        );
+        Ok(())
+    }
+
     /// Evaluates the instruction.
     #[inline]
     pub fn evaluate(
@@ -90,6 +121,8 @@ impl<N: Network, const VARIANT: u8> IsInstruction<N, VARIANT> {
         // Retrieve the inputs.
         let input_a = registers.load(stack, &self.operands[0])?;
         let input_b = registers.load(stack, &self.operands[1])?;
+        // Ensure the inputs are of the same type.
+        self.ensure_same_type(&input_a, &input_b)?;
 
         // Check the inputs.
         let output = match VARIANT {
@@ -116,6 +149,11 @@ impl<N: Network, const VARIANT: u8> IsInstruction<N, VARIANT> {
         // Retrieve the inputs.
         let input_a = registers.load_circuit(stack, &self.operands[0])?;
         let input_b = registers.load_circuit(stack, &self.operands[1])?;
+        // Ensure the inputs are of the same type.
+        {
+            use circuit::Eject;
+            self.ensure_same_type(&input_a.eject_value(), &input_b.eject_value())?;
+        }
 
         // Check the inputs.
         let output = match VARIANT {
@@ -171,6 +209,36 @@ impl<N: Network, const VARIANT: u8> IsInstruction<N, VARIANT> {
     }
 }
 
+/// Returns `true` if the given values are of the same type, comparing records and structs member-wise.
+fn is_same_type<N: Network>(a: &Value<N>, b: &Value<N>) -> bool {
+    match (a, b) {
+        (Value::Plaintext(a), Value::Plaintext(b)) => is_same_plaintext_type(a, b),
+        (Value::Record(a), Value::Record(b)) => {
+            a.owner().is_public() == b.owner().is_public()
+                && a.data().len() == b.data().len()
+                && a.data().iter().zip(b.data()).all(|((name_a, entry_a), (name_b, entry_b))| {
+                    name_a == name_b
+                        && entry_a.is_constant() == entry_b.is_constant()
+                        && entry_a.is_public() == entry_b.is_public()
+                        && is_same_plaintext_type(entry_a.plaintext(), entry_b.plaintext())
+                })
+        }
+        (Value::Plaintext(..), _) | (Value::Record(..), _) => false,
+    }
+}
+
+/// Returns `true` if the given plaintexts are of the same type.
+fn is_same_plaintext_type<N: Network>(a: &Plaintext<N>, b: &Plaintext<N>) -> bool {
+    match (a, b) {
+        (Plaintext::Literal(a, _), Plaintext::Literal(b, _)) => a.to_type() == b.to_type(),
+        (Plaintext::Struct(a, _), Plaintext::Struct(b, _)) => {
+            a.len() == b.len()
+                && a.iter().zip(b).all(|((name_a, a), (name_b, b))| name_a == name_b && is_same_plaintext_type(a, b))
+        }
+        (Plaintext::Literal(..), _) | (Plaintext::Struct(..), _) => false,
+    }
+}
+
 impl<N: Network, const VARIANT: u8> Parser for IsInstruction<N, VARIANT> {
     /// Parses a string into an operation.
     #[inline]
@@ -274,7 +342,7 @@ mod tests {
     use super::*;
     use crate::{
         process::Stack,
-        program::test_helpers::{sample_finalize_registers, sample_registers},
+        program::test_helpers::{sample_finalize_registers, sample_registers, sample_value_registers},
     };
     use circuit::AleoV0;
     use console::{network::Testnet3, program::Identifier};
@@ -690,6 +758,113 @@ mod tests {
         }
     }
 
+    /// Samples a stack for comparing structs and records.
+    fn sample_composite_stack() -> Result<Stack<CurrentNetwork>> {
+        use crate::{Process, Program};
+
+        let program = Program::from_str(
+            "program testing.aleo;
+            struct point:
+                x as field;
+                y as field;
+
+            record token:
+                owner as address.private;
+                amount as u64.private;
+
+            function structs:
+                input r0 as point.private;
+                input r1 as point.private;
+                is.eq r0 r1 into r2;
+
+            function records:
+                input r0 as token.record;
+                input r1 as token.record;
+                is.eq r0 r1 into r2;
+
+            function mismatched:
+                input r0 as point.private;
+                input r1 as field.private;",
+        )?;
+        Stack::new(&Process::load()?, &program)
+    }
+
+    /// Checks `is.eq` and `is.neq` on the given composite values, in console and in every circuit mode.
+    fn check_composite(function_name: &str, a: &str, b: &str, expected: bool) -> Result<()> {
+        use circuit::Eject;
+
+        let stack = sample_composite_stack()?;
+        let function_name = Identifier::from_str(function_name)?;
+        let (a, b) = (Value::from_str(a)?, Value::from_str(b)?);
+        let values = |mode| [(a.clone(), mode), (b.clone(), mode)];
+        let destination = Operand::Register(Register::Locator(2));
+        let is_eq = IsEq::<CurrentNetwork>::from_str("is.eq r0 r1 into r2")?;
+        let is_neq = IsNeq::<CurrentNetwork>::from_str("is.neq r0 r1 into r2")?;
+
+        // Check the console evaluation.
+        let mut registers = sample_value_registers(&stack, &function_name, &values(None))?;
+        is_eq.evaluate(&stack, &mut registers)?;
+        assert_eq!(Literal::Boolean(Boolean::new(expected)), registers.load_literal(&stack, &destination)?);
+        let mut registers = sample_value_registers(&stack, &function_name, &values(None))?;
+        is_neq.evaluate(&stack, &mut registers)?;
+        assert_eq!(Literal::Boolean(Boolean::new(!expected)), registers.load_literal(&stack, &destination)?);
+
+        // Check the circuit execution.
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            let mut registers = sample_value_registers(&stack, &function_name, &values(Some(mode)))?;
+            is_eq.execute::<CurrentAleo>(&stack, &mut registers)?;
+            let output = registers.load_literal_circuit(&stack, &destination)?.eject_value();
+            assert_eq!(Literal::Boolean(Boolean::new(expected)), output);
+
+            let mut registers = sample_value_registers(&stack, &function_name, &values(Some(mode)))?;
+            is_neq.execute::<CurrentAleo>(&stack, &mut registers)?;
+            let output = registers.load_literal_circuit(&stack, &destination)?.eject_value();
+            assert_eq!(Literal::Boolean(Boolean::new(!expected)), output);
+
+            assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_eq_structs() -> Result<()> {
+        check_composite("structs", "{ x: 1field, y: 2field }", "{ x: 1field, y: 2field }", true)?;
+        check_composite("structs", "{ x: 1field, y: 2field }", "{ x: 1field, y: 3field }", false)
+    }
+
+    #[test]
+    fn test_is_eq_records() -> Result<()> {
+        let owner = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+        let record =
+            |amount: u64| format!("{{ owner: {owner}.private, amount: {amount}u64.private, _nonce: 0group.public }}");
+        check_composite("records", &record(100), &record(100), true)?;
+        // Ensure records differing only in a private entry are unequal.
+        check_composite("records", &record(100), &record(101), false)
+    }
+
+    #[test]
+    fn test_is_eq_halts_on_mismatched_types() -> Result<()> {
+        let stack = sample_composite_stack()?;
+        let mismatched = Identifier::from_str("mismatched")?;
+        let (point, field) = (Value::from_str("{ x: 1field, y: 2field }")?, Value::from_str("1field")?);
+        let values = |mode| [(point.clone(), mode), (field.clone(), mode)];
+        let is_eq = IsEq::<CurrentNetwork>::from_str("is.eq r0 r1 into r2")?;
+        let is_neq = IsNeq::<CurrentNetwork>::from_str("is.neq r0 r1 into r2")?;
+
+        // Ensure evaluation halts rather than returning `false`.
+        let mut registers = sample_value_registers(&stack, &mismatched, &values(None))?;
+        let error = is_eq.evaluate(&stack, &mut registers).unwrap_err();
+        assert!(error.to_string().contains("expects inputs of the same type"), "Unexpected error: {error}");
+        assert!(is_neq.evaluate(&stack, &mut registers).is_err());
+
+        // Ensure execution halts rather than returning `false`.
+        let mut registers = sample_value_registers(&stack, &mismatched, &values(Some(circuit::Mode::Private)))?;
+        assert!(is_eq.execute::<CurrentAleo>(&stack, &mut registers).is_err());
+        assert!(is_neq.execute::<CurrentAleo>(&stack, &mut registers).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parse() {
         let (string, is) = IsEq::<CurrentNetwork>::parse("is.eq r0 r1 into r2").unwrap();
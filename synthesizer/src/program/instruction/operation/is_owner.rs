@@ -0,0 +1,394 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    Opcode,
+    Operand,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+    StackMatches,
+    StackProgram,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Computes whether the record in `first` is owned by the address in `second` as a boolean,
+/// storing the outcome in `destination`, i.e. `is.owner r0 r1 into r2;`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IsOwner<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> IsOwner<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Is("is.owner")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly two inputs.
+        debug_assert!(self.operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the instruction with each operand and destination register rewritten by the given functions.
+    #[inline]
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destination: destination(&self.destination)?,
+        })
+    }
+
+    /// Returns `None`, as this instruction is not folded into a literal.
+    #[inline]
+    pub fn fold(&self) -> Option<Literal<N>> {
+        None
+    }
+}
+
+impl<N: Network> IsOwner<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the record.
+        let record = match registers.load(stack, &self.operands[0])? {
+            Value::Record(record) => record,
+            Value::Plaintext(..) => bail!("Instruction '{}' expects a record as its first operand", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match registers.load_literal(stack, &self.operands[1])? {
+            Literal::Address(address) => address,
+            _ => bail!("Instruction '{}' expects an address as its second operand", Self::opcode()),
+        };
+
+        // Check the record owner, and store the output.
+        let output = Literal::Boolean(Boolean::new(**record.owner() == address));
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the record.
+        let record = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Record(record) => record,
+            circuit::Value::Plaintext(..) => {
+                bail!("Instruction '{}' expects a record as its first operand", Self::opcode())
+            }
+        };
+        // Retrieve the address.
+        let address = match registers.load_literal_circuit(stack, &self.operands[1])? {
+            circuit::Literal::Address(address) => address,
+            _ => bail!("Instruction '{}' expects an address as its second operand", Self::opcode()),
+        };
+
+        // Check the record owner, and store the output.
+        let output = circuit::Literal::Boolean(record.owner().is_equal(&address));
+        registers.store_literal_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 2 {
+            bail!("Instruction '{}' expects 2 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+        // Ensure the first operand is a record.
+        if !matches!(input_types[0], RegisterType::Record(..) | RegisterType::ExternalRecord(..)) {
+            bail!("Instruction '{}' expects a record as its first operand, found '{}'", Self::opcode(), input_types[0])
+        }
+        // Ensure the second operand is an address.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)) {
+            bail!(
+                "Instruction '{}' expects an address as its second operand, found '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network> Parser for IsOwner<N> {
+    /// Parses a string into an operation.
+    /// The operation is of the form `is.owner {record} {address} into {destination}`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the record operand from the string.
+        let (string, record) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the address operand from the string.
+        let (string, address) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![record, address], destination }))
+    }
+}
+
+impl<N: Network> FromStr for IsOwner<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for IsOwner<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for IsOwner<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            eprintln!("The number of operands must be 2, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for IsOwner<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operands.
+        let operands = vec![Operand::read_le(&mut reader)?, Operand::read_le(&mut reader)?];
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for IsOwner<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process::Stack, program::test_helpers::sample_value_registers};
+    use circuit::{AleoV0, Eject};
+    use console::{
+        account::{Address, PrivateKey},
+        network::Testnet3,
+        program::{Identifier, Plaintext},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    const MODES: [circuit::Mode; 3] = [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private];
+
+    /// Samples a stack whose `run` function checks the owner of a record, and whose `plain` function takes a field.
+    fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+        use crate::{Process, Program};
+
+        let program = Program::from_str(
+            "program testing.aleo;
+            record token:
+                owner as address.private;
+                amount as u64.private;
+
+            function run:
+                input r0 as token.record;
+                input r1 as address.private;
+                is.owner r0 r1 into r2;
+
+            function plain:
+                input r0 as field.private;
+                input r1 as address.private;",
+        )?;
+        Stack::new(&Process::load()?, &program)
+    }
+
+    /// Returns a record owned by the given address.
+    fn sample_record(owner: Address<CurrentNetwork>) -> Value<CurrentNetwork> {
+        Value::from_str(&format!("{{ owner: {owner}.private, amount: 5u64.private, _nonce: 0group.public }}")).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let (string, is_owner) = IsOwner::<CurrentNetwork>::parse("is.owner r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(is_owner.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(is_owner.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(is_owner.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(is_owner.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!("is.owner r0 r1 into r2", is_owner.to_string());
+    }
+
+    #[test]
+    fn test_is_owner() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let stack = sample_stack()?;
+        let operation = IsOwner::<CurrentNetwork>::from_str("is.owner r0 r1 into r2")?;
+        let destination = Operand::Register(Register::Locator(2));
+
+        // Sample the owner, and another address.
+        let owner = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let other = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let record = sample_record(owner);
+        let run = Identifier::from_str("run")?;
+
+        for (address, expected) in [(owner, true), (other, false)] {
+            let address = Value::Plaintext(Plaintext::from(Literal::Address(address)));
+            let values = |mode| [(record.clone(), mode), (address.clone(), mode)];
+            let expected = Literal::Boolean(Boolean::new(expected));
+
+            // Check the console evaluation.
+            let mut registers = sample_value_registers(&stack, &run, &values(None))?;
+            operation.evaluate(&stack, &mut registers)?;
+            assert_eq!(expected, registers.load_literal(&stack, &destination)?);
+
+            // Check the circuit execution.
+            for mode in MODES {
+                let mut registers = sample_value_registers(&stack, &run, &values(Some(mode)))?;
+                operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+                assert_eq!(expected, registers.load_literal_circuit(&stack, &destination)?.eject_value());
+                assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+                <CurrentAleo as circuit::Environment>::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_owner_halts_on_non_record() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let stack = sample_stack()?;
+        let operation = IsOwner::<CurrentNetwork>::from_str("is.owner r0 r1 into r2")?;
+
+        // Ensure a plaintext in place of the record is rejected.
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let (field, address) =
+            (Value::from_str("1field")?, Value::Plaintext(Plaintext::from(Literal::Address(address))));
+        let values = |mode| [(field.clone(), mode), (address.clone(), mode)];
+        let plain = Identifier::from_str("plain")?;
+        let mut registers = sample_value_registers(&stack, &plain, &values(None))?;
+        let error = operation.evaluate(&stack, &mut registers).unwrap_err();
+        assert_eq!(error.to_string(), "Instruction 'is.owner' expects a record as its first operand");
+        let mut registers = sample_value_registers(&stack, &plain, &values(Some(circuit::Mode::Private)))?;
+        assert!(operation.execute::<CurrentAleo>(&stack, &mut registers).is_err());
+
+        // Ensure the operand types are checked.
+        let record = RegisterType::Record(Identifier::from_str("token")?);
+        let address = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
+        let field = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field));
+        assert!(operation.output_types(&stack, &[record, address]).is_ok());
+        assert!(operation.output_types(&stack, &[field, address]).is_err());
+        assert!(operation.output_types(&stack, &[record, field]).is_err());
+        assert!(operation.output_types(&stack, &[address, record]).is_err());
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    InstructionError,
     Opcode,
     Operand,
     Operation,
@@ -67,6 +68,40 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the instruction with each operand and destination register rewritten by the given functions.
+    #[inline]
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operands: self.operands.iter().map(operand).collect::<Result<_>>()?,
+            destination: destination(&self.destination)?,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the result of the operation if every operand is a literal, or `None` otherwise.
+    /// Note: An operation that fails on its literal operands is not folded, so that it still halts.
+    #[inline]
+    pub fn fold(&self) -> Option<Literal<N>> {
+        // Retrieve the literal operands.
+        let inputs = self
+            .operands
+            .iter()
+            .map(|operand| match operand {
+                Operand::Literal(literal) => Some(literal.clone()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        // Prepare the inputs.
+        let inputs: [Literal<N>; NUM_OPERANDS] = inputs.try_into().ok()?;
+        // Evaluate the operation.
+        // Note: The operations on literals report overflows and divisions by zero as errors, instead of halting.
+        O::evaluate(&inputs).ok()
+    }
 }
 
 impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const NUM_OPERANDS: usize>
@@ -179,11 +214,11 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
             .copied()
             .map(|input_type| match input_type {
                 RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => Ok(literal_type),
-                RegisterType::Plaintext(PlaintextType::Struct(..)) => {
-                    bail!("Expected literal type, found '{input_type}'")
+                RegisterType::Plaintext(PlaintextType::Struct(..))
+                | RegisterType::Record(..)
+                | RegisterType::ExternalRecord(..) => {
+                    bail!("Instruction '{}' expects literal operands, found '{input_type}'", O::OPCODE)
                 }
-                RegisterType::Record(..) => bail!("Expected literal type, found '{input_type}'"),
-                RegisterType::ExternalRecord(..) => bail!("Expected literal type, found '{input_type}'"),
             })
             .collect::<Result<Vec<_>>>()?;
 
@@ -193,14 +228,40 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
         // Return the output type.
         Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(output))])
     }
-}
 
-impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const NUM_OPERANDS: usize> Parser
-    for Literals<N, O, NUM_OPERANDS>
-{
-    /// Parses a string into an operation.
+    /// Ensures the literal operands do not mix integer types that the operation rejects, e.g. `add 5u8 3i8`.
+    /// Note: The types of register operands are only known from the stack, so this check is skipped for them.
+    fn check_operand_types(operands: &[Operand<N>]) -> Result<()> {
+        // Retrieve the literal types, if every operand is a literal.
+        let literal_types = operands
+            .iter()
+            .map(|operand| match operand {
+                Operand::Literal(literal) => Some(literal.to_type()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+        let literal_types: [LiteralType; NUM_OPERANDS] = match literal_types.map(TryInto::try_into) {
+            Some(Ok(literal_types)) => literal_types,
+            _ => return Ok(()),
+        };
+        // If the operation supports the literal types, return early.
+        if O::output_type(&literal_types).is_ok() {
+            return Ok(());
+        }
+        // Ensure the integer types match.
+        let mut integer_types = literal_types.iter().filter(|literal_type| literal_type.is_integer());
+        if let Some(first) = integer_types.next() {
+            if let Some(second) = integer_types.find(|literal_type| *literal_type != first) {
+                let message = format!("operand type mismatch: {first} vs {second}");
+                return Err(InstructionError::TypeMismatch(message).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a string into an operation, without checking the types of the literal operands.
     #[inline]
-    fn parse(string: &str) -> ParserResult<Self> {
+    fn parse_unchecked(string: &str) -> ParserResult<Self> {
         // Parse the opcode from the string.
         let (string, _) = tag(*O::OPCODE)(string)?;
         // Parse the whitespace from the string.
@@ -240,6 +301,22 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
     }
 }
 
+impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const NUM_OPERANDS: usize> Parser
+    for Literals<N, O, NUM_OPERANDS>
+{
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string.
+        let (string, operation) = Self::parse_unchecked(string)?;
+        // Ensure the literal operands have compatible types.
+        // Note: The parser error does not carry the message, which `Instruction::from_str` recovers instead.
+        map_res(take(0usize), move |_| Self::check_operand_types(&operation.operands).map(|_| operation.clone()))(
+            string,
+        )
+    }
+}
+
 impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const NUM_OPERANDS: usize> FromStr
     for Literals<N, O, NUM_OPERANDS>
 {
@@ -248,10 +325,12 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
     /// Parses a string into an operation.
     #[inline]
     fn from_str(string: &str) -> Result<Self> {
-        match Self::parse(string) {
+        match Self::parse_unchecked(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
                 ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Ensure the literal operands have compatible types.
+                Self::check_operand_types(&object.operands)?;
                 // Return the object.
                 Ok(object)
             }
@@ -37,23 +37,23 @@
 #[macro_export]
 macro_rules! operation {
     // Unary operation.
-    ($vis:vis struct $name:ident<$operator:path, $circuit_operator:path, $operate:ident, $opcode:tt> { $( $input:ident => $output:ident $( ($($condition:tt),+) )?, )+ }) => {
-        $crate::operation!($vis struct $name<$operator, $circuit_operator, $operate, $opcode, 1> { $( ($input) => $output $( ( $($condition),+ ) )?, )+ });
+    ($vis:vis struct $name:ident<$operator:path, $circuit_operator:path, $operate:ident, $opcode:tt> { $( $input:ident => $output:ident $($checked:ident)? $( ($($condition:tt),+) )?, )+ }) => {
+        $crate::operation!($vis struct $name<$operator, $circuit_operator, $operate, $opcode, 1> { $( ($input) => $output $($checked)? $( ( $($condition),+ ) )?, )+ });
     };
     // Unary operation with question mark (?).
-    ($vis:vis struct $name:ident<$operator:path, $circuit_operator:path, $operate:ident?, $opcode:tt> { $( $input:ident => $output:ident $( ($($condition:tt),+) )?, )+ }) => {
-        $crate::operation!($vis struct $name<$operator, $circuit_operator, $operate?, $opcode, 1> { $( ($input) => $output $( ( $($condition),+ ) )?, )+ });
+    ($vis:vis struct $name:ident<$operator:path, $circuit_operator:path, $operate:ident?, $opcode:tt> { $( $input:ident => $output:ident $($checked:ident)? $( ($($condition:tt),+) )?, )+ }) => {
+        $crate::operation!($vis struct $name<$operator, $circuit_operator, $operate?, $opcode, 1> { $( ($input) => $output $($checked)? $( ( $($condition),+ ) )?, )+ });
     };
     // Binary operation.
-    ($vis:vis struct $name:ident<$operator:path, $circuit_operator:path, $operate:ident, $opcode:tt> { $( ($input_a:ident, $input_b:ident) => $output:ident $( ($($condition:tt),+) )?, )+ }) => {
-        $crate::operation!($vis struct $name<$operator, $circuit_operator, $operate, $opcode, 2> { $( ($input_a, $input_b) => $output $( ( $($condition),+ ) )?, )+ });
+    ($vis:vis struct $name:ident<$operator:path, $circuit_operator:path, $operate:ident, $opcode:tt> { $( ($input_a:ident, $input_b:ident) => $output:ident $($checked:ident)? $( ($($condition:tt),+) )?, )+ }) => {
+        $crate::operation!($vis struct $name<$operator, $circuit_operator, $operate, $opcode, 2> { $( ($input_a, $input_b) => $output $($checked)? $( ( $($condition),+ ) )?, )+ });
     };
     // Ternary operation.
-    ($vis:vis struct $name:ident<$operator:path, $circuit_operator:path, $operate:ident, $opcode:tt> { $( ($input_a:ident, $input_b:ident, $input_c:ident) => $output:ident $( ($($condition:tt),+) )?, )+ }) => {
-        $crate::operation!($vis struct $name<$operator, $circuit_operator, $operate, $opcode, 3> { $( ($input_a, $input_b, $input_c) => $output $( ( $($condition),+ ) )?, )+ });
+    ($vis:vis struct $name:ident<$operator:path, $circuit_operator:path, $operate:ident, $opcode:tt> { $( ($input_a:ident, $input_b:ident, $input_c:ident) => $output:ident $($checked:ident)? $( ($($condition:tt),+) )?, )+ }) => {
+        $crate::operation!($vis struct $name<$operator, $circuit_operator, $operate, $opcode, 3> { $( ($input_a, $input_b, $input_c) => $output $($checked)? $( ( $($condition),+ ) )?, )+ });
     };
     // K-ary operation.
-    ($vis:vis struct $name:ident<$operator:path, $circuit_operator:path, $operate:ident, $opcode:tt, $num_inputs:tt> { $( ( $($input:ident),+ ) => $output:ident $( ($($condition:tt),+) )?, )+ }) => {
+    ($vis:vis struct $name:ident<$operator:path, $circuit_operator:path, $operate:ident, $opcode:tt, $num_inputs:tt> { $( ( $($input:ident),+ ) => $output:ident $($checked:ident)? $( ($($condition:tt),+) )?, )+ }) => {
         /// The implementation of the binary operation.
         #[derive(Clone, PartialEq, Eq, Hash)]
         $vis struct $name<N: Network>(core::marker::PhantomData<N>);
@@ -68,7 +68,7 @@ macro_rules! operation {
                 // Prepare the operator.
                 use $operator as Operator;
                 // Compute the output.
-                Ok($crate::evaluate!(match Operator::$operate(inputs) { $( ( $($input),+ ) => $output, )+ }))
+                Ok($crate::evaluate!(match Operator::$operate(inputs) { $( ( $($input),+ ) => $output $($checked)?, )+ }))
             }
 
             /// Returns the result of executing the operation on the given circuit inputs.
@@ -108,7 +108,7 @@ macro_rules! operation {
         }
     };
     // K-ary operation with question mark (?).
-    ($vis:vis struct $name:ident<$operator:path, $circuit_operator:path, $operate:ident?, $opcode:tt, $num_inputs:tt> { $( ( $($input:ident),+ ) => $output:ident $( ($($condition:tt),+) )?, )+ }) => {
+    ($vis:vis struct $name:ident<$operator:path, $circuit_operator:path, $operate:ident?, $opcode:tt, $num_inputs:tt> { $( ( $($input:ident),+ ) => $output:ident $($checked:ident)? $( ($($condition:tt),+) )?, )+ }) => {
         /// The implementation of the binary operation.
         #[derive(Clone, PartialEq, Eq, Hash)]
         $vis struct $name<N: Network>(core::marker::PhantomData<N>);
@@ -186,13 +186,13 @@ macro_rules! operation {
 #[macro_export]
 macro_rules! evaluate {
     // Unary operation.
-    (match $operator:tt::$operate:tt($inputs:expr) { $( ($input:ident) => $output:ident, )+ }) => {{
+    (match $operator:tt::$operate:tt($inputs:expr) { $( ($input:ident) => $output:ident $($checked:ident)?, )+ }) => {{
         // Retrieve the operand.
         let [first] = $inputs;
         // Compute the output.
         match first {
-            $(console::program::Literal::$input(first) => console::program::Literal::$output(first.$operate()),)+
-            _ => bail!("Invalid operand for the '{}' instruction", Self::OPCODE),
+            $(console::program::Literal::$input(first) => console::program::Literal::$output($crate::evaluate_operator!($($checked)? first.$operate())),)+
+            _ => return Err($crate::InstructionError::TypeMismatch(format!("Invalid operand for the '{}' instruction", Self::OPCODE)).into()),
         }
     }};
     // Unary operation with question mark (?).
@@ -202,17 +202,17 @@ macro_rules! evaluate {
         // Compute the output.
         match first {
             $(console::program::Literal::$input(first) => console::program::Literal::$output(first.$operate()?),)+
-            _ => bail!("Invalid operand for the '{}' instruction", Self::OPCODE),
+            _ => return Err($crate::InstructionError::TypeMismatch(format!("Invalid operand for the '{}' instruction", Self::OPCODE)).into()),
         }
     }};
     // Binary operation.
-    (match $operator:tt::$operate:tt($inputs:expr) { $( ($input_a:ident, $input_b:ident) => $output:ident, )+ }) => {{
+    (match $operator:tt::$operate:tt($inputs:expr) { $( ($input_a:ident, $input_b:ident) => $output:ident $($checked:ident)?, )+ }) => {{
         // Retrieve the operands.
         let [first, second] = $inputs;
         // Compute the output.
         match (first, second) {
-            $((console::program::Literal::$input_a(first), console::program::Literal::$input_b(second)) => console::program::Literal::$output(first.$operate(second)),)+
-            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+            $((console::program::Literal::$input_a(first), console::program::Literal::$input_b(second)) => console::program::Literal::$output($crate::evaluate_operator!($($checked)? first.$operate(second))),)+
+            _ => return Err($crate::InstructionError::TypeMismatch(format!("Invalid operands for the '{}' instruction", Self::OPCODE)).into()),
         }
     }};
     // Ternary operation.
@@ -222,11 +222,46 @@ macro_rules! evaluate {
         // Compute the output.
         match (first, second, third) {
             $((console::program::Literal::$input_a(first), console::program::Literal::$input_b(second), console::program::Literal::$input_c(third)) => console::program::Literal::$output($operator::$operate(first, second, third)),)+
-            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+            _ => return Err($crate::InstructionError::TypeMismatch(format!("Invalid operands for the '{}' instruction", Self::OPCODE)).into()),
         }
     }};
 }
 
+/// Applies the console operator to the operands, for an operation arm in `evaluate!`.
+///
+/// If the arm is marked as `checked`, the fallible `try_` variant of the operator is used,
+/// and the error is returned as an `InstructionError`, instead of halting the evaluation.
+/// A checked binary operation fails on a division by zero if the second operand is zero,
+/// and on an overflow otherwise.
+///
+/// ## Example
+/// ```ignore
+/// evaluate_operator!(checked first.add(second))
+/// ```
+/// The above example is equivalent to `first.try_add(second)?`, with the error mapped to `InstructionError::Overflow`.
+#[macro_export]
+macro_rules! evaluate_operator {
+    // A checked unary operation.
+    (checked $first:ident.$operate:ident()) => {
+        paste::paste! {
+            $first.[<try_ $operate>]().map_err(|error| $crate::InstructionError::Overflow(error.to_string()))?
+        }
+    };
+    // A checked binary operation.
+    (checked $first:ident.$operate:ident($second:ident)) => {
+        paste::paste! {
+            $first.[<try_ $operate>]($second).map_err(|error| match console::prelude::Zero::is_zero($second) {
+                true => $crate::InstructionError::DivisionByZero(error.to_string()),
+                false => $crate::InstructionError::Overflow(error.to_string()),
+            })?
+        }
+    };
+    // An operation that does not halt.
+    ($first:ident.$operate:ident($($second:ident)?)) => {
+        $first.$operate($($second)?)
+    };
+}
+
 /// Creates a match statement that executes the operation.
 ///
 /// ## Example
@@ -748,6 +783,8 @@ mod tests {
                         let mut shift_exceeds_bitwidth = false;
                         #[allow(unused_mut)]
                         let mut is_division_operator = false;
+                        #[allow(unused_mut)]
+                        let mut is_bit_operator = false;
                         /// A helper macro to check the conditions.
                         #[allow(unused_macros)]
                         macro_rules! check_condition {
@@ -780,6 +817,11 @@ mod tests {
                                 // This indicator is later used in the for-loops below.
                                 is_division_operator |= true;
                             };
+                            ("ensure bit index out of range halts") => {
+                                should_succeed &= (*b as u64) < $input_a::<CurrentNetwork>::size_in_bits() as u64;
+                                // This indicator is later used in the for-loops below.
+                                is_bit_operator |= true;
+                            };
                         }
                         // Check the conditions.
                         $( $( check_condition!($condition); )+ )?
@@ -809,6 +851,8 @@ mod tests {
                                 should_panic_on_halt |= is_division_operator && (
                                     mode_b.is_constant() && (mode_a.is_constant() || is_rhs_zero)
                                 );
+                                // If the operation is a bit operator, check if the mode of the index is a constant.
+                                should_panic_on_halt |= is_bit_operator && mode_b.is_constant();
 
                                 // If this iteration should succeed, ensure the evaluated and executed outputs match the expected output.
                                 if should_succeed {
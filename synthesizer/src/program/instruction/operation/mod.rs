@@ -21,15 +21,24 @@ pub use call::*;
 mod cast;
 pub use cast::*;
 
+mod cast_bytes;
+pub use cast_bytes::*;
+
 mod commit;
 pub use commit::*;
 
+mod div_rem;
+pub use div_rem::*;
+
 mod hash;
 pub use hash::*;
 
 mod is;
 pub use is::*;
 
+mod is_owner;
+pub use is_owner::*;
+
 mod literals;
 pub use literals::*;
 
@@ -59,11 +68,11 @@ pub type Abs<N> = UnaryLiteral<N, AbsOperation<N>>;
 
 crate::operation!(
     pub struct AbsOperation<console::prelude::AbsChecked, circuit::prelude::AbsChecked, abs_checked, "abs"> {
-        I8 => I8 ("ensure overflows halt"),
-        I16 => I16 ("ensure overflows halt"),
-        I32 => I32 ("ensure overflows halt"),
-        I64 => I64 ("ensure overflows halt"),
-        I128 => I128 ("ensure overflows halt"),
+        I8 => I8 checked ("ensure overflows halt"),
+        I16 => I16 checked ("ensure overflows halt"),
+        I32 => I32 checked ("ensure overflows halt"),
+        I64 => I64 checked ("ensure overflows halt"),
+        I128 => I128 checked ("ensure overflows halt"),
     }
 );
 
@@ -87,16 +96,16 @@ crate::operation!(
     pub struct AddOperation<core::ops::Add, core::ops::Add, add, "add"> {
         (Field, Field) => Field,
         (Group, Group) => Group,
-        (I8, I8) => I8 ("ensure overflows halt"),
-        (I16, I16) => I16 ("ensure overflows halt"),
-        (I32, I32) => I32 ("ensure overflows halt"),
-        (I64, I64) => I64 ("ensure overflows halt"),
-        (I128, I128) => I128 ("ensure overflows halt"),
-        (U8, U8) => U8 ("ensure overflows halt"),
-        (U16, U16) => U16 ("ensure overflows halt"),
-        (U32, U32) => U32 ("ensure overflows halt"),
-        (U64, U64) => U64 ("ensure overflows halt"),
-        (U128, U128) => U128 ("ensure overflows halt"),
+        (I8, I8) => I8 checked ("ensure overflows halt"),
+        (I16, I16) => I16 checked ("ensure overflows halt"),
+        (I32, I32) => I32 checked ("ensure overflows halt"),
+        (I64, I64) => I64 checked ("ensure overflows halt"),
+        (I128, I128) => I128 checked ("ensure overflows halt"),
+        (U8, U8) => U8 checked ("ensure overflows halt"),
+        (U16, U16) => U16 checked ("ensure overflows halt"),
+        (U32, U32) => U32 checked ("ensure overflows halt"),
+        (U64, U64) => U64 checked ("ensure overflows halt"),
+        (U128, U128) => U128 checked ("ensure overflows halt"),
         (Scalar, Scalar) => Scalar,
     }
 );
@@ -138,22 +147,71 @@ crate::operation!(
     }
 );
 
+/// Counts the leading bits of `first` equal to its sign bit, excluding the sign bit, storing the outcome in `destination`.
+pub type CountLeadingSignBits<N> = UnaryLiteral<N, CountLeadingSignBitsOperation<N>>;
+
+crate::operation!(
+    pub struct CountLeadingSignBitsOperation<console::prelude::CountLeadingSignBits, circuit::prelude::CountLeadingSignBits, count_leading_sign_bits, "cls"> {
+        I8 => U32,
+        I16 => U32,
+        I32 => U32,
+        I64 => U32,
+        I128 => U32,
+    }
+);
+
+/// Counts the number of bits set to one in `first`, storing the outcome in `destination`.
+pub type CountOnes<N> = UnaryLiteral<N, CountOnesOperation<N>>;
+
+crate::operation!(
+    pub struct CountOnesOperation<console::prelude::CountOnes, circuit::prelude::CountOnes, count_ones, "popcount"> {
+        I8 => U32,
+        I16 => U32,
+        I32 => U32,
+        I64 => U32,
+        I128 => U32,
+        U8 => U32,
+        U16 => U32,
+        U32 => U32,
+        U64 => U32,
+        U128 => U32,
+    }
+);
+
+/// Counts the number of bits set to zero in `first`, storing the outcome in `destination`.
+pub type CountZeros<N> = UnaryLiteral<N, CountZerosOperation<N>>;
+
+crate::operation!(
+    pub struct CountZerosOperation<console::prelude::CountZeros, circuit::prelude::CountZeros, count_zeros, "popcount.zero"> {
+        I8 => U32,
+        I16 => U32,
+        I32 => U32,
+        I64 => U32,
+        I128 => U32,
+        U8 => U32,
+        U16 => U32,
+        U32 => U32,
+        U64 => U32,
+        U128 => U32,
+    }
+);
+
 /// Divides `first` by `second`, storing the outcome in `destination`.
 pub type Div<N> = BinaryLiteral<N, DivOperation<N>>;
 
 crate::operation!(
     pub struct DivOperation<core::ops::Div, core::ops::Div, div, "div"> {
-        (Field, Field) => Field ("ensure divide by zero halts"),
-        (I8, I8) => I8 ("ensure overflows halt", "ensure divide by zero halts"),
-        (I16, I16) => I16 ("ensure overflows halt", "ensure divide by zero halts"),
-        (I32, I32) => I32 ("ensure overflows halt", "ensure divide by zero halts"),
-        (I64, I64) => I64 ("ensure overflows halt", "ensure divide by zero halts"),
-        (I128, I128) => I128 ("ensure overflows halt", "ensure divide by zero halts"),
-        (U8, U8) => U8 ("ensure overflows halt", "ensure divide by zero halts"),
-        (U16, U16) => U16 ("ensure overflows halt", "ensure divide by zero halts"),
-        (U32, U32) => U32 ("ensure overflows halt", "ensure divide by zero halts"),
-        (U64, U64) => U64 ("ensure overflows halt", "ensure divide by zero halts"),
-        (U128, U128) => U128 ("ensure overflows halt", "ensure divide by zero halts"),
+        (Field, Field) => Field checked ("ensure divide by zero halts"),
+        (I8, I8) => I8 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (I16, I16) => I16 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (I32, I32) => I32 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (I64, I64) => I64 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (I128, I128) => I128 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (U8, U8) => U8 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (U16, U16) => U16 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (U32, U32) => U32 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (U64, U64) => U64 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (U128, U128) => U128 checked ("ensure overflows halt", "ensure divide by zero halts"),
         // (Scalar, Scalar) => Scalar,
     }
 );
@@ -163,16 +221,16 @@ pub type DivWrapped<N> = BinaryLiteral<N, DivWrappedOperation<N>>;
 
 crate::operation!(
     pub struct DivWrappedOperation<console::prelude::DivWrapped, circuit::prelude::DivWrapped, div_wrapped, "div.w"> {
-        (I8, I8) => I8 ("ensure divide by zero halts"),
-        (I16, I16) => I16 ("ensure divide by zero halts"),
-        (I32, I32) => I32 ("ensure divide by zero halts"),
-        (I64, I64) => I64 ("ensure divide by zero halts"),
-        (I128, I128) => I128 ("ensure divide by zero halts"),
-        (U8, U8) => U8 ("ensure divide by zero halts"),
-        (U16, U16) => U16 ("ensure divide by zero halts"),
-        (U32, U32) => U32 ("ensure divide by zero halts"),
-        (U64, U64) => U64 ("ensure divide by zero halts"),
-        (U128, U128) => U128 ("ensure divide by zero halts"),
+        (I8, I8) => I8 checked ("ensure divide by zero halts"),
+        (I16, I16) => I16 checked ("ensure divide by zero halts"),
+        (I32, I32) => I32 checked ("ensure divide by zero halts"),
+        (I64, I64) => I64 checked ("ensure divide by zero halts"),
+        (I128, I128) => I128 checked ("ensure divide by zero halts"),
+        (U8, U8) => U8 checked ("ensure divide by zero halts"),
+        (U16, U16) => U16 checked ("ensure divide by zero halts"),
+        (U32, U32) => U32 checked ("ensure divide by zero halts"),
+        (U64, U64) => U64 checked ("ensure divide by zero halts"),
+        (U128, U128) => U128 checked ("ensure divide by zero halts"),
     }
 );
 
@@ -186,6 +244,37 @@ crate::operation!(
     }
 );
 
+/// Computes the greatest common divisor of `first` and `second`, storing the outcome in `destination`.
+pub type Gcd<N> = BinaryLiteral<N, GcdOperation<N>>;
+
+crate::operation!(
+    pub struct GcdOperation<console::prelude::Gcd, circuit::prelude::Gcd, gcd, "gcd"> {
+        (U8, U8) => U8,
+        (U16, U16) => U16,
+        (U32, U32) => U32,
+        (U64, U64) => U64,
+        (U128, U128) => U128,
+    }
+);
+
+/// Retrieves the bit of `first` at the index `second`, storing the outcome in `destination`.
+pub type GetBit<N> = BinaryLiteral<N, GetBitOperation<N>>;
+
+crate::operation!(
+    pub struct GetBitOperation<console::prelude::GetBit, circuit::prelude::GetBit, get_bit, "bit"> {
+        (I8, U32) => Boolean checked ("ensure bit index out of range halts"),
+        (I16, U32) => Boolean checked ("ensure bit index out of range halts"),
+        (I32, U32) => Boolean checked ("ensure bit index out of range halts"),
+        (I64, U32) => Boolean checked ("ensure bit index out of range halts"),
+        (I128, U32) => Boolean checked ("ensure bit index out of range halts"),
+        (U8, U32) => Boolean checked ("ensure bit index out of range halts"),
+        (U16, U32) => Boolean checked ("ensure bit index out of range halts"),
+        (U32, U32) => Boolean checked ("ensure bit index out of range halts"),
+        (U64, U32) => Boolean checked ("ensure bit index out of range halts"),
+        (U128, U32) => Boolean checked ("ensure bit index out of range halts"),
+    }
+);
+
 /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
 pub type GreaterThan<N> = BinaryLiteral<N, GreaterThanOperation<N>>;
 
@@ -237,6 +326,44 @@ crate::operation!(
     }
 );
 
+/// Computes whether `first` is zero as a boolean, storing the outcome in `destination`.
+pub type IsZero<N> = UnaryLiteral<N, IsZeroOperation<N>>;
+
+crate::operation!(
+    pub struct IsZeroOperation<console::prelude::IsZero, circuit::prelude::IsZero, is_equal_to_zero, "is.zero"> {
+        Field => Boolean,
+        I8 => Boolean,
+        I16 => Boolean,
+        I32 => Boolean,
+        I64 => Boolean,
+        I128 => Boolean,
+        U8 => Boolean,
+        U16 => Boolean,
+        U32 => Boolean,
+        U64 => Boolean,
+        U128 => Boolean,
+        Scalar => Boolean,
+    }
+);
+
+/// Counts the number of leading zero bits in `first`, storing the outcome in `destination`.
+pub type LeadingZeros<N> = UnaryLiteral<N, LeadingZerosOperation<N>>;
+
+crate::operation!(
+    pub struct LeadingZerosOperation<console::prelude::LeadingZeros, circuit::prelude::LeadingZeros, leading_zeros, "clz"> {
+        I8 => U32,
+        I16 => U32,
+        I32 => U32,
+        I64 => U32,
+        I128 => U32,
+        U8 => U32,
+        U16 => U32,
+        U32 => U32,
+        U64 => U32,
+        U128 => U32,
+    }
+);
+
 /// Computes whether `first` is less than `second` as a boolean, storing the outcome in `destination`.
 pub type LessThan<N> = BinaryLiteral<N, LessThanOperation<N>>;
 
@@ -279,20 +406,30 @@ crate::operation!(
     }
 );
 
+/// Raises the field element `first` to the power of the field element `second`, storing the outcome in `destination`.
+pub type ModPow<N> = BinaryLiteral<N, ModPowOperation<N>>;
+
+crate::operation!(
+    pub struct ModPowOperation<console::prelude::ModPow, circuit::prelude::ModPow, mod_pow, "modpow"> {
+        (Field, Field) => Field,
+    }
+);
+
 /// Computes the result of `first` mod `second`, storing the outcome in the destination.
 pub type Modulo<N> = BinaryLiteral<N, ModuloOperation<N>>;
 
 crate::operation!(
     pub struct ModuloOperation<console::prelude::Modulo, circuit::prelude::Modulo, modulo, "mod"> {
-        (U8, U8) => U8("ensure divide by zero halts"),
-        (U16, U16) => U16("ensure divide by zero halts"),
-        (U32, U32) => U32("ensure divide by zero halts"),
-        (U64, U64) => U64("ensure divide by zero halts"),
-        (U128, U128) => U128("ensure divide by zero halts"),
+        (U8, U8) => U8 checked ("ensure divide by zero halts"),
+        (U16, U16) => U16 checked ("ensure divide by zero halts"),
+        (U32, U32) => U32 checked ("ensure divide by zero halts"),
+        (U64, U64) => U64 checked ("ensure divide by zero halts"),
+        (U128, U128) => U128 checked ("ensure divide by zero halts"),
     }
 );
 
 /// Multiplies `first` and `second`, storing the outcome in `destination`.
+/// Note: A group element is multiplied by a scalar in either operand order, but never by another group element.
 pub type Mul<N> = BinaryLiteral<N, MulOperation<N>>;
 
 crate::operation!(
@@ -300,16 +437,16 @@ crate::operation!(
         (Field, Field) => Field,
         (Group, Scalar) => Group,
         (Scalar, Group) => Group,
-        (I8, I8) => I8 ("ensure overflows halt"),
-        (I16, I16) => I16 ("ensure overflows halt"),
-        (I32, I32) => I32 ("ensure overflows halt"),
-        (I64, I64) => I64 ("ensure overflows halt"),
-        (I128, I128) => I128 ("ensure overflows halt"),
-        (U8, U8) => U8 ("ensure overflows halt"),
-        (U16, U16) => U16 ("ensure overflows halt"),
-        (U32, U32) => U32 ("ensure overflows halt"),
-        (U64, U64) => U64 ("ensure overflows halt"),
-        (U128, U128) => U128 ("ensure overflows halt"),
+        (I8, I8) => I8 checked ("ensure overflows halt"),
+        (I16, I16) => I16 checked ("ensure overflows halt"),
+        (I32, I32) => I32 checked ("ensure overflows halt"),
+        (I64, I64) => I64 checked ("ensure overflows halt"),
+        (I128, I128) => I128 checked ("ensure overflows halt"),
+        (U8, U8) => U8 checked ("ensure overflows halt"),
+        (U16, U16) => U16 checked ("ensure overflows halt"),
+        (U32, U32) => U32 checked ("ensure overflows halt"),
+        (U64, U64) => U64 checked ("ensure overflows halt"),
+        (U128, U128) => U128 checked ("ensure overflows halt"),
         // (Scalar, Scalar) => Scalar,
     }
 );
@@ -332,12 +469,22 @@ crate::operation!(
     }
 );
 
-/// Returns `false` if `first` and `second` are `true`, storing the outcome in `destination`.
+/// Performs a bitwise `nand` on `first` and `second`, storing the outcome in `destination`.
 pub type Nand<N> = BinaryLiteral<N, NandOperation<N>>;
 
 crate::operation!(
     pub struct NandOperation<console::prelude::Nand, circuit::prelude::Nand, nand, "nand"> {
         (Boolean, Boolean) => Boolean,
+        (I8, I8) => I8,
+        (I16, I16) => I16,
+        (I32, I32) => I32,
+        (I64, I64) => I64,
+        (I128, I128) => I128,
+        (U8, U8) => U8,
+        (U16, U16) => U16,
+        (U32, U32) => U32,
+        (U64, U64) => U64,
+        (U128, U128) => U128,
     }
 );
 
@@ -348,20 +495,43 @@ crate::operation!(
     pub struct NegOperation<core::ops::Neg, core::ops::Neg, neg, "neg"> {
         Field => Field,
         Group => Group,
-        I8 => I8 ("ensure overflows halt"),
-        I16 => I16 ("ensure overflows halt"),
-        I32 => I32 ("ensure overflows halt"),
-        I64 => I64 ("ensure overflows halt"),
-        I128 => I128 ("ensure overflows halt"),
+        I8 => I8 checked ("ensure overflows halt"),
+        I16 => I16 checked ("ensure overflows halt"),
+        I32 => I32 checked ("ensure overflows halt"),
+        I64 => I64 checked ("ensure overflows halt"),
+        I128 => I128 checked ("ensure overflows halt"),
     }
 );
 
-/// Returns `true` if neither `first` nor `second` is `true`, storing the outcome in `destination`.
+/// Negates `first`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
+pub type NegWrapped<N> = UnaryLiteral<N, NegWrappedOperation<N>>;
+
+crate::operation!(
+    pub struct NegWrappedOperation<console::prelude::NegWrapped, circuit::prelude::NegWrapped, neg_wrapped, "neg.w"> {
+        I8 => I8,
+        I16 => I16,
+        I32 => I32,
+        I64 => I64,
+        I128 => I128,
+    }
+);
+
+/// Performs a bitwise `nor` on `first` and `second`, storing the outcome in `destination`.
 pub type Nor<N> = BinaryLiteral<N, NorOperation<N>>;
 
 crate::operation!(
     pub struct NorOperation<console::prelude::Nor, circuit::prelude::Nor, nor, "nor"> {
         (Boolean, Boolean) => Boolean,
+        (I8, I8) => I8,
+        (I16, I16) => I16,
+        (I32, I32) => I32,
+        (I64, I64) => I64,
+        (I128, I128) => I128,
+        (U8, U8) => U8,
+        (U16, U16) => U16,
+        (U32, U32) => U32,
+        (U64, U64) => U64,
+        (U128, U128) => U128,
     }
 );
 
@@ -409,36 +579,36 @@ pub type Pow<N> = BinaryLiteral<N, PowOperation<N>>;
 crate::operation!(
     pub struct PowOperation<console::prelude::Pow, circuit::prelude::Pow, pow, "pow"> {
         (Field, Field) => Field,
-        (I8, U8) => I8 ("ensure exponentiation overflows halt"),
-        (I8, U16) => I8 ("ensure exponentiation overflows halt"),
-        (I8, U32) => I8 ("ensure exponentiation overflows halt"),
-        (I16, U8) => I16 ("ensure exponentiation overflows halt"),
-        (I16, U16) => I16 ("ensure exponentiation overflows halt"),
-        (I16, U32) => I16 ("ensure exponentiation overflows halt"),
-        (I32, U8) => I32 ("ensure exponentiation overflows halt"),
-        (I32, U16) => I32 ("ensure exponentiation overflows halt"),
-        (I32, U32) => I32 ("ensure exponentiation overflows halt"),
-        (I64, U8) => I64 ("ensure exponentiation overflows halt"),
-        (I64, U16) => I64 ("ensure exponentiation overflows halt"),
-        (I64, U32) => I64 ("ensure exponentiation overflows halt"),
-        (I128, U8) => I128 ("ensure exponentiation overflows halt"),
-        (I128, U16) => I128 ("ensure exponentiation overflows halt"),
-        (I128, U32) => I128 ("ensure exponentiation overflows halt"),
-        (U8, U8) => U8 ("ensure exponentiation overflows halt"),
-        (U8, U16) => U8 ("ensure exponentiation overflows halt"),
-        (U8, U32) => U8 ("ensure exponentiation overflows halt"),
-        (U16, U8) => U16 ("ensure exponentiation overflows halt"),
-        (U16, U16) => U16 ("ensure exponentiation overflows halt"),
-        (U16, U32) => U16 ("ensure exponentiation overflows halt"),
-        (U32, U8) => U32 ("ensure exponentiation overflows halt"),
-        (U32, U16) => U32 ("ensure exponentiation overflows halt"),
-        (U32, U32) => U32 ("ensure exponentiation overflows halt"),
-        (U64, U8) => U64 ("ensure exponentiation overflows halt"),
-        (U64, U16) => U64 ("ensure exponentiation overflows halt"),
-        (U64, U32) => U64 ("ensure exponentiation overflows halt"),
-        (U128, U8) => U128 ("ensure exponentiation overflows halt"),
-        (U128, U16) => U128 ("ensure exponentiation overflows halt"),
-        (U128, U32) => U128 ("ensure exponentiation overflows halt"),
+        (I8, U8) => I8 checked ("ensure exponentiation overflows halt"),
+        (I8, U16) => I8 checked ("ensure exponentiation overflows halt"),
+        (I8, U32) => I8 checked ("ensure exponentiation overflows halt"),
+        (I16, U8) => I16 checked ("ensure exponentiation overflows halt"),
+        (I16, U16) => I16 checked ("ensure exponentiation overflows halt"),
+        (I16, U32) => I16 checked ("ensure exponentiation overflows halt"),
+        (I32, U8) => I32 checked ("ensure exponentiation overflows halt"),
+        (I32, U16) => I32 checked ("ensure exponentiation overflows halt"),
+        (I32, U32) => I32 checked ("ensure exponentiation overflows halt"),
+        (I64, U8) => I64 checked ("ensure exponentiation overflows halt"),
+        (I64, U16) => I64 checked ("ensure exponentiation overflows halt"),
+        (I64, U32) => I64 checked ("ensure exponentiation overflows halt"),
+        (I128, U8) => I128 checked ("ensure exponentiation overflows halt"),
+        (I128, U16) => I128 checked ("ensure exponentiation overflows halt"),
+        (I128, U32) => I128 checked ("ensure exponentiation overflows halt"),
+        (U8, U8) => U8 checked ("ensure exponentiation overflows halt"),
+        (U8, U16) => U8 checked ("ensure exponentiation overflows halt"),
+        (U8, U32) => U8 checked ("ensure exponentiation overflows halt"),
+        (U16, U8) => U16 checked ("ensure exponentiation overflows halt"),
+        (U16, U16) => U16 checked ("ensure exponentiation overflows halt"),
+        (U16, U32) => U16 checked ("ensure exponentiation overflows halt"),
+        (U32, U8) => U32 checked ("ensure exponentiation overflows halt"),
+        (U32, U16) => U32 checked ("ensure exponentiation overflows halt"),
+        (U32, U32) => U32 checked ("ensure exponentiation overflows halt"),
+        (U64, U8) => U64 checked ("ensure exponentiation overflows halt"),
+        (U64, U16) => U64 checked ("ensure exponentiation overflows halt"),
+        (U64, U32) => U64 checked ("ensure exponentiation overflows halt"),
+        (U128, U8) => U128 checked ("ensure exponentiation overflows halt"),
+        (U128, U16) => U128 checked ("ensure exponentiation overflows halt"),
+        (U128, U32) => U128 checked ("ensure exponentiation overflows halt"),
     }
 );
 
@@ -485,16 +655,16 @@ pub type Rem<N> = BinaryLiteral<N, RemOperation<N>>;
 
 crate::operation!(
     pub struct RemOperation<core::ops::Rem, core::ops::Rem, rem, "rem"> {
-        (I8, I8) => I8 ("ensure overflows halt", "ensure divide by zero halts"),
-        (I16, I16) => I16 ("ensure overflows halt", "ensure divide by zero halts"),
-        (I32, I32) => I32 ("ensure overflows halt", "ensure divide by zero halts"),
-        (I64, I64) => I64 ("ensure overflows halt", "ensure divide by zero halts"),
-        (I128, I128) => I128 ("ensure overflows halt", "ensure divide by zero halts"),
-        (U8, U8) => U8 ("ensure overflows halt", "ensure divide by zero halts"),
-        (U16, U16) => U16 ("ensure overflows halt", "ensure divide by zero halts"),
-        (U32, U32) => U32 ("ensure overflows halt", "ensure divide by zero halts"),
-        (U64, U64) => U64 ("ensure overflows halt", "ensure divide by zero halts"),
-        (U128, U128) => U128 ("ensure overflows halt", "ensure divide by zero halts"),
+        (I8, I8) => I8 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (I16, I16) => I16 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (I32, I32) => I32 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (I64, I64) => I64 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (I128, I128) => I128 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (U8, U8) => U8 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (U16, U16) => U16 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (U32, U32) => U32 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (U64, U64) => U64 checked ("ensure overflows halt", "ensure divide by zero halts"),
+        (U128, U128) => U128 checked ("ensure overflows halt", "ensure divide by zero halts"),
     }
 );
 
@@ -503,16 +673,16 @@ pub type RemWrapped<N> = BinaryLiteral<N, RemWrappedOperation<N>>;
 
 crate::operation!(
     pub struct RemWrappedOperation<console::prelude::RemWrapped, circuit::prelude::RemWrapped, rem_wrapped, "rem.w"> {
-        (I8, I8) => I8 ("ensure divide by zero halts"),
-        (I16, I16) => I16 ("ensure divide by zero halts"),
-        (I32, I32) => I32 ("ensure divide by zero halts"),
-        (I64, I64) => I64 ("ensure divide by zero halts"),
-        (I128, I128) => I128 ("ensure divide by zero halts"),
-        (U8, U8) => U8 ("ensure divide by zero halts"),
-        (U16, U16) => U16 ("ensure divide by zero halts"),
-        (U32, U32) => U32 ("ensure divide by zero halts"),
-        (U64, U64) => U64 ("ensure divide by zero halts"),
-        (U128, U128) => U128 ("ensure divide by zero halts"),
+        (I8, I8) => I8 checked ("ensure divide by zero halts"),
+        (I16, I16) => I16 checked ("ensure divide by zero halts"),
+        (I32, I32) => I32 checked ("ensure divide by zero halts"),
+        (I64, I64) => I64 checked ("ensure divide by zero halts"),
+        (I128, I128) => I128 checked ("ensure divide by zero halts"),
+        (U8, U8) => U8 checked ("ensure divide by zero halts"),
+        (U16, U16) => U16 checked ("ensure divide by zero halts"),
+        (U32, U32) => U32 checked ("ensure divide by zero halts"),
+        (U64, U64) => U64 checked ("ensure divide by zero halts"),
+        (U128, U128) => U128 checked ("ensure divide by zero halts"),
     }
 );
 
@@ -521,36 +691,36 @@ pub type Shl<N> = BinaryLiteral<N, ShlOperation<N>>;
 
 crate::operation!(
     pub struct ShlOperation<console::prelude::ShlChecked, circuit::prelude::ShlChecked, shl_checked, "shl"> {
-        (I8, U8) => I8 ("ensure shifting past boundary halts"),
-        (I8, U16) => I8 ("ensure shifting past boundary halts"),
-        (I8, U32) => I8 ("ensure shifting past boundary halts"),
-        (I16, U8) => I16 ("ensure shifting past boundary halts"),
-        (I16, U16) => I16 ("ensure shifting past boundary halts"),
-        (I16, U32) => I16 ("ensure shifting past boundary halts"),
-        (I32, U8) => I32 ("ensure shifting past boundary halts"),
-        (I32, U16) => I32 ("ensure shifting past boundary halts"),
-        (I32, U32) => I32 ("ensure shifting past boundary halts"),
-        (I64, U8) => I64 ("ensure shifting past boundary halts"),
-        (I64, U16) => I64 ("ensure shifting past boundary halts"),
-        (I64, U32) => I64 ("ensure shifting past boundary halts"),
-        (I128, U8) => I128 ("ensure shifting past boundary halts"),
-        (I128, U16) => I128 ("ensure shifting past boundary halts"),
-        (I128, U32) => I128 ("ensure shifting past boundary halts"),
-        (U8, U8) => U8 ("ensure shifting past boundary halts"),
-        (U8, U16) => U8 ("ensure shifting past boundary halts"),
-        (U8, U32) => U8 ("ensure shifting past boundary halts"),
-        (U16, U8) => U16 ("ensure shifting past boundary halts"),
-        (U16, U16) => U16 ("ensure shifting past boundary halts"),
-        (U16, U32) => U16 ("ensure shifting past boundary halts"),
-        (U32, U8) => U32 ("ensure shifting past boundary halts"),
-        (U32, U16) => U32 ("ensure shifting past boundary halts"),
-        (U32, U32) => U32 ("ensure shifting past boundary halts"),
-        (U64, U8) => U64 ("ensure shifting past boundary halts"),
-        (U64, U16) => U64 ("ensure shifting past boundary halts"),
-        (U64, U32) => U64 ("ensure shifting past boundary halts"),
-        (U128, U8) => U128 ("ensure shifting past boundary halts"),
-        (U128, U16) => U128 ("ensure shifting past boundary halts"),
-        (U128, U32) => U128 ("ensure shifting past boundary halts"),
+        (I8, U8) => I8 checked ("ensure shifting past boundary halts"),
+        (I8, U16) => I8 checked ("ensure shifting past boundary halts"),
+        (I8, U32) => I8 checked ("ensure shifting past boundary halts"),
+        (I16, U8) => I16 checked ("ensure shifting past boundary halts"),
+        (I16, U16) => I16 checked ("ensure shifting past boundary halts"),
+        (I16, U32) => I16 checked ("ensure shifting past boundary halts"),
+        (I32, U8) => I32 checked ("ensure shifting past boundary halts"),
+        (I32, U16) => I32 checked ("ensure shifting past boundary halts"),
+        (I32, U32) => I32 checked ("ensure shifting past boundary halts"),
+        (I64, U8) => I64 checked ("ensure shifting past boundary halts"),
+        (I64, U16) => I64 checked ("ensure shifting past boundary halts"),
+        (I64, U32) => I64 checked ("ensure shifting past boundary halts"),
+        (I128, U8) => I128 checked ("ensure shifting past boundary halts"),
+        (I128, U16) => I128 checked ("ensure shifting past boundary halts"),
+        (I128, U32) => I128 checked ("ensure shifting past boundary halts"),
+        (U8, U8) => U8 checked ("ensure shifting past boundary halts"),
+        (U8, U16) => U8 checked ("ensure shifting past boundary halts"),
+        (U8, U32) => U8 checked ("ensure shifting past boundary halts"),
+        (U16, U8) => U16 checked ("ensure shifting past boundary halts"),
+        (U16, U16) => U16 checked ("ensure shifting past boundary halts"),
+        (U16, U32) => U16 checked ("ensure shifting past boundary halts"),
+        (U32, U8) => U32 checked ("ensure shifting past boundary halts"),
+        (U32, U16) => U32 checked ("ensure shifting past boundary halts"),
+        (U32, U32) => U32 checked ("ensure shifting past boundary halts"),
+        (U64, U8) => U64 checked ("ensure shifting past boundary halts"),
+        (U64, U16) => U64 checked ("ensure shifting past boundary halts"),
+        (U64, U32) => U64 checked ("ensure shifting past boundary halts"),
+        (U128, U8) => U128 checked ("ensure shifting past boundary halts"),
+        (U128, U16) => U128 checked ("ensure shifting past boundary halts"),
+        (U128, U32) => U128 checked ("ensure shifting past boundary halts"),
     }
 );
 
@@ -597,36 +767,36 @@ pub type Shr<N> = BinaryLiteral<N, ShrOperation<N>>;
 
 crate::operation!(
     pub struct ShrOperation<console::prelude::ShrChecked, circuit::prelude::ShrChecked, shr_checked, "shr"> {
-        (I8, U8) => I8 ("ensure shifting past boundary halts"),
-        (I8, U16) => I8 ("ensure shifting past boundary halts"),
-        (I8, U32) => I8 ("ensure shifting past boundary halts"),
-        (I16, U8) => I16 ("ensure shifting past boundary halts"),
-        (I16, U16) => I16 ("ensure shifting past boundary halts"),
-        (I16, U32) => I16 ("ensure shifting past boundary halts"),
-        (I32, U8) => I32 ("ensure shifting past boundary halts"),
-        (I32, U16) => I32 ("ensure shifting past boundary halts"),
-        (I32, U32) => I32 ("ensure shifting past boundary halts"),
-        (I64, U8) => I64 ("ensure shifting past boundary halts"),
-        (I64, U16) => I64 ("ensure shifting past boundary halts"),
-        (I64, U32) => I64 ("ensure shifting past boundary halts"),
-        (I128, U8) => I128 ("ensure shifting past boundary halts"),
-        (I128, U16) => I128 ("ensure shifting past boundary halts"),
-        (I128, U32) => I128 ("ensure shifting past boundary halts"),
-        (U8, U8) => U8 ("ensure shifting past boundary halts"),
-        (U8, U16) => U8 ("ensure shifting past boundary halts"),
-        (U8, U32) => U8 ("ensure shifting past boundary halts"),
-        (U16, U8) => U16 ("ensure shifting past boundary halts"),
-        (U16, U16) => U16 ("ensure shifting past boundary halts"),
-        (U16, U32) => U16 ("ensure shifting past boundary halts"),
-        (U32, U8) => U32 ("ensure shifting past boundary halts"),
-        (U32, U16) => U32 ("ensure shifting past boundary halts"),
-        (U32, U32) => U32 ("ensure shifting past boundary halts"),
-        (U64, U8) => U64 ("ensure shifting past boundary halts"),
-        (U64, U16) => U64 ("ensure shifting past boundary halts"),
-        (U64, U32) => U64 ("ensure shifting past boundary halts"),
-        (U128, U8) => U128 ("ensure shifting past boundary halts"),
-        (U128, U16) => U128 ("ensure shifting past boundary halts"),
-        (U128, U32) => U128 ("ensure shifting past boundary halts"),
+        (I8, U8) => I8 checked ("ensure shifting past boundary halts"),
+        (I8, U16) => I8 checked ("ensure shifting past boundary halts"),
+        (I8, U32) => I8 checked ("ensure shifting past boundary halts"),
+        (I16, U8) => I16 checked ("ensure shifting past boundary halts"),
+        (I16, U16) => I16 checked ("ensure shifting past boundary halts"),
+        (I16, U32) => I16 checked ("ensure shifting past boundary halts"),
+        (I32, U8) => I32 checked ("ensure shifting past boundary halts"),
+        (I32, U16) => I32 checked ("ensure shifting past boundary halts"),
+        (I32, U32) => I32 checked ("ensure shifting past boundary halts"),
+        (I64, U8) => I64 checked ("ensure shifting past boundary halts"),
+        (I64, U16) => I64 checked ("ensure shifting past boundary halts"),
+        (I64, U32) => I64 checked ("ensure shifting past boundary halts"),
+        (I128, U8) => I128 checked ("ensure shifting past boundary halts"),
+        (I128, U16) => I128 checked ("ensure shifting past boundary halts"),
+        (I128, U32) => I128 checked ("ensure shifting past boundary halts"),
+        (U8, U8) => U8 checked ("ensure shifting past boundary halts"),
+        (U8, U16) => U8 checked ("ensure shifting past boundary halts"),
+        (U8, U32) => U8 checked ("ensure shifting past boundary halts"),
+        (U16, U8) => U16 checked ("ensure shifting past boundary halts"),
+        (U16, U16) => U16 checked ("ensure shifting past boundary halts"),
+        (U16, U32) => U16 checked ("ensure shifting past boundary halts"),
+        (U32, U8) => U32 checked ("ensure shifting past boundary halts"),
+        (U32, U16) => U32 checked ("ensure shifting past boundary halts"),
+        (U32, U32) => U32 checked ("ensure shifting past boundary halts"),
+        (U64, U8) => U64 checked ("ensure shifting past boundary halts"),
+        (U64, U16) => U64 checked ("ensure shifting past boundary halts"),
+        (U64, U32) => U64 checked ("ensure shifting past boundary halts"),
+        (U128, U8) => U128 checked ("ensure shifting past boundary halts"),
+        (U128, U16) => U128 checked ("ensure shifting past boundary halts"),
+        (U128, U32) => U128 checked ("ensure shifting past boundary halts"),
     }
 );
 
@@ -686,6 +856,15 @@ crate::operation!(
     }
 );
 
+/// Computes the canonical (lexicographically smaller) square root of `first`, storing the outcome in `destination`.
+pub type SquareRootCanonical<N> = UnaryLiteral<N, SquareRootCanonicalOperation<N>>;
+
+crate::operation!(
+    pub struct SquareRootCanonicalOperation<console::prelude::SquareRootCanonical, circuit::prelude::SquareRootCanonical, square_root_canonical?, "sqrt.canonical"> {
+        Field => Field ("ensure quadratic nonresidues halt"),
+    }
+);
+
 /// Computes `first - second`, storing the outcome in `destination`.
 pub type Sub<N> = BinaryLiteral<N, SubOperation<N>>;
 
@@ -693,16 +872,16 @@ crate::operation!(
     pub struct SubOperation<core::ops::Sub, core::ops::Sub, sub, "sub"> {
         (Field, Field) => Field,
         (Group, Group) => Group,
-        (I8, I8) => I8 ("ensure overflows halt"),
-        (I16, I16) => I16 ("ensure overflows halt"),
-        (I32, I32) => I32 ("ensure overflows halt"),
-        (I64, I64) => I64 ("ensure overflows halt"),
-        (I128, I128) => I128 ("ensure overflows halt"),
-        (U8, U8) => U8 ("ensure overflows halt"),
-        (U16, U16) => U16 ("ensure overflows halt"),
-        (U32, U32) => U32 ("ensure overflows halt"),
-        (U64, U64) => U64 ("ensure overflows halt"),
-        (U128, U128) => U128 ("ensure overflows halt"),
+        (I8, I8) => I8 checked ("ensure overflows halt"),
+        (I16, I16) => I16 checked ("ensure overflows halt"),
+        (I32, I32) => I32 checked ("ensure overflows halt"),
+        (I64, I64) => I64 checked ("ensure overflows halt"),
+        (I128, I128) => I128 checked ("ensure overflows halt"),
+        (U8, U8) => U8 checked ("ensure overflows halt"),
+        (U16, U16) => U16 checked ("ensure overflows halt"),
+        (U32, U32) => U32 checked ("ensure overflows halt"),
+        (U64, U64) => U64 checked ("ensure overflows halt"),
+        (U128, U128) => U128 checked ("ensure overflows halt"),
         // (Scalar, Scalar) => Scalar,
     }
 );
@@ -725,6 +904,22 @@ crate::operation!(
     }
 );
 
+/// Reverses the byte order of `first`, storing the outcome in `destination`.
+pub type SwapBytes<N> = UnaryLiteral<N, SwapBytesOperation<N>>;
+
+crate::operation!(
+    pub struct SwapBytesOperation<console::prelude::SwapBytes, circuit::prelude::SwapBytes, swap_bytes, "bswap"> {
+        I16 => I16,
+        I32 => I32,
+        I64 => I64,
+        I128 => I128,
+        U16 => U16,
+        U32 => U32,
+        U64 => U64,
+        U128 => U128,
+    }
+);
+
 /// Selects `first`, if `condition` is true, otherwise selects `second`, storing the result in `destination`.
 pub type Ternary<N> = TernaryLiteral<N, TernaryOperation<N>>;
 
@@ -749,6 +944,24 @@ crate::operation!(
     }
 );
 
+/// Counts the number of trailing zero bits in `first`, storing the outcome in `destination`.
+pub type TrailingZeros<N> = UnaryLiteral<N, TrailingZerosOperation<N>>;
+
+crate::operation!(
+    pub struct TrailingZerosOperation<console::prelude::TrailingZeros, circuit::prelude::TrailingZeros, trailing_zeros, "ctz"> {
+        I8 => U32,
+        I16 => U32,
+        I32 => U32,
+        I64 => U32,
+        I128 => U32,
+        U8 => U32,
+        U16 => U32,
+        U32 => U32,
+        U64 => U32,
+        U128 => U32,
+    }
+);
+
 /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
 pub type Xor<N> = BinaryLiteral<N, XorOperation<N>>;
 
@@ -795,6 +1008,21 @@ pub(crate) mod test_helpers {
         stack: &Stack<CurrentNetwork>,
         function_name: &Identifier<CurrentNetwork>,
         values: &[(&Literal<CurrentNetwork>, Option<circuit::Mode>)],
+    ) -> Result<Registers<CurrentNetwork, CurrentAleo>> {
+        // Initialize the console values.
+        let values = values
+            .iter()
+            .map(|(literal, mode)| (Value::Plaintext(Plaintext::from(*literal)), *mode))
+            .collect::<Vec<_>>();
+        sample_value_registers(stack, function_name, &values)
+    }
+
+    /// Samples the registers with the given values, which may be structs or records.
+    /// Note: Do not replicate this for real program use, it is insecure.
+    pub fn sample_value_registers(
+        stack: &Stack<CurrentNetwork>,
+        function_name: &Identifier<CurrentNetwork>,
+        values: &[(Value<CurrentNetwork>, Option<circuit::Mode>)],
     ) -> Result<Registers<CurrentNetwork, CurrentAleo>> {
         // Initialize the registers.
         let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
@@ -803,11 +1031,9 @@ pub(crate) mod test_helpers {
         );
 
         // For each value, store the register and value.
-        for (index, (literal, mode)) in values.iter().enumerate() {
+        for (index, (value, mode)) in values.iter().enumerate() {
             // Initialize the register.
             let register = Register::Locator(index as u64);
-            // Initialize the console value.
-            let value = Value::Plaintext(Plaintext::from(*literal));
             // Store the value in the console registers.
             registers.store(stack, &register, value.clone())?;
             // If the mode is not `None`,
@@ -815,7 +1041,7 @@ pub(crate) mod test_helpers {
                 use circuit::Inject;
 
                 // Initialize the circuit value.
-                let circuit_value = circuit::Value::new(*mode, value);
+                let circuit_value = circuit::Value::new(*mode, value.clone());
                 // Store the value in the circuit registers.
                 registers.store_circuit(stack, &register, circuit_value)?;
             }
@@ -846,3 +1072,570 @@ pub(crate) mod test_helpers {
         Ok(finalize_registers)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InstructionError;
+    use circuit::AleoV0;
+    use console::{
+        network::Testnet3,
+        program::{Literal, LiteralType},
+        types::Boolean,
+    };
+
+    use core::cmp::Ordering;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Checks the comparison on the given operands in every mode, against the expected outcome.
+    fn check_comparison<O: Operation<CurrentNetwork, Literal<CurrentNetwork>, LiteralType, 2>>(
+        first: &str,
+        second: &str,
+        expected: bool,
+    ) -> Result<()> {
+        let expected = Literal::Boolean(Boolean::new(expected));
+
+        // Check the console evaluation.
+        let inputs = [Literal::from_str(first)?, Literal::from_str(second)?];
+        assert_eq!(expected, O::evaluate(&inputs)?, "{} {first} {second}", O::OPCODE);
+
+        // Check the circuit execution.
+        for mode_a in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            for mode_b in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+                let inputs = [
+                    circuit::Literal::from_str(&format!("{first}.{mode_a}"))?,
+                    circuit::Literal::from_str(&format!("{second}.{mode_b}"))?,
+                ];
+                let candidate = O::execute::<CurrentAleo>(&inputs)?;
+                assert_eq!(expected, circuit::Eject::eject_value(&candidate), "{} {first} {second}", O::OPCODE);
+                assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+                <CurrentAleo as circuit::Environment>::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_comparisons() -> Result<()> {
+        let vectors = [
+            // Signed integers must respect two's complement ordering.
+            ("-1i8", "1i8", Ordering::Less),
+            ("-128i8", "127i8", Ordering::Less),
+            ("-5i16", "-5i16", Ordering::Equal),
+            ("-1i32", "-2i32", Ordering::Greater),
+            ("0i64", "-9223372036854775808i64", Ordering::Greater),
+            ("-170141183460469231731687303715884105728i128", "-1i128", Ordering::Less),
+            // Unsigned integers.
+            ("0u8", "255u8", Ordering::Less),
+            ("300u16", "300u16", Ordering::Equal),
+            ("4294967295u32", "0u32", Ordering::Greater),
+            ("1u64", "2u64", Ordering::Less),
+            ("340282366920938463463374607431768211455u128", "1u128", Ordering::Greater),
+            // Field and scalar elements.
+            ("1field", "2field", Ordering::Less),
+            ("7field", "7field", Ordering::Equal),
+            ("3scalar", "2scalar", Ordering::Greater),
+        ];
+
+        for (first, second, ordering) in vectors {
+            check_comparison::<LessThanOperation<CurrentNetwork>>(first, second, ordering.is_lt())?;
+            check_comparison::<LessThanOrEqualOperation<CurrentNetwork>>(first, second, ordering.is_le())?;
+            check_comparison::<GreaterThanOperation<CurrentNetwork>>(first, second, ordering.is_gt())?;
+            check_comparison::<GreaterThanOrEqualOperation<CurrentNetwork>>(first, second, ordering.is_ge())?;
+        }
+        Ok(())
+    }
+
+    /// Checks the operation rejects the given operand types.
+    fn check_rejects<O: Operation<CurrentNetwork, Literal<CurrentNetwork>, LiteralType, 2>>(
+        first: LiteralType,
+        second: LiteralType,
+    ) {
+        assert!(O::output_type(&[first, second]).is_err(), "{} accepted '{first}' and '{second}'", O::OPCODE);
+    }
+
+    #[test]
+    fn test_comparisons_reject_mismatched_types() -> Result<()> {
+        for (first, second) in [
+            (LiteralType::I8, LiteralType::U8),
+            (LiteralType::U32, LiteralType::U64),
+            (LiteralType::Field, LiteralType::Scalar),
+            (LiteralType::Boolean, LiteralType::Boolean),
+        ] {
+            check_rejects::<LessThanOperation<CurrentNetwork>>(first, second);
+            check_rejects::<LessThanOrEqualOperation<CurrentNetwork>>(first, second);
+            check_rejects::<GreaterThanOperation<CurrentNetwork>>(first, second);
+            check_rejects::<GreaterThanOrEqualOperation<CurrentNetwork>>(first, second);
+        }
+
+        // Ensure evaluating mismatched operands fails.
+        let inputs = [Literal::from_str("1i8")?, Literal::from_str("1u8")?];
+        assert!(LessThanOperation::<CurrentNetwork>::evaluate(&inputs).is_err());
+        Ok(())
+    }
+
+    /// Checks the operation on the given operands in every mode, against the expected output.
+    fn check_binary<O: Operation<CurrentNetwork, Literal<CurrentNetwork>, LiteralType, 2>>(
+        first: &str,
+        second: &str,
+        expected: &str,
+    ) -> Result<()> {
+        let expected = Literal::from_str(expected)?;
+
+        // Check the console evaluation.
+        let inputs = [Literal::from_str(first)?, Literal::from_str(second)?];
+        assert_eq!(expected, O::evaluate(&inputs)?, "{} {first} {second}", O::OPCODE);
+
+        // Check the circuit execution.
+        for mode_a in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            for mode_b in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+                let inputs = [
+                    circuit::Literal::from_str(&format!("{first}.{mode_a}"))?,
+                    circuit::Literal::from_str(&format!("{second}.{mode_b}"))?,
+                ];
+                let candidate = O::execute::<CurrentAleo>(&inputs)?;
+                assert_eq!(expected, circuit::Eject::eject_value(&candidate), "{} {first} {second}", O::OPCODE);
+                assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+                <CurrentAleo as circuit::Environment>::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_gcd() -> Result<()> {
+        let vectors = [
+            // Coprime operands.
+            ("35u8", "64u8", "1u8"),
+            ("65535u16", "65534u16", "1u16"),
+            ("4294967291u32", "4294967290u32", "1u32"),
+            // Operands with a shared factor.
+            ("12u8", "18u8", "6u8"),
+            ("1024u16", "768u16", "256u16"),
+            ("1071u32", "462u32", "21u32"),
+            ("18446744073709551615u64", "4294967295u64", "4294967295u64"),
+            ("340282366920938463463374607431768211455u128", "255u128", "255u128"),
+            // Zero operands.
+            ("0u8", "0u8", "0u8"),
+            ("0u64", "42u64", "42u64"),
+            ("42u128", "0u128", "42u128"),
+        ];
+
+        for (first, second, expected) in vectors {
+            check_binary::<GcdOperation<CurrentNetwork>>(first, second, expected)?;
+            check_binary::<GcdOperation<CurrentNetwork>>(second, first, expected)?;
+        }
+        Ok(())
+    }
+
+    /// Checks the unary operation on the given operand in every mode, against the expected output.
+    fn check_unary<O: Operation<CurrentNetwork, Literal<CurrentNetwork>, LiteralType, 1>>(
+        input: &str,
+        expected: &str,
+    ) -> Result<()> {
+        let expected = Literal::from_str(expected)?;
+
+        // Check the console evaluation.
+        assert_eq!(expected, O::evaluate(&[Literal::from_str(input)?])?, "{} {input}", O::OPCODE);
+
+        // Check the circuit execution.
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            let candidate = O::execute::<CurrentAleo>(&[circuit::Literal::from_str(&format!("{input}.{mode}"))?])?;
+            assert_eq!(expected, circuit::Eject::eject_value(&candidate), "{} {input}", O::OPCODE);
+            assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_swap_bytes() -> Result<()> {
+        let vectors = [
+            ("258u16", "513u16"),
+            ("-2i16", "-257i16"),
+            ("305419896u32", "2018915346u32"),
+            ("1i64", "72057594037927936i64"),
+            ("0u128", "0u128"),
+            ("255u128", "338953138925153547590470800371487866880u128"),
+        ];
+
+        for (input, expected) in vectors {
+            check_unary::<SwapBytesOperation<CurrentNetwork>>(input, expected)?;
+            // Ensure swapping the bytes twice returns the input.
+            check_unary::<SwapBytesOperation<CurrentNetwork>>(expected, input)?;
+        }
+
+        // Ensure single-byte and non-integer operands are rejected.
+        for literal_type in [LiteralType::U8, LiteralType::I8, LiteralType::Field, LiteralType::Boolean] {
+            assert!(SwapBytesOperation::<CurrentNetwork>::output_type(&[literal_type]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_ones_and_zeros() -> Result<()> {
+        let vectors = [
+            // All zeros.
+            ("0i8", "0u32", "8u32"),
+            ("0i16", "0u32", "16u32"),
+            ("0i32", "0u32", "32u32"),
+            ("0i64", "0u32", "64u32"),
+            ("0i128", "0u32", "128u32"),
+            ("0u8", "0u32", "8u32"),
+            ("0u16", "0u32", "16u32"),
+            ("0u32", "0u32", "32u32"),
+            ("0u64", "0u32", "64u32"),
+            ("0u128", "0u32", "128u32"),
+            // All ones.
+            ("-1i8", "8u32", "0u32"),
+            ("-1i16", "16u32", "0u32"),
+            ("-1i32", "32u32", "0u32"),
+            ("-1i64", "64u32", "0u32"),
+            ("-1i128", "128u32", "0u32"),
+            ("255u8", "8u32", "0u32"),
+            ("65535u16", "16u32", "0u32"),
+            ("4294967295u32", "32u32", "0u32"),
+            ("18446744073709551615u64", "64u32", "0u32"),
+            ("340282366920938463463374607431768211455u128", "128u32", "0u32"),
+            // Mixed bits.
+            ("-128i8", "1u32", "7u32"),
+            ("127i8", "7u32", "1u32"),
+            ("170u8", "4u32", "4u32"),
+            ("4096u16", "1u32", "15u32"),
+            ("-2i64", "63u32", "1u32"),
+        ];
+
+        for (input, ones, zeros) in vectors {
+            check_unary::<CountOnesOperation<CurrentNetwork>>(input, ones)?;
+            check_unary::<CountZerosOperation<CurrentNetwork>>(input, zeros)?;
+        }
+
+        // Ensure non-integer operands are rejected.
+        for literal_type in [LiteralType::Boolean, LiteralType::Field, LiteralType::Group, LiteralType::Scalar] {
+            assert!(CountOnesOperation::<CurrentNetwork>::output_type(&[literal_type]).is_err());
+            assert!(CountZerosOperation::<CurrentNetwork>::output_type(&[literal_type]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_leading_and_trailing_zeros() -> Result<()> {
+        let vectors = [
+            // Zero returns the full bit width.
+            ("0i8", "8u32", "8u32"),
+            ("0i16", "16u32", "16u32"),
+            ("0i32", "32u32", "32u32"),
+            ("0i64", "64u32", "64u32"),
+            ("0i128", "128u32", "128u32"),
+            ("0u8", "8u32", "8u32"),
+            ("0u16", "16u32", "16u32"),
+            ("0u32", "32u32", "32u32"),
+            ("0u64", "64u32", "64u32"),
+            ("0u128", "128u32", "128u32"),
+            // A single bit set.
+            ("1u8", "7u32", "0u32"),
+            ("128u8", "0u32", "7u32"),
+            ("256u16", "7u32", "8u32"),
+            ("65536u32", "15u32", "16u32"),
+            ("4294967296u64", "31u32", "32u32"),
+            ("170141183460469231731687303715884105728u128", "0u32", "127u32"),
+            ("-128i8", "0u32", "7u32"),
+            ("64i8", "1u32", "6u32"),
+            ("1i128", "127u32", "0u32"),
+            // Multiple bits set.
+            ("-1i32", "0u32", "0u32"),
+            ("12u8", "4u32", "2u32"),
+            ("18446744073709551615u64", "0u32", "0u32"),
+        ];
+
+        for (input, leading, trailing) in vectors {
+            check_unary::<LeadingZerosOperation<CurrentNetwork>>(input, leading)?;
+            check_unary::<TrailingZerosOperation<CurrentNetwork>>(input, trailing)?;
+        }
+
+        // Ensure non-integer operands are rejected.
+        for literal_type in [LiteralType::Boolean, LiteralType::Field, LiteralType::Group, LiteralType::Scalar] {
+            assert!(LeadingZerosOperation::<CurrentNetwork>::output_type(&[literal_type]).is_err());
+            assert!(TrailingZerosOperation::<CurrentNetwork>::output_type(&[literal_type]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_zero() -> Result<()> {
+        let vectors = [
+            ("0field", "true"),
+            ("1field", "false"),
+            ("0scalar", "true"),
+            ("7scalar", "false"),
+            ("0u8", "true"),
+            ("5u32", "false"),
+            ("0i8", "true"),
+            ("-0i8", "true"),
+            ("-0i128", "true"),
+            ("-1i32", "false"),
+            ("-128i8", "false"),
+        ];
+
+        for (input, expected) in vectors {
+            check_unary::<IsZeroOperation<CurrentNetwork>>(input, expected)?;
+        }
+
+        // Ensure non-numeric operands are rejected.
+        for literal_type in [LiteralType::Address, LiteralType::Boolean, LiteralType::Group, LiteralType::String] {
+            assert!(IsZeroOperation::<CurrentNetwork>::output_type(&[literal_type]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_neg() -> Result<()> {
+        let vectors = [
+            ("5i8", "-5i8"),
+            ("-127i8", "127i8"),
+            ("0i16", "0i16"),
+            ("-32767i16", "32767i16"),
+            ("2147483647i32", "-2147483647i32"),
+            ("-1i64", "1i64"),
+            ("1i128", "-1i128"),
+        ];
+
+        for (input, expected) in vectors {
+            check_unary::<NegOperation<CurrentNetwork>>(input, expected)?;
+            check_unary::<NegWrappedOperation<CurrentNetwork>>(input, expected)?;
+        }
+
+        // Ensure field and group elements are negated.
+        let mut rng = TestRng::default();
+        let field = console::types::Field::<CurrentNetwork>::rand(&mut rng);
+        check_unary::<NegOperation<CurrentNetwork>>(&field.to_string(), &(-field).to_string())?;
+        let group = console::types::Group::<CurrentNetwork>::rand(&mut rng);
+        check_unary::<NegOperation<CurrentNetwork>>(&group.to_string(), &(-group).to_string())?;
+
+        // Ensure `neg` of the minimum signed integer halts.
+        let error = NegOperation::<CurrentNetwork>::evaluate(&[Literal::from_str("-128i8")?]).unwrap_err();
+        assert!(matches!(error.downcast_ref::<InstructionError>(), Some(InstructionError::Overflow(..))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_neg_wrapped() -> Result<()> {
+        let vectors = [
+            ("-128i8", "-128i8"),
+            ("-32768i16", "-32768i16"),
+            ("-2147483648i32", "-2147483648i32"),
+            ("-9223372036854775808i64", "-9223372036854775808i64"),
+            ("-170141183460469231731687303715884105728i128", "-170141183460469231731687303715884105728i128"),
+            ("127i8", "-127i8"),
+        ];
+
+        for (input, expected) in vectors {
+            check_unary::<NegWrappedOperation<CurrentNetwork>>(input, expected)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_neg_rejects_unsigned_types() {
+        for literal_type in [LiteralType::U8, LiteralType::U16, LiteralType::U32, LiteralType::U64, LiteralType::U128] {
+            let error = NegOperation::<CurrentNetwork>::output_type(&[literal_type]).unwrap_err();
+            assert_eq!(error.to_string(), "Invalid operand types for the 'neg' instruction");
+            let error = NegWrappedOperation::<CurrentNetwork>::output_type(&[literal_type]).unwrap_err();
+            assert_eq!(error.to_string(), "Invalid operand types for the 'neg.w' instruction");
+        }
+        // Ensure `neg.w` rejects field and group elements.
+        for literal_type in [LiteralType::Field, LiteralType::Group] {
+            assert!(NegWrappedOperation::<CurrentNetwork>::output_type(&[literal_type]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_boolean_logic_truth_tables() -> Result<()> {
+        for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+            let (first, second) = (a.to_string(), b.to_string());
+            check_binary::<AndOperation<CurrentNetwork>>(&first, &second, &(a & b).to_string())?;
+            check_binary::<OrOperation<CurrentNetwork>>(&first, &second, &(a | b).to_string())?;
+            check_binary::<XorOperation<CurrentNetwork>>(&first, &second, &(a ^ b).to_string())?;
+            check_binary::<NandOperation<CurrentNetwork>>(&first, &second, &(!(a & b)).to_string())?;
+            check_binary::<NorOperation<CurrentNetwork>>(&first, &second, &(!(a | b)).to_string())?;
+        }
+        check_unary::<NotOperation<CurrentNetwork>>("true", "false")?;
+        check_unary::<NotOperation<CurrentNetwork>>("false", "true")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_bitwise_integer_logic() -> Result<()> {
+        const MAX_U128: &str = "340282366920938463463374607431768211455u128";
+        const MAX_U128_MINUS_ONE: &str = "340282366920938463463374607431768211454u128";
+
+        // The vectors are of the form `(first, second, [and, or, xor, nand, nor])`.
+        let vectors = [
+            ("12u8", "10u8", ["8u8", "14u8", "6u8", "247u8", "241u8"]),
+            ("-1i16", "0i16", ["0i16", "-1i16", "-1i16", "-1i16", "0i16"]),
+            ("4042322160u32", "252645135u32", ["0u32", "4294967295u32", "4294967295u32", "4294967295u32", "0u32"]),
+            ("-6i64", "3i64", ["2i64", "-5i64", "-7i64", "-3i64", "4i64"]),
+            (MAX_U128, "1u128", ["1u128", MAX_U128, MAX_U128_MINUS_ONE, MAX_U128_MINUS_ONE, "0u128"]),
+        ];
+
+        for (first, second, [and, or, xor, nand, nor]) in vectors {
+            check_binary::<AndOperation<CurrentNetwork>>(first, second, and)?;
+            check_binary::<OrOperation<CurrentNetwork>>(first, second, or)?;
+            check_binary::<XorOperation<CurrentNetwork>>(first, second, xor)?;
+            check_binary::<NandOperation<CurrentNetwork>>(first, second, nand)?;
+            check_binary::<NorOperation<CurrentNetwork>>(first, second, nor)?;
+        }
+
+        check_unary::<NotOperation<CurrentNetwork>>("12u8", "243u8")?;
+        check_unary::<NotOperation<CurrentNetwork>>("0i32", "-1i32")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_logic_rejects_field_group_and_scalar_types() {
+        for literal_type in [LiteralType::Field, LiteralType::Group, LiteralType::Scalar] {
+            let inputs = [literal_type, literal_type];
+            assert!(AndOperation::<CurrentNetwork>::output_type(&inputs).is_err());
+            assert!(OrOperation::<CurrentNetwork>::output_type(&inputs).is_err());
+            assert!(XorOperation::<CurrentNetwork>::output_type(&inputs).is_err());
+            assert!(NandOperation::<CurrentNetwork>::output_type(&inputs).is_err());
+            assert!(NorOperation::<CurrentNetwork>::output_type(&inputs).is_err());
+            assert!(NotOperation::<CurrentNetwork>::output_type(&[literal_type]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_gcd_rejects_signed_and_field_types() {
+        for (first, second) in [
+            (LiteralType::I8, LiteralType::I8),
+            (LiteralType::I128, LiteralType::I128),
+            (LiteralType::Field, LiteralType::Field),
+            (LiteralType::Scalar, LiteralType::Scalar),
+            (LiteralType::U8, LiteralType::U16),
+        ] {
+            check_rejects::<GcdOperation<CurrentNetwork>>(first, second);
+        }
+    }
+
+    #[test]
+    fn test_mod_pow() -> Result<()> {
+        let vectors = [
+            // Small exponents, including zero.
+            ("7field", "0field", "1field"),
+            ("7field", "1field", "7field"),
+            ("7field", "2field", "49field"),
+            ("7field", "3field", "343field"),
+            ("2field", "64field", "18446744073709551616field"),
+            // Zero and one bases.
+            ("0field", "0field", "1field"),
+            ("0field", "5field", "0field"),
+            ("1field", "12345field", "1field"),
+        ];
+
+        for (first, second, expected) in vectors {
+            check_binary::<ModPowOperation<CurrentNetwork>>(first, second, expected)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mul_group_by_scalar() -> Result<()> {
+        let generator = console::types::Group::<CurrentNetwork>::generator();
+        let mut rng = TestRng::default();
+        let scalar = console::types::Scalar::<CurrentNetwork>::rand(&mut rng);
+
+        let vectors = [
+            // Multiplying by zero yields the identity, and multiplying by one yields the point.
+            ("0scalar".to_string(), "0group".to_string()),
+            ("1scalar".to_string(), generator.to_string()),
+            ("2scalar".to_string(), (generator + generator).to_string()),
+            (scalar.to_string(), (generator * scalar).to_string()),
+        ];
+
+        // Ensure the scalar is accepted as either operand.
+        for (scalar, expected) in vectors {
+            check_binary::<MulOperation<CurrentNetwork>>(&generator.to_string(), &scalar, &expected)?;
+            check_binary::<MulOperation<CurrentNetwork>>(&scalar, &generator.to_string(), &expected)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mul_rejects_group_by_group() {
+        check_rejects::<MulOperation<CurrentNetwork>>(LiteralType::Group, LiteralType::Group);
+        check_rejects::<MulOperation<CurrentNetwork>>(LiteralType::Group, LiteralType::Field);
+        check_rejects::<MulOperation<CurrentNetwork>>(LiteralType::Field, LiteralType::Group);
+    }
+
+    #[test]
+    fn test_group_double_add_and_sub() -> Result<()> {
+        let generator = console::types::Group::<CurrentNetwork>::generator();
+        let mut rng = TestRng::default();
+        let point = console::types::Group::<CurrentNetwork>::rand(&mut rng);
+        let (identity, g, p) = ("0group".to_string(), generator.to_string(), point.to_string());
+
+        // Ensure doubling matches adding a point to itself, and the identity doubles to itself.
+        check_unary::<DoubleOperation<CurrentNetwork>>(&g, &(generator + generator).to_string())?;
+        check_unary::<DoubleOperation<CurrentNetwork>>(&p, &(point + point).to_string())?;
+        check_unary::<DoubleOperation<CurrentNetwork>>(&identity, &identity)?;
+
+        // Ensure the identity is neutral for addition and subtraction.
+        check_binary::<AddOperation<CurrentNetwork>>(&g, &identity, &g)?;
+        check_binary::<AddOperation<CurrentNetwork>>(&identity, &g, &g)?;
+        check_binary::<AddOperation<CurrentNetwork>>(&identity, &identity, &identity)?;
+        check_binary::<SubOperation<CurrentNetwork>>(&g, &identity, &g)?;
+        // Ensure two known points add and subtract as expected.
+        check_binary::<AddOperation<CurrentNetwork>>(&g, &p, &(generator + point).to_string())?;
+        check_binary::<SubOperation<CurrentNetwork>>(&g, &p, &(generator - point).to_string())?;
+        check_binary::<SubOperation<CurrentNetwork>>(&g, &g, &identity)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_add_and_sub_reject_mixed_types() {
+        for other in [LiteralType::Field, LiteralType::Scalar, LiteralType::U8, LiteralType::I128] {
+            check_rejects::<AddOperation<CurrentNetwork>>(LiteralType::Group, other);
+            check_rejects::<AddOperation<CurrentNetwork>>(other, LiteralType::Group);
+            check_rejects::<SubOperation<CurrentNetwork>>(LiteralType::Group, other);
+            check_rejects::<SubOperation<CurrentNetwork>>(other, LiteralType::Group);
+        }
+        // Ensure `double` rejects scalars.
+        assert!(DoubleOperation::<CurrentNetwork>::output_type(&[LiteralType::Scalar]).is_err());
+    }
+
+    #[test]
+    fn test_mod_pow_rejects_non_field_types() {
+        for (first, second) in [
+            (LiteralType::Group, LiteralType::Field),
+            (LiteralType::Scalar, LiteralType::Field),
+            (LiteralType::U64, LiteralType::Field),
+            (LiteralType::Field, LiteralType::Scalar),
+            (LiteralType::Field, LiteralType::U8),
+        ] {
+            check_rejects::<ModPowOperation<CurrentNetwork>>(first, second);
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_mismatched_integer_operands() {
+        // Ensure mixed signedness and mixed widths are rejected at parse time.
+        let error = Add::<CurrentNetwork>::from_str("add 5u8 3i8 into r0").unwrap_err();
+        assert_eq!(error.to_string(), "operand type mismatch: u8 vs i8");
+        let error = Sub::<CurrentNetwork>::from_str("sub 5u64 3u32 into r0").unwrap_err();
+        assert_eq!(error.to_string(), "operand type mismatch: u64 vs u32");
+        assert!(crate::Instruction::<CurrentNetwork>::from_str("add 5u8 3i8 into r0;").is_err());
+
+        // Ensure matching operands still parse.
+        assert!(Add::<CurrentNetwork>::from_str("add 5u8 3u8 into r0").is_ok());
+        assert!(Add::<CurrentNetwork>::from_str("add -5i8 3i8 into r0").is_ok());
+        assert!(crate::Instruction::<CurrentNetwork>::from_str("add 5u8 3u8 into r0;").is_ok());
+        // Ensure operations with mixed operand types by design still parse.
+        assert!(Pow::<CurrentNetwork>::from_str("pow 2i8 3u32 into r0").is_ok());
+        assert!(Shl::<CurrentNetwork>::from_str("shl 1u8 2u16 into r0").is_ok());
+        // Ensure register operands are still checked at runtime instead.
+        assert!(Add::<CurrentNetwork>::from_str("add r0 3i8 into r1").is_ok());
+        assert!(Add::<CurrentNetwork>::from_str("add 5u8 r0 into r1").is_ok());
+    }
+}
@@ -64,13 +64,74 @@ impl<N: Network> Parser for Instruction<N> {
     }
 }
 
+impl<N: Network> Instruction<N> {
+    /// Returns an error for the given parse failure.
+    /// If the failure is at an instruction, the error reports the line and the offending opcode.
+    pub(crate) fn parse_error(error: ParseError) -> Error {
+        let (line, token, snippet) = (error.line(), error.token(), error.snippet());
+        // Determine if the opcode is known.
+        let is_opcode = Self::OPCODES.iter().any(|opcode| **opcode == token);
+        // Determine if the opcode is unknown, by checking if the instruction parses with a known opcode in its place.
+        let is_unknown_opcode = !is_opcode
+            && match snippet.strip_prefix(token) {
+                Some(operands) => {
+                    Self::OPCODES.iter().any(|opcode| Self::parse(&format!("{opcode}{operands}")).is_ok())
+                }
+                None => false,
+            };
+
+        match (is_opcode, is_unknown_opcode) {
+            (true, _) => match Self::operand_type_error(token, snippet) {
+                Some(error) => anyhow!("line {line}: invalid '{token}' instruction \"{snippet}\" ({error})"),
+                None => anyhow!("line {line}: invalid '{token}' instruction \"{snippet}\""),
+            },
+            (false, true) => anyhow!("line {line}: unknown opcode '{token}' in \"{snippet}\""),
+            (false, false) => anyhow!("{error}"),
+        }
+    }
+}
+
+impl<N: Network> Instruction<N> {
+    /// Returns the type mismatch of the operands, if the given statement parses as an operation
+    /// with the given opcode, except for the types of its operands, e.g. `add 5u8 3i8 into r0;`.
+    fn operand_type_error(opcode: &str, statement: &str) -> Option<Error> {
+        /// Returns the error of parsing the statement as the operation with the given opcode.
+        ///
+        /// ## Example
+        /// ```ignore
+        /// operation_error!(Instruction, |None| {}, { Add, Sub, Mul, Div })
+        /// ```
+        macro_rules! operation_error {
+            ($_object:expr, |$_reader:ident| $_operation:block, { $( $variant:ident, )+ }) => {{
+                $( if *$variant::<N>::opcode() == opcode {
+                    return $variant::<N>::from_str(statement).err();
+                } )+
+                None
+            }};
+        }
+
+        // Remove the whitespace and the semicolon from the statement.
+        let statement = statement.trim().strip_suffix(';')?.trim_end();
+        // Return the error, if the operands have mismatched types.
+        let error: Option<Error> = crate::instruction!(operation_error, Instruction, |None| {});
+        error.filter(|error| {
+            matches!(error.downcast_ref::<InstructionError>(), Some(InstructionError::TypeMismatch(..)))
+        })
+    }
+}
+
 impl<N: Network> FromStr for Instruction<N> {
     type Err = Error;
 
     /// Parses a string into an instruction.
     #[inline]
     fn from_str(string: &str) -> Result<Self> {
-        match Self::parse(string) {
+        let result = Self::parse(string);
+        // If the parser failed, report the failure at its position in the string.
+        if let Some(error) = ParseError::from_result(string, &result) {
+            return Err(Self::parse_error(error));
+        }
+        match result {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
                 ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
@@ -97,4 +158,22 @@ mod tests {
         assert_eq!(instruction, candidate.to_string());
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error_reports_opcode() {
+        let error = Instruction::<CurrentNetwork>::from_str("hsah.psd4 r0 into r1;").unwrap_err();
+        assert_eq!("line 1: unknown opcode 'hsah.psd4' in \"hsah.psd4 r0 into r1;\"", error.to_string());
+
+        let error = Instruction::<CurrentNetwork>::from_str("add r0 into r1;").unwrap_err();
+        assert_eq!("line 1: invalid 'add' instruction \"add r0 into r1;\"", error.to_string());
+
+        let error = Instruction::<CurrentNetwork>::from_str("add 5u8 3i8 into r0;").unwrap_err();
+        assert_eq!(
+            "line 1: invalid 'add' instruction \"add 5u8 3i8 into r0;\" (operand type mismatch: u8 vs i8)",
+            error.to_string()
+        );
+
+        let error = Instruction::<CurrentNetwork>::from_str("input r0 as field.private;").unwrap_err();
+        assert_eq!("line 1, column 1: failed to parse 'input' in \"input r0 as field.private;\"", error.to_string());
+    }
 }
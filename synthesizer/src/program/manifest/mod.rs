@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod serialize;
+
+use super::*;
+use console::program::ValueType;
+
+/// The signature of a program function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionSignature<N: Network> {
+    /// The name of the function.
+    name: Identifier<N>,
+    /// The input types of the function.
+    inputs: Vec<ValueType<N>>,
+    /// The output types of the function.
+    outputs: Vec<ValueType<N>>,
+    /// Whether the function has a finalize scope.
+    has_finalize: bool,
+}
+
+impl<N: Network> FunctionSignature<N> {
+    /// Returns the name of the function.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the input types of the function.
+    pub fn inputs(&self) -> &[ValueType<N>] {
+        &self.inputs
+    }
+
+    /// Returns the output types of the function.
+    pub fn outputs(&self) -> &[ValueType<N>] {
+        &self.outputs
+    }
+
+    /// Returns `true` if the function has a finalize scope.
+    pub const fn has_finalize(&self) -> bool {
+        self.has_finalize
+    }
+}
+
+impl<N: Network> Function<N> {
+    /// Returns the signature of the function.
+    pub fn signature(&self) -> FunctionSignature<N> {
+        FunctionSignature {
+            name: *self.name(),
+            inputs: self.input_types(),
+            outputs: self.output_types(),
+            has_finalize: self.finalize().is_some(),
+        }
+    }
+}
+
+/// A summary of a program, as displayed for confirmation before it is deployed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentManifest<N: Network> {
+    /// The ID of the program.
+    program_id: ProgramID<N>,
+    /// The IDs of the imported programs.
+    imports: Vec<ProgramID<N>>,
+    /// The struct definitions of the program.
+    structs: Vec<Struct<N>>,
+    /// The record definitions of the program.
+    records: Vec<RecordType<N>>,
+    /// The signatures of the program functions.
+    functions: Vec<FunctionSignature<N>>,
+    /// The number of occurrences of each opcode in the program.
+    opcode_frequency: IndexMap<&'static str, usize>,
+    /// The size of the program in bytes.
+    size_in_bytes: usize,
+    /// The estimated cost of the program closures and functions.
+    estimated_cost: u64,
+}
+
+impl<N: Network> DeploymentManifest<N> {
+    /// Returns the ID of the program.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the IDs of the imported programs.
+    pub fn imports(&self) -> &[ProgramID<N>] {
+        &self.imports
+    }
+
+    /// Returns the struct definitions of the program.
+    pub fn structs(&self) -> &[Struct<N>] {
+        &self.structs
+    }
+
+    /// Returns the record definitions of the program.
+    pub fn records(&self) -> &[RecordType<N>] {
+        &self.records
+    }
+
+    /// Returns the signatures of the program functions.
+    pub fn functions(&self) -> &[FunctionSignature<N>] {
+        &self.functions
+    }
+
+    /// Returns the number of occurrences of each opcode in the program.
+    pub const fn opcode_frequency(&self) -> &IndexMap<&'static str, usize> {
+        &self.opcode_frequency
+    }
+
+    /// Returns the size of the program in bytes.
+    pub const fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
+    /// Returns the estimated cost of the program closures and functions.
+    pub const fn estimated_cost(&self) -> u64 {
+        self.estimated_cost
+    }
+}
+
+impl<N: Network> Program<N> {
+    /// Returns the number of occurrences of each opcode in the program closures and functions.
+    pub fn opcode_frequency(&self) -> IndexMap<&'static str, usize> {
+        let closures = self.closures.values().flat_map(Closure::instructions);
+        let functions = self.functions.values().flat_map(Function::instructions);
+
+        let mut frequency = IndexMap::new();
+        for instruction in closures.chain(functions) {
+            *frequency.entry(*instruction.opcode()).or_insert(0) += 1;
+        }
+        frequency
+    }
+
+    /// Returns the deployment manifest of the program.
+    pub fn to_manifest(&self) -> Result<DeploymentManifest<N>> {
+        // Compute the estimated cost, excluding the cost of any imported program.
+        let estimated_cost = self
+            .closures
+            .values()
+            .map(Closure::cost)
+            .chain(self.functions.values().map(Function::cost))
+            .try_fold(0u64, |cost, next| cost.checked_add(next))
+            .ok_or_else(|| anyhow!("The estimated cost of '{}' overflowed", self.id))?;
+
+        Ok(DeploymentManifest {
+            program_id: self.id,
+            imports: self.imports.keys().copied().collect(),
+            structs: self.structs.values().cloned().collect(),
+            records: self.records.values().cloned().collect(),
+            functions: self.functions.values().map(Function::signature).collect(),
+            opcode_frequency: self.opcode_frequency(),
+            size_in_bytes: self.to_bytes_le()?.len(),
+            estimated_cost,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_manifest() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"import credits.aleo;
+
+program manifest.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+closure double:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as message.private;
+    call double r0.first into r1;
+    add r1 r0.second into r2;
+    output r2 as field.private;
+
+function deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+",
+        )?;
+
+        let manifest = program.to_manifest()?;
+        assert_eq!(program.id(), manifest.program_id());
+        assert_eq!(&[ProgramID::from_str("credits.aleo")?], manifest.imports());
+        assert_eq!(&[program.get_struct(&Identifier::from_str("message")?)?], manifest.structs());
+        assert_eq!(&[program.get_record(&Identifier::from_str("token")?)?], manifest.records());
+
+        // Ensure the function signatures match the program functions.
+        let signatures = manifest.functions();
+        assert_eq!(2, signatures.len());
+        assert_eq!("compute", signatures[0].name().to_string());
+        assert_eq!(&[ValueType::from_str("message.private")?], signatures[0].inputs());
+        assert_eq!(&[ValueType::from_str("field.private")?], signatures[0].outputs());
+        assert!(!signatures[0].has_finalize());
+        assert_eq!("deposit", signatures[1].name().to_string());
+        assert_eq!(2, signatures[1].inputs().len());
+        assert!(signatures[1].outputs().is_empty());
+        assert!(signatures[1].has_finalize());
+
+        // Ensure the opcodes of closures and functions are counted, excluding the finalize scope.
+        assert_eq!(Some(&2), manifest.opcode_frequency().get("add"));
+        assert_eq!(Some(&1), manifest.opcode_frequency().get("call"));
+        assert_eq!(None, manifest.opcode_frequency().get("set"));
+
+        // Ensure the size and cost are consistent with the program.
+        assert_eq!(program.to_bytes_le()?.len(), manifest.size_in_bytes());
+        let function_costs = program.functions().values().map(Function::cost).sum::<u64>();
+        let closure_costs = program.closures().values().map(Closure::cost).sum::<u64>();
+        assert_eq!(function_costs + closure_costs, manifest.estimated_cost());
+        Ok(())
+    }
+}
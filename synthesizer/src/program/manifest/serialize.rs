@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for FunctionSignature<N> {
+    /// Serializes the function signature into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut signature = serializer.serialize_struct("FunctionSignature", 4)?;
+        signature.serialize_field("name", &self.name)?;
+        signature.serialize_field("inputs", &self.inputs)?;
+        signature.serialize_field("outputs", &self.outputs)?;
+        signature.serialize_field("has_finalize", &self.has_finalize)?;
+        signature.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for FunctionSignature<N> {
+    /// Deserializes the function signature from a JSON object.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the function signature from a string into a value.
+        let mut signature = serde_json::Value::deserialize(deserializer)?;
+
+        // Recover the function signature.
+        Ok(Self {
+            // Retrieve the name.
+            name: DeserializeExt::take_from_value::<D>(&mut signature, "name")?,
+            // Retrieve the input types.
+            inputs: DeserializeExt::take_from_value::<D>(&mut signature, "inputs")?,
+            // Retrieve the output types.
+            outputs: DeserializeExt::take_from_value::<D>(&mut signature, "outputs")?,
+            // Retrieve whether the function has a finalize scope.
+            has_finalize: DeserializeExt::take_from_value::<D>(&mut signature, "has_finalize")?,
+        })
+    }
+}
+
+impl<N: Network> Serialize for DeploymentManifest<N> {
+    /// Serializes the deployment manifest into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut manifest = serializer.serialize_struct("DeploymentManifest", 8)?;
+        manifest.serialize_field("program_id", &self.program_id)?;
+        manifest.serialize_field("imports", &self.imports)?;
+        manifest.serialize_field("structs", &self.structs)?;
+        manifest.serialize_field("records", &self.records)?;
+        manifest.serialize_field("functions", &self.functions)?;
+        manifest.serialize_field("opcode_frequency", &self.opcode_frequency)?;
+        manifest.serialize_field("size_in_bytes", &self.size_in_bytes)?;
+        manifest.serialize_field("estimated_cost", &self.estimated_cost)?;
+        manifest.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for DeploymentManifest<N> {
+    /// Deserializes the deployment manifest from a JSON object.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the deployment manifest from a string into a value.
+        let mut manifest = serde_json::Value::deserialize(deserializer)?;
+
+        // Retrieve the opcode frequency, resolving each opcode to a known instruction.
+        let opcode_frequency: IndexMap<String, usize> =
+            DeserializeExt::take_from_value::<D>(&mut manifest, "opcode_frequency")?;
+        let opcode_frequency: IndexMap<&'static str, usize> = opcode_frequency
+            .into_iter()
+            .map(|(opcode, count)| match Instruction::<N>::OPCODES.iter().find(|candidate| ***candidate == opcode) {
+                Some(candidate) => Ok((**candidate, count)),
+                None => Err(de::Error::custom(format!("Unknown opcode '{opcode}' in the deployment manifest"))),
+            })
+            .collect::<Result<_, D::Error>>()?;
+
+        // Recover the deployment manifest.
+        Ok(Self {
+            // Retrieve the program ID.
+            program_id: DeserializeExt::take_from_value::<D>(&mut manifest, "program_id")?,
+            // Retrieve the imports.
+            imports: DeserializeExt::take_from_value::<D>(&mut manifest, "imports")?,
+            // Retrieve the structs.
+            structs: DeserializeExt::take_from_value::<D>(&mut manifest, "structs")?,
+            // Retrieve the records.
+            records: DeserializeExt::take_from_value::<D>(&mut manifest, "records")?,
+            // Retrieve the function signatures.
+            functions: DeserializeExt::take_from_value::<D>(&mut manifest, "functions")?,
+            opcode_frequency,
+            // Retrieve the size in bytes.
+            size_in_bytes: DeserializeExt::take_from_value::<D>(&mut manifest, "size_in_bytes")?,
+            // Retrieve the estimated cost.
+            estimated_cost: DeserializeExt::take_from_value::<D>(&mut manifest, "estimated_cost")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program manifest.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    mul r1 r0 into r2;
+    output r2 as field.private;
+",
+        )?;
+        let expected = program.to_manifest()?;
+
+        // Ensure the manifest serializes into JSON.
+        let json = serde_json::to_value(&expected)?;
+        assert_eq!("manifest.aleo", json["program_id"]);
+        assert_eq!("compute", json["functions"][0]["name"]);
+        assert_eq!(1, json["opcode_frequency"]["add"]);
+
+        // Ensure the manifest deserializes from JSON.
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_json_rejects_unknown_opcode() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program manifest.aleo;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+",
+        )?;
+
+        // Replace the opcode with one that does not exist.
+        let mut json = serde_json::to_value(program.to_manifest()?)?;
+        json["opcode_frequency"] = serde_json::json!({ "add.x": 1 });
+        assert!(serde_json::from_value::<DeploymentManifest<CurrentNetwork>>(json).is_err());
+        Ok(())
+    }
+}
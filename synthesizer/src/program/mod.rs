@@ -26,6 +26,9 @@ pub use import::*;
 mod instruction;
 pub use instruction::*;
 
+mod manifest;
+pub use manifest::*;
+
 mod mapping;
 pub use mapping::*;
 
@@ -35,7 +38,8 @@ mod serialize;
 
 use console::{
     network::prelude::*,
-    program::{EntryType, Identifier, PlaintextType, ProgramID, RecordType, Struct},
+    program::{EntryType, Identifier, LiteralType, PlaintextType, ProgramID, RecordType, Struct},
+    types::{Field, Scalar},
 };
 
 use indexmap::IndexMap;
@@ -269,6 +273,33 @@ function fee:
         // Return the function.
         Ok(function)
     }
+
+    /// Returns the size in bytes of the struct with the given name, summing its members recursively.
+    /// Note: A `string` member is counted at its maximum size, as its length is not fixed.
+    pub fn struct_size(&self, name: &Identifier<N>) -> Result<usize> {
+        // Retrieve the struct.
+        let struct_ = self.get_struct(name)?;
+        // Sum the size of each member.
+        struct_.members().values().try_fold(0usize, |size, member_type| {
+            let member_size = match member_type {
+                PlaintextType::Literal(literal_type) => match literal_type {
+                    LiteralType::Address => Field::<N>::SIZE_IN_BYTES,
+                    LiteralType::Boolean => 1,
+                    LiteralType::Field => Field::<N>::SIZE_IN_BYTES,
+                    LiteralType::Group => Field::<N>::SIZE_IN_BYTES,
+                    LiteralType::I8 | LiteralType::U8 => 1,
+                    LiteralType::I16 | LiteralType::U16 => 2,
+                    LiteralType::I32 | LiteralType::U32 => 4,
+                    LiteralType::I64 | LiteralType::U64 => 8,
+                    LiteralType::I128 | LiteralType::U128 => 16,
+                    LiteralType::Scalar => Scalar::<N>::SIZE_IN_BYTES,
+                    LiteralType::String => 2 + N::MAX_STRING_BYTES as usize,
+                },
+                PlaintextType::Struct(struct_name) => self.struct_size(struct_name)?,
+            };
+            size.checked_add(member_size).ok_or_else(|| anyhow!("Struct '{name}' exceeds the maximum size"))
+        })
+    }
 }
 
 impl<N: Network> Program<N> {
@@ -688,6 +719,56 @@ struct message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_struct_reserved_member() -> Result<()> {
+        // Initialize a new program.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("unknown.aleo")?)?;
+
+        // Ensure a member named after a keyword is rejected.
+        let struct_ = Struct::<CurrentNetwork>::from_str("struct message:\n    constant as field;")?;
+        let error = program.add_struct(struct_).unwrap_err();
+        assert_eq!("'constant' is a reserved keyword.", error.to_string());
+
+        // Ensure a member named after an opcode is accepted.
+        let struct_ = Struct::<CurrentNetwork>::from_str("struct message:\n    add as field;")?;
+        program.add_struct(struct_)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_struct_size() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+
+struct point:
+    x as u64;
+    y as u64;
+
+struct token:
+    owner as address;
+    amount as u128;
+    origin as point;
+    flag as boolean;
+
+function compute:
+    input r0 as u8.public;
+    output r0 as u8.public;",
+        )?;
+
+        // Ensure the size of a flat struct is the sum of its members.
+        assert_eq!(16, program.struct_size(&Identifier::from_str("point")?)?);
+        // Ensure the size of a nested struct includes the size of its inner struct.
+        let expected = Field::<CurrentNetwork>::SIZE_IN_BYTES + 16 + 16 + 1;
+        assert_eq!(expected, program.struct_size(&Identifier::from_str("token")?)?);
+        // Ensure an unknown struct fails.
+        assert!(program.struct_size(&Identifier::from_str("unknown")?).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_record() -> Result<()> {
         // Create a new record.
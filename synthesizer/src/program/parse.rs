@@ -103,12 +103,11 @@ impl<N: Network> FromStr for Program<N> {
     /// Returns a program from a string literal.
     fn from_str(string: &str) -> Result<Self> {
         match Self::parse(string) {
-            Ok((remainder, object)) => {
-                // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Remaining invalid string is: \"{remainder}\"");
-                // Return the object.
-                Ok(object)
-            }
+            // Ensure the remainder is empty.
+            Ok((remainder, object)) => match remainder.is_empty() {
+                true => Ok(object),
+                false => Err(Instruction::<N>::parse_error(ParseError::new(string, remainder))),
+            },
             Err(error) => bail!("Failed to parse string. {error}"),
         }
     }
@@ -122,6 +121,16 @@ impl<N: Network> Debug for Program<N> {
 }
 
 #[allow(clippy::format_push_string)]
+impl<N: Network> Program<N> {
+    /// Returns the canonical source of the program, ensuring it re-parses to the same program.
+    pub fn to_source(&self) -> Result<String> {
+        let source = self.to_string();
+        // Ensure the source re-parses to the same program.
+        ensure!(Self::from_str(&source)? == *self, "Program '{}' does not re-parse from its source", self.id);
+        Ok(source)
+    }
+}
+
 impl<N: Network> Display for Program<N> {
     /// Prints the program as a string.
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -261,4 +270,82 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_program_to_source() -> Result<()> {
+        let source = r"program to_source.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+closure helper:
+    input r0 as field;
+    mul r0 r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as message.private;
+    input r1 as scalar.public;
+    add r0.first r0.second into r2;
+    call helper r2 into r3;
+    hash.bhp256 r3 into r4;
+    hash.psd2 r0.first into r5;
+    commit.bhp256 r2 r1 into r6;
+    ternary true r4 r3 into r7;
+    cast r7 r6 into r8 as message;
+    output r8 as message.private;
+    output r5 as field.public;
+";
+        let program = Program::<CurrentNetwork>::from_str(source)?;
+
+        // Disassemble a program deserialized from bytes.
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&program.to_bytes_le()?)?;
+        let disassembled = candidate.to_source()?;
+        assert_eq!(source, disassembled);
+        // Ensure the disassembled source re-parses to the same program.
+        assert_eq!(program, Program::from_str(&disassembled)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_parse_error_reports_line() {
+        let program = |instruction: &str, trailer: &str| {
+            format!(
+                r"program to_parse.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function compute:
+    input r0 as message.private;
+    add r0.first r0.second into r1;
+    {instruction};
+    output r2 as field.private;
+{trailer}"
+            )
+        };
+
+        // Ensure the program parses without a typo.
+        assert!(Program::<CurrentNetwork>::from_str(&program("hash.psd4 r1 into r2", "")).is_ok());
+
+        // Ensure an unknown opcode is reported with its line.
+        let error = Program::<CurrentNetwork>::from_str(&program("hsah.psd4 r1 into r2", "")).unwrap_err();
+        assert_eq!("line 10: unknown opcode 'hsah.psd4' in \"hsah.psd4 r1 into r2;\"", error.to_string());
+
+        // Ensure a known opcode with invalid operands is reported with its line.
+        let error = Program::<CurrentNetwork>::from_str(&program("hash.psd4 into r2", "")).unwrap_err();
+        assert_eq!("line 10: invalid 'hash.psd4' instruction \"hash.psd4 into r2;\"", error.to_string());
+
+        // Ensure a failure outside of an instruction is reported with its line and column.
+        let trailer = "\nstrcut other:\n    first as field;\n";
+        let error = Program::<CurrentNetwork>::from_str(&program("hash.psd4 r1 into r2", trailer)).unwrap_err();
+        assert_eq!("line 13, column 1: failed to parse 'strcut' in \"strcut other:\"", error.to_string());
+    }
 }